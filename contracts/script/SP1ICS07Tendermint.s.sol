@@ -13,6 +13,9 @@ struct SP1ICS07TendermintGenesisJson {
     bytes32 membershipVkey;
     bytes32 ucAndMembershipVkey;
     bytes32 misbehaviourVkey;
+    bytes32 upgradeClientVkey;
+    bytes32 multiUpdateClientVkey;
+    bytes32 multiMembershipVkey;
 }
 
 contract SP1TendermintScript is Script, IICS07TendermintMsgs {
@@ -36,6 +39,9 @@ contract SP1TendermintScript is Script, IICS07TendermintMsgs {
             genesis.membershipVkey,
             genesis.ucAndMembershipVkey,
             genesis.misbehaviourVkey,
+            genesis.upgradeClientVkey,
+            genesis.multiUpdateClientVkey,
+            genesis.multiMembershipVkey,
             genesis.trustedClientState,
             trustedConsensusHash
         );
@@ -45,7 +51,7 @@ contract SP1TendermintScript is Script, IICS07TendermintMsgs {
         ClientState memory clientState = ics07Tendermint.getClientState();
         assert(keccak256(abi.encode(clientState)) == keccak256(genesis.trustedClientState));
 
-        bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(clientState.latestHeight.revisionHeight);
+        bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(clientState.latestHeight);
         assert(consensusHash == keccak256(abi.encode(trustedConsensusState)));
 
         return address(ics07Tendermint);
@@ -61,6 +67,9 @@ contract SP1TendermintScript is Script, IICS07TendermintMsgs {
         bytes32 membershipVkey = json.readBytes32(".membershipVkey");
         bytes32 ucAndMembershipVkey = json.readBytes32(".ucAndMembershipVkey");
         bytes32 misbehaviourVkey = json.readBytes32(".misbehaviourVkey");
+        bytes32 upgradeClientVkey = json.readBytes32(".upgradeClientVkey");
+        bytes32 multiUpdateClientVkey = json.readBytes32(".multiUpdateClientVkey");
+        bytes32 multiMembershipVkey = json.readBytes32(".multiMembershipVkey");
 
         SP1ICS07TendermintGenesisJson memory fixture = SP1ICS07TendermintGenesisJson({
             trustedClientState: trustedClientState,
@@ -68,7 +77,10 @@ contract SP1TendermintScript is Script, IICS07TendermintMsgs {
             updateClientVkey: updateClientVkey,
             membershipVkey: membershipVkey,
             ucAndMembershipVkey: ucAndMembershipVkey,
-            misbehaviourVkey: misbehaviourVkey
+            misbehaviourVkey: misbehaviourVkey,
+            upgradeClientVkey: upgradeClientVkey,
+            multiUpdateClientVkey: multiUpdateClientVkey,
+            multiMembershipVkey: multiMembershipVkey
         });
 
         return fixture;
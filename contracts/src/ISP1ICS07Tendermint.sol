@@ -2,12 +2,18 @@
 pragma solidity ^0.8.28;
 
 import { ILightClient } from "solidity-ibc/interfaces/ILightClient.sol";
+import { IICS02ClientMsgs } from "solidity-ibc/msgs/IICS02ClientMsgs.sol";
 import { IICS07TendermintMsgs } from "../src/msgs/IICS07TendermintMsgs.sol";
 import { ISP1Verifier } from "@sp1-contracts/ISP1Verifier.sol";
 
 /// @title ISP1ICS07Tendermint
 /// @notice ISP1ICS07Tendermint is the interface for the ICS07 Tendermint light client
 interface ISP1ICS07Tendermint is ILightClient {
+    /// @notice Emitted when a consensus state is stored, i.e. at deployment, by an update or by an upgrade.
+    /// @param height The height of the consensus state.
+    /// @param consensusStateHash The keccak256 hash of the consensus state.
+    event ConsensusStateAdded(IICS02ClientMsgs.Height height, bytes32 consensusStateHash);
+
     /// @notice Immutable update client program verification key.
     /// @return The verification key for the update client program.
     function UPDATE_CLIENT_PROGRAM_VKEY() external view returns (bytes32);
@@ -24,6 +30,18 @@ interface ISP1ICS07Tendermint is ILightClient {
     /// @return The verification key for the misbehaviour program.
     function MISBEHAVIOUR_PROGRAM_VKEY() external view returns (bytes32);
 
+    /// @notice Immutable upgrade client program verification key.
+    /// @return The verification key for the upgrade client program.
+    function UPGRADE_CLIENT_PROGRAM_VKEY() external view returns (bytes32);
+
+    /// @notice Immutable multi-header update client program verification key.
+    /// @return The verification key for the multi-header update client program.
+    function MULTI_UPDATE_CLIENT_PROGRAM_VKEY() external view returns (bytes32);
+
+    /// @notice Immutable verify (non)membership at multiple heights program verification key.
+    /// @return The verification key for the multi-height membership program.
+    function MULTI_MEMBERSHIP_PROGRAM_VKEY() external view returns (bytes32);
+
     /// @notice Immutable SP1 verifier contract address.
     /// @return The SP1 verifier contract.
     function VERIFIER() external view returns (ISP1Verifier);
@@ -36,8 +54,8 @@ interface ISP1ICS07Tendermint is ILightClient {
     /// @return The client state.
     function getClientState() external view returns (IICS07TendermintMsgs.ClientState memory);
 
-    /// @notice Returns the consensus state keccak256 hash at the given revision height.
-    /// @param revisionHeight The revision height.
-    /// @return The consensus state at the given revision height.
-    function getConsensusStateHash(uint32 revisionHeight) external view returns (bytes32);
+    /// @notice Returns the consensus state keccak256 hash at the given height.
+    /// @param height The height of the consensus state.
+    /// @return The consensus state at the given height.
+    function getConsensusStateHash(IICS02ClientMsgs.Height memory height) external view returns (bytes32);
 }
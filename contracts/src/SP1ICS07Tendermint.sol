@@ -6,10 +6,14 @@ import { IUpdateClientMsgs } from "./msgs/IUpdateClientMsgs.sol";
 import { IMembershipMsgs } from "./msgs/IMembershipMsgs.sol";
 import { IUpdateClientAndMembershipMsgs } from "./msgs/IUcAndMembershipMsgs.sol";
 import { IMisbehaviourMsgs } from "./msgs/IMisbehaviourMsgs.sol";
+import { IUpgradeClientMsgs } from "./msgs/IUpgradeClientMsgs.sol";
+import { IMultiUpdateClientMsgs } from "./msgs/IMultiUpdateClientMsgs.sol";
+import { IMultiMembershipMsgs } from "./msgs/IMultiMembershipMsgs.sol";
 import { ISP1ICS07TendermintErrors } from "./errors/ISP1ICS07TendermintErrors.sol";
 import { ISP1ICS07Tendermint } from "./ISP1ICS07Tendermint.sol";
 
 import { Paths } from "./utils/Paths.sol";
+import { Heights } from "./utils/Heights.sol";
 
 import { ILightClientMsgs } from "solidity-ibc/msgs/ILightClientMsgs.sol";
 import { ILightClient } from "solidity-ibc/interfaces/ILightClient.sol";
@@ -31,6 +35,9 @@ contract SP1ICS07Tendermint is
     IMembershipMsgs,
     IUpdateClientAndMembershipMsgs,
     IMisbehaviourMsgs,
+    IUpgradeClientMsgs,
+    IMultiUpdateClientMsgs,
+    IMultiMembershipMsgs,
     ISP1ICS07TendermintErrors,
     ILightClientMsgs,
     ISP1ICS07Tendermint,
@@ -47,12 +54,18 @@ contract SP1ICS07Tendermint is
     /// @inheritdoc ISP1ICS07Tendermint
     bytes32 public immutable MISBEHAVIOUR_PROGRAM_VKEY;
     /// @inheritdoc ISP1ICS07Tendermint
+    bytes32 public immutable UPGRADE_CLIENT_PROGRAM_VKEY;
+    /// @inheritdoc ISP1ICS07Tendermint
+    bytes32 public immutable MULTI_UPDATE_CLIENT_PROGRAM_VKEY;
+    /// @inheritdoc ISP1ICS07Tendermint
+    bytes32 public immutable MULTI_MEMBERSHIP_PROGRAM_VKEY;
+    /// @inheritdoc ISP1ICS07Tendermint
     ISP1Verifier public immutable VERIFIER;
 
     /// @notice The ICS07Tendermint client state
     ClientState private clientState;
     /// @notice The mapping from height to consensus state keccak256 hashes.
-    mapping(uint32 height => bytes32 hash) private consensusStateHashes;
+    mapping(uint32 revisionNumber => mapping(uint32 revisionHeight => bytes32 hash)) private consensusStateHashes;
 
     /// @notice Allowed clock drift in seconds.
     /// @inheritdoc ISP1ICS07Tendermint
@@ -63,6 +76,10 @@ contract SP1ICS07Tendermint is
     /// @param membershipProgramVkey The verification key for the verify (non)membership program.
     /// @param updateClientAndMembershipProgramVkey The verification key for the update client and membership program.
     /// @param misbehaviourProgramVkey The verification key for the misbehaviour program.
+    /// @param upgradeClientProgramVkey The verification key for the upgrade client program.
+    /// @param multiUpdateClientProgramVkey The verification key for the multi-header update client program.
+    /// @param multiMembershipProgramVkey The verification key for the verify (non)membership at multiple heights
+    /// program.
     /// @param _clientState The encoded initial client state.
     /// @param _consensusState The encoded initial consensus state.
     constructor(
@@ -70,6 +87,9 @@ contract SP1ICS07Tendermint is
         bytes32 membershipProgramVkey,
         bytes32 updateClientAndMembershipProgramVkey,
         bytes32 misbehaviourProgramVkey,
+        bytes32 upgradeClientProgramVkey,
+        bytes32 multiUpdateClientProgramVkey,
+        bytes32 multiMembershipProgramVkey,
         bytes memory _clientState,
         bytes32 _consensusState
     ) {
@@ -77,9 +97,12 @@ contract SP1ICS07Tendermint is
         MEMBERSHIP_PROGRAM_VKEY = membershipProgramVkey;
         UPDATE_CLIENT_AND_MEMBERSHIP_PROGRAM_VKEY = updateClientAndMembershipProgramVkey;
         MISBEHAVIOUR_PROGRAM_VKEY = misbehaviourProgramVkey;
+        UPGRADE_CLIENT_PROGRAM_VKEY = upgradeClientProgramVkey;
+        MULTI_UPDATE_CLIENT_PROGRAM_VKEY = multiUpdateClientProgramVkey;
+        MULTI_MEMBERSHIP_PROGRAM_VKEY = multiMembershipProgramVkey;
 
         clientState = abi.decode(_clientState, (ClientState));
-        consensusStateHashes[clientState.latestHeight.revisionHeight] = _consensusState;
+        setConsensusStateHash(clientState.latestHeight, _consensusState);
 
         if (clientState.zkAlgorithm == SupportedZkAlgorithm.Groth16) {
             VERIFIER = new SP1VerifierGroth16();
@@ -101,19 +124,23 @@ contract SP1ICS07Tendermint is
     }
 
     /// @inheritdoc ISP1ICS07Tendermint
-    function getConsensusStateHash(uint32 revisionHeight) public view returns (bytes32) {
-        bytes32 hash = consensusStateHashes[revisionHeight];
+    function getConsensusStateHash(Height memory height) public view returns (bytes32) {
+        bytes32 hash = consensusStateHashes[height.revisionNumber][height.revisionHeight];
         require(hash != 0, ConsensusStateNotFound());
         return hash;
     }
 
     /// @notice The entrypoint for updating the client.
     /// @dev This function verifies the public values and forwards the proof to the SP1 verifier.
+    /// @dev The proof is either of the update client program or of the multi-header update client program.
     /// @param updateMsg The encoded update message.
     /// @return The result of the update.
     /// @inheritdoc ILightClient
     function updateClient(bytes calldata updateMsg) public notFrozen returns (UpdateResult) {
         MsgUpdateClient memory msgUpdateClient = abi.decode(updateMsg, (MsgUpdateClient));
+        if (msgUpdateClient.sp1Proof.vKey == MULTI_UPDATE_CLIENT_PROGRAM_VKEY) {
+            return handleMultiUpdateClient(msgUpdateClient.sp1Proof);
+        }
         require(
             msgUpdateClient.sp1Proof.vKey == UPDATE_CLIENT_PROGRAM_VKEY,
             VerificationKeyMismatch(UPDATE_CLIENT_PROGRAM_VKEY, msgUpdateClient.sp1Proof.vKey)
@@ -126,10 +153,10 @@ contract SP1ICS07Tendermint is
         UpdateResult updateResult = checkUpdateResult(output);
         if (updateResult == UpdateResult.Update) {
             // adding the new consensus state to the mapping
-            if (output.newHeight.revisionHeight > clientState.latestHeight.revisionHeight) {
-                clientState.latestHeight = output.newHeight;
+            if (Heights.gt(output.newHeight, clientState.latestHeight)) {
+                setLatestHeight(output.newHeight);
             }
-            consensusStateHashes[output.newHeight.revisionHeight] = keccak256(abi.encode(output.newConsensusState));
+            setConsensusStateHash(output.newHeight, keccak256(abi.encode(output.newConsensusState)));
         } else if (updateResult == UpdateResult.Misbehaviour) {
             clientState.isFrozen = true;
         } else if (updateResult == UpdateResult.NoOp) {
@@ -142,15 +169,15 @@ contract SP1ICS07Tendermint is
     }
 
     /// @notice The entrypoint for verifying (non)membership proof.
+    /// @dev If the proof committed hashed values, the keccak256 hash of the value of the message is checked against
+    /// @dev the proof instead, so that large values are not part of the public values.
     /// @param msgMembership The membership message.
     /// @return timestamp The timestamp of the trusted consensus state.
     /// @inheritdoc ILightClient
     function membership(MsgMembership calldata msgMembership) public notFrozen returns (uint256 timestamp) {
         if (msgMembership.proof.length == 0) {
             // cached proof
-            return getCachedKvPair(
-                msgMembership.proofHeight.revisionHeight, KVPair(msgMembership.path, msgMembership.value)
-            );
+            return getCachedKvPair(msgMembership.proofHeight, msgMembership.path, msgMembership.value);
         }
 
         MembershipProof memory membershipProof = abi.decode(msgMembership.proof, (MembershipProof));
@@ -162,6 +189,10 @@ contract SP1ICS07Tendermint is
             return handleSP1UpdateClientAndMembership(
                 msgMembership.proofHeight, membershipProof.proof, msgMembership.path, msgMembership.value
             );
+        } else if (membershipProof.proofType == MembershipProofType.SP1MultiMembershipProof) {
+            return handleSP1MultiMembershipProof(
+                msgMembership.proofHeight, membershipProof.proof, msgMembership.path, msgMembership.value
+            );
         } else {
             revert UnknownMembershipProofType(uint8(membershipProof.proofType));
         }
@@ -187,10 +218,79 @@ contract SP1ICS07Tendermint is
     }
 
     /// @notice The entrypoint for upgrading the client.
+    /// @dev The upgraded client and consensus states are proven under the ICS-02 upgrade path at the latest height of
+    /// @dev the client, which must be the height of the upgrade plan. The client keeps its trust level and zk
+    /// @dev algorithm, and its trusting period is only shortened if the unbonding period is.
     /// @inheritdoc ILightClient
-    function upgradeClient(bytes calldata) public view notFrozen {
-        // TODO: Not yet implemented. (#78)
-        revert FeatureNotSupported();
+    function upgradeClient(bytes calldata upgradeMsg) public notFrozen {
+        MsgUpgradeClient memory msgUpgradeClient = abi.decode(upgradeMsg, (MsgUpgradeClient));
+        require(
+            msgUpgradeClient.sp1Proof.vKey == UPGRADE_CLIENT_PROGRAM_VKEY,
+            VerificationKeyMismatch(UPGRADE_CLIENT_PROGRAM_VKEY, msgUpgradeClient.sp1Proof.vKey)
+        );
+
+        UpgradeClientOutput memory output = abi.decode(msgUpgradeClient.sp1Proof.publicValues, (UpgradeClientOutput));
+
+        validateUpgradeClientOutput(output);
+
+        verifySP1Proof(msgUpgradeClient.sp1Proof);
+
+        clientState = output.newClientState;
+        setConsensusStateHash(output.newClientState.latestHeight, keccak256(abi.encode(output.newConsensusState)));
+    }
+
+    /// @notice Handles the proof of the multi-header update client program.
+    /// @dev Each new consensus state is checked against the one already stored at its height, and the client is frozen
+    /// @dev if any of them conflicts.
+    /// @param sp1Proof The SP1 proof of the multi-header update client program.
+    /// @return The result of the update.
+    function handleMultiUpdateClient(SP1Proof memory sp1Proof) private returns (UpdateResult) {
+        MultiUpdateClientOutput memory output = abi.decode(sp1Proof.publicValues, (MultiUpdateClientOutput));
+        require(
+            output.newConsensusStates.length > 0 && output.newConsensusStates.length <= 256,
+            LengthIsOutOfRange(output.newConsensusStates.length, 1, 256)
+        );
+
+        validateClientStateAndTime(output.clientState, output.time);
+
+        bytes32 outputConsensusStateHash = keccak256(abi.encode(output.trustedConsensusState));
+        bytes32 storedConsensusStateHash = getConsensusStateHash(output.trustedHeight);
+        require(
+            outputConsensusStateHash == storedConsensusStateHash,
+            ConsensusStateHashMismatch(storedConsensusStateHash, outputConsensusStateHash)
+        );
+
+        UpdateResult updateResult = UpdateResult.NoOp;
+        ConsensusState memory trustedConsensusState = output.trustedConsensusState;
+        for (uint256 i = 0; i < output.newConsensusStates.length; i++) {
+            ConsensusStateAtHeight memory newConsensusState = output.newConsensusStates[i];
+            UpdateResult result = checkConsensusStateUpdate(
+                trustedConsensusState, newConsensusState.height, newConsensusState.consensusState
+            );
+            if (result == UpdateResult.Misbehaviour) {
+                clientState.isFrozen = true;
+                updateResult = UpdateResult.Misbehaviour;
+                break;
+            } else if (result == UpdateResult.Update) {
+                // adding the new consensus state to the mapping
+                if (Heights.gt(newConsensusState.height, clientState.latestHeight)) {
+                    setLatestHeight(newConsensusState.height);
+                }
+                setConsensusStateHash(
+                    newConsensusState.height, keccak256(abi.encode(newConsensusState.consensusState))
+                );
+                updateResult = UpdateResult.Update;
+            }
+            trustedConsensusState = newConsensusState.consensusState;
+        }
+
+        if (updateResult == UpdateResult.NoOp) {
+            return UpdateResult.NoOp;
+        }
+
+        verifySP1Proof(sp1Proof);
+
+        return updateResult;
     }
 
     /// @notice Handles the `SP1MembershipProof` proof type.
@@ -219,33 +319,19 @@ contract SP1ICS07Tendermint is
             output.kvPairs.length > 0 && output.kvPairs.length <= 256, LengthIsOutOfRange(output.kvPairs.length, 1, 256)
         );
 
-        {
-            // loop through the key-value pairs and validate them
-            bool found = false;
-            for (uint8 i = 0; i < output.kvPairs.length; i++) {
-                if (!Paths.equal(output.kvPairs[i].path, kvPath)) {
-                    continue;
-                }
-
-                bytes memory value = output.kvPairs[i].value;
-                require(
-                    value.length == kvValue.length && keccak256(value) == keccak256(kvValue),
-                    MembershipProofValueMismatch(kvValue, value)
-                );
+        validateKvPair(output.kvPairs, output.hashedValues, kvPath, kvValue);
 
-                found = true;
-                break;
-            }
-            require(found, MembershipProofKeyNotFound(kvPath));
-        }
-
-        validateMembershipOutput(output.commitmentRoot, proofHeight.revisionHeight, proof.trustedConsensusState);
+        validateMembershipOutput(output.commitmentRoot, proofHeight, proof.trustedConsensusState);
+        require(
+            output.proofSpecsHash == clientState.proofSpecsHash,
+            ProofSpecsHashMismatch(clientState.proofSpecsHash, output.proofSpecsHash)
+        );
 
         verifySP1Proof(proof.sp1Proof);
 
         // We avoid the cost of caching for single kv pairs, as reusing the proof is not necessary
         if (output.kvPairs.length > 1) {
-            cacheKvPairs(proofHeight.revisionHeight, output.kvPairs, proof.trustedConsensusState.timestamp);
+            cacheKvPairs(proofHeight, output.kvPairs, output.hashedValues, proof.trustedConsensusState.timestamp);
         }
         return proof.trustedConsensusState.timestamp;
     }
@@ -303,59 +389,117 @@ contract SP1ICS07Tendermint is
             UpdateResult updateResult = checkUpdateResult(output.updateClientOutput);
             if (updateResult == UpdateResult.Update) {
                 // adding the new consensus state to the mapping
-                if (proofHeight.revisionHeight > clientState.latestHeight.revisionHeight) {
-                    clientState.latestHeight = proofHeight;
+                if (Heights.gt(proofHeight, clientState.latestHeight)) {
+                    setLatestHeight(proofHeight);
                 }
-                consensusStateHashes[proofHeight.revisionHeight] =
-                    keccak256(abi.encode(output.updateClientOutput.newConsensusState));
+                setConsensusStateHash(proofHeight, keccak256(abi.encode(output.updateClientOutput.newConsensusState)));
             } else if (updateResult == UpdateResult.Misbehaviour) {
                 clientState.isFrozen = true;
                 revert CannotHandleMisbehavior();
             } // else: NoOp
         }
 
-        // loop through the key-value pairs and validate them
-        {
-            bool found = false;
-            for (uint8 i = 0; i < output.kvPairs.length; i++) {
-                if (!Paths.equal(output.kvPairs[i].path, kvPath)) {
-                    continue;
-                }
-
-                bytes memory value = output.kvPairs[i].value;
-                require(
-                    value.length == kvValue.length && keccak256(value) == keccak256(kvValue),
-                    MembershipProofValueMismatch(kvValue, value)
-                );
-
-                found = true;
-                break;
-            }
-            require(found, MembershipProofKeyNotFound(kvPath));
-        }
+        validateKvPair(output.kvPairs, output.hashedValues, kvPath, kvValue);
 
         validateMembershipOutput(
             output.updateClientOutput.newConsensusState.root,
-            output.updateClientOutput.newHeight.revisionHeight,
+            output.updateClientOutput.newHeight,
             output.updateClientOutput.newConsensusState
         );
 
         // We avoid the cost of caching for single kv pairs, as reusing the proof is not necessary
         if (output.kvPairs.length > 1) {
             cacheKvPairs(
-                proofHeight.revisionHeight, output.kvPairs, output.updateClientOutput.newConsensusState.timestamp
+                proofHeight, output.kvPairs, output.hashedValues, output.updateClientOutput.newConsensusState.timestamp
             );
         }
         return output.updateClientOutput.newConsensusState.timestamp;
     }
 
+    /// @notice Handles the `SP1MultiMembershipProof` proof type.
+    /// @dev All the key-value pairs of the proof are cached, so that the other heights of the proof can be verified
+    /// @dev with cached proofs in the same transaction.
+    /// @param proofHeight The height of the proof.
+    /// @param proofBytes The encoded proof.
+    /// @param kvPath The path of the key-value pair.
+    /// @param kvValue The value of the key-value pair.
+    /// @return The timestamp of the trusted consensus state at the proof height.
+    function handleSP1MultiMembershipProof(
+        Height calldata proofHeight,
+        bytes memory proofBytes,
+        bytes[] calldata kvPath,
+        bytes calldata kvValue
+    )
+        private
+        returns (uint256)
+    {
+        SP1MultiMembershipProof memory proof = abi.decode(proofBytes, (SP1MultiMembershipProof));
+        require(
+            proof.sp1Proof.vKey == MULTI_MEMBERSHIP_PROGRAM_VKEY,
+            VerificationKeyMismatch(MULTI_MEMBERSHIP_PROGRAM_VKEY, proof.sp1Proof.vKey)
+        );
+
+        MultiMembershipOutput memory output = abi.decode(proof.sp1Proof.publicValues, (MultiMembershipOutput));
+        require(
+            output.groups.length > 0 && output.groups.length <= 256, LengthIsOutOfRange(output.groups.length, 1, 256)
+        );
+        require(
+            output.proofSpecsHash == clientState.proofSpecsHash,
+            ProofSpecsHashMismatch(clientState.proofSpecsHash, output.proofSpecsHash)
+        );
+
+        // the groups are ordered by strictly increasing height, so there is at most one group at the proof height
+        uint256 timestamp = 0;
+        for (uint256 i = 0; i < output.groups.length; i++) {
+            MembershipGroup memory group = output.groups[i];
+            require(
+                group.kvPairs.length > 0 && group.kvPairs.length <= 256,
+                LengthIsOutOfRange(group.kvPairs.length, 1, 256)
+            );
+            validateMembershipOutput(group.trustedConsensusState.root, group.height, group.trustedConsensusState);
+
+            if (
+                group.height.revisionNumber == proofHeight.revisionNumber
+                    && group.height.revisionHeight == proofHeight.revisionHeight
+            ) {
+                validateKvPair(group.kvPairs, output.hashedValues, kvPath, kvValue);
+                timestamp = group.trustedConsensusState.timestamp;
+            }
+        }
+        require(timestamp != 0, MembershipProofKeyNotFound(kvPath));
+
+        verifySP1Proof(proof.sp1Proof);
+
+        for (uint256 i = 0; i < output.groups.length; i++) {
+            cacheKvPairs(
+                output.groups[i].height,
+                output.groups[i].kvPairs,
+                output.hashedValues,
+                output.groups[i].trustedConsensusState.timestamp
+            );
+        }
+        return timestamp;
+    }
+
+    /// @notice Sets the latest height of the client, following the chain to a new revision if needed.
+    /// @dev The programs only verify a header of a new revision if its chain ID is the same chain with the new revision
+    /// @dev number, e.g. `foo-2` for a client of `foo-1`. Revision heights keep increasing across revisions, since the
+    /// @dev header of a new revision must be verified from a trusted header of the previous revision.
+    /// @param newHeight The new latest height.
+    function setLatestHeight(Height memory newHeight) private {
+        if (newHeight.revisionNumber != clientState.latestHeight.revisionNumber) {
+            clientState.chainId = Heights.withRevision(clientState.chainId, newHeight.revisionNumber);
+        }
+        clientState.latestHeight = newHeight;
+    }
+
     /// @notice Validates the MembershipOutput public values.
     /// @param outputCommitmentRoot The commitment root of the output.
     /// @param proofHeight The height of the proof.
     /// @param trustedConsensusState The trusted consensus state
     function validateMembershipOutput(
         bytes32 outputCommitmentRoot,
-        uint32 proofHeight,
+        Height memory proofHeight,
         ConsensusState memory trustedConsensusState
     )
         private
@@ -374,19 +518,106 @@ contract SP1ICS07Tendermint is
         );
     }
 
+    /// @notice Validates that the key-value pairs contain the value at the path.
+    /// @param kvPairs The key-value pairs verified by a program.
+    /// @param hashedValues Whether the program committed the keccak256 hashes of the values.
+    /// @param kvPath The path of the key-value pair.
+    /// @param kvValue The value of the key-value pair.
+    function validateKvPair(
+        KVPair[] memory kvPairs,
+        bool hashedValues,
+        bytes[] calldata kvPath,
+        bytes calldata kvValue
+    )
+        private
+        pure
+    {
+        bytes memory committedValue = committedKvValue(kvValue, hashedValues);
+        for (uint256 i = 0; i < kvPairs.length; i++) {
+            if (!Paths.equal(kvPairs[i].path, kvPath)) {
+                continue;
+            }
+
+            bytes memory value = kvPairs[i].value;
+            require(
+                value.length == committedValue.length && keccak256(value) == keccak256(committedValue),
+                MembershipProofValueMismatch(committedValue, value)
+            );
+            return;
+        }
+        revert MembershipProofKeyNotFound(kvPath);
+    }
+
+    /// @notice Returns the value of a key-value pair as committed by a program.
+    /// @dev The value of a non-membership proof is empty, so it is never hashed.
+    /// @param value The value of the key-value pair.
+    /// @param hashedValues Whether the program committed the keccak256 hashes of the values.
+    /// @return The committed value.
+    function committedKvValue(bytes memory value, bool hashedValues) private pure returns (bytes memory) {
+        if (!hashedValues || value.length == 0) {
+            return value;
+        }
+        return abi.encodePacked(keccak256(value));
+    }
+
     /// @notice Validates the SP1ICS07UpdateClientOutput public values.
     /// @param output The public values.
     function validateUpdateClientPublicValues(UpdateClientOutput memory output) private view {
         validateClientStateAndTime(output.clientState, output.time);
 
         bytes32 outputConsensusStateHash = keccak256(abi.encode(output.trustedConsensusState));
-        bytes32 storedConsensusStateHash = getConsensusStateHash(output.trustedHeight.revisionHeight);
+        bytes32 storedConsensusStateHash = getConsensusStateHash(output.trustedHeight);
         require(
             outputConsensusStateHash == storedConsensusStateHash,
             ConsensusStateHashMismatch(storedConsensusStateHash, outputConsensusStateHash)
         );
     }
 
+    /// @notice Validates the UpgradeClientOutput public values.
+    /// @param output The public values.
+    function validateUpgradeClientOutput(UpgradeClientOutput memory output) private view {
+        validateClientStateAndTime(output.clientState, output.time);
+
+        require(
+            output.trustedHeight.revisionNumber == clientState.latestHeight.revisionNumber
+                && output.trustedHeight.revisionHeight == clientState.latestHeight.revisionHeight,
+            ProofHeightMismatch(
+                clientState.latestHeight.revisionNumber,
+                clientState.latestHeight.revisionHeight,
+                output.trustedHeight.revisionNumber,
+                output.trustedHeight.revisionHeight
+            )
+        );
+
+        bytes32 outputConsensusStateHash = keccak256(abi.encode(output.trustedConsensusState));
+        bytes32 storedConsensusStateHash = getConsensusStateHash(output.trustedHeight);
+        require(
+            outputConsensusStateHash == storedConsensusStateHash,
+            ConsensusStateHashMismatch(storedConsensusStateHash, outputConsensusStateHash)
+        );
+
+        Height memory newHeight = output.newClientState.latestHeight;
+        require(
+            Heights.gt(newHeight, output.trustedHeight),
+            UpgradeHeightNotIncreasing(
+                output.trustedHeight.revisionNumber,
+                output.trustedHeight.revisionHeight,
+                newHeight.revisionNumber,
+                newHeight.revisionHeight
+            )
+        );
+
+        require(
+            !output.newClientState.isFrozen && output.newClientState.zkAlgorithm == clientState.zkAlgorithm
+                && output.newClientState.proofSpecsHash == clientState.proofSpecsHash,
+            ClientStateMismatch(abi.encode(clientState), abi.encode(output.newClientState))
+        );
+        require(
+            output.newClientState.trustingPeriod <= output.newClientState.unbondingPeriod,
+            TrustingPeriodTooLong(output.newClientState.trustingPeriod, output.newClientState.unbondingPeriod)
+        );
+    }
+
     /// @notice Validates the SP1ICS07MisbehaviourOutput public values.
     /// @param output The public values.
     function validateMisbehaviourOutput(MisbehaviourOutput memory output) private view {
@@ -395,7 +626,7 @@ contract SP1ICS07Tendermint is
         // make sure the trusted consensus state from header 1 is known (trusted) by matching it with the the one in the
         // mapping
         bytes32 outputConsensusStateHash1 = keccak256(abi.encode(output.trustedConsensusState1));
-        bytes32 storedConsensusStateHash1 = getConsensusStateHash(output.trustedHeight1.revisionHeight);
+        bytes32 storedConsensusStateHash1 = getConsensusStateHash(output.trustedHeight1);
         require(
             outputConsensusStateHash1 == storedConsensusStateHash1,
             ConsensusStateHashMismatch(storedConsensusStateHash1, outputConsensusStateHash1)
@@ -404,7 +635,7 @@ contract SP1ICS07Tendermint is
         // make sure the trusted consensus state from header 2 is known (trusted) by matching it with the the one in the
         // mapping
         bytes32 outputConsensusStateHash2 = keccak256(abi.encode(output.trustedConsensusState2));
-        bytes32 storedConsensusStateHash2 = getConsensusStateHash(output.trustedHeight2.revisionHeight);
+        bytes32 storedConsensusStateHash2 = getConsensusStateHash(output.trustedHeight2);
         require(
             outputConsensusStateHash2 == storedConsensusStateHash2,
             ConsensusStateHashMismatch(storedConsensusStateHash2, outputConsensusStateHash2)
@@ -444,6 +675,14 @@ contract SP1ICS07Tendermint is
             publicClientState.unbondingPeriod == clientState.unbondingPeriod,
             UnbondingPeriodMismatch(clientState.unbondingPeriod, publicClientState.unbondingPeriod)
         );
+        require(
+            publicClientState.maxClockDrift == clientState.maxClockDrift,
+            MaxClockDriftMismatch(clientState.maxClockDrift, publicClientState.maxClockDrift)
+        );
+        require(
+            publicClientState.proofSpecsHash == clientState.proofSpecsHash,
+            ProofSpecsHashMismatch(clientState.proofSpecsHash, publicClientState.proofSpecsHash)
+        );
     }
 
     /// @notice Checks for basic misbehaviour.
@@ -453,13 +692,31 @@ contract SP1ICS07Tendermint is
     /// @param output The public values of the update client program.
     /// @return The result of the update.
     function checkUpdateResult(UpdateClientOutput memory output) private view returns (UpdateResult) {
-        bytes32 consensusStateHash = consensusStateHashes[output.newHeight.revisionHeight];
+        return checkConsensusStateUpdate(output.trustedConsensusState, output.newHeight, output.newConsensusState);
+    }
+
+    /// @notice Checks for basic misbehaviour of a consensus state verified from a trusted consensus state.
+    /// @dev See checkUpdateResult.
+    /// @param trustedConsensusState The trusted consensus state that the new one was verified from.
+    /// @param newHeight The height of the new consensus state.
+    /// @param newConsensusState The new consensus state.
+    /// @return The result of the update.
+    function checkConsensusStateUpdate(
+        ConsensusState memory trustedConsensusState,
+        Height memory newHeight,
+        ConsensusState memory newConsensusState
+    )
+        private
+        view
+        returns (UpdateResult)
+    {
+        bytes32 consensusStateHash = consensusStateHashes[newHeight.revisionNumber][newHeight.revisionHeight];
         if (consensusStateHash == bytes32(0)) {
             // No consensus state at the new height, so no misbehaviour
             return UpdateResult.Update;
         } else if (
-            consensusStateHash != keccak256(abi.encode(output.newConsensusState))
-                || output.trustedConsensusState.timestamp >= output.newConsensusState.timestamp
+            consensusStateHash != keccak256(abi.encode(newConsensusState))
+                || trustedConsensusState.timestamp >= newConsensusState.timestamp
         ) {
             // The consensus state at the new height is different than the one in the mapping
             // or the timestamp is not increasing
@@ -470,6 +727,15 @@ contract SP1ICS07Tendermint is
         }
     }
 
+    /// @notice Stores the keccak256 hash of the consensus state at the given height.
+    /// @dev Emits a `ConsensusStateAdded` event, which the misbehaviour monitor of the operator scans.
+    /// @param height The height of the consensus state.
+    /// @param consensusStateHash The keccak256 hash of the consensus state.
+    function setConsensusStateHash(Height memory height, bytes32 consensusStateHash) private {
+        consensusStateHashes[height.revisionNumber][height.revisionHeight] = consensusStateHash;
+        emit ConsensusStateAdded(height, consensusStateHash);
+    }
+
     /// @notice Verifies the SP1 proof
     /// @param proof The SP1 proof.
     function verifySP1Proof(SP1Proof memory proof) private view {
@@ -477,26 +743,50 @@ contract SP1ICS07Tendermint is
     }
 
     /// @notice Caches the key-value pairs to the transient storage with the timestamp.
+    /// @dev The pairs are cached with their committed values, under a key that includes whether these are hashed, so
+    /// @dev that a hashed value is never mistaken for a value.
     /// @param proofHeight The height of the proof.
     /// @param kvPairs The key-value pairs.
+    /// @param hashedValues Whether the program committed the keccak256 hashes of the values.
     /// @param timestamp The timestamp of the trusted consensus state.
     /// @dev WARNING: Transient store is not reverted even if a message within a transaction reverts.
     /// @dev WARNING: This function must be called after all proof and validation checks.
-    function cacheKvPairs(uint32 proofHeight, KVPair[] memory kvPairs, uint256 timestamp) private {
-        for (uint8 i = 0; i < kvPairs.length; i++) {
-            bytes32 kvPairHash = keccak256(abi.encode(proofHeight, kvPairs[i]));
+    function cacheKvPairs(
+        Height memory proofHeight,
+        KVPair[] memory kvPairs,
+        bool hashedValues,
+        uint256 timestamp
+    )
+        private
+    {
+        for (uint256 i = 0; i < kvPairs.length; i++) {
+            bytes32 kvPairHash = keccak256(abi.encode(proofHeight, kvPairs[i], hashedValues));
             kvPairHash.asUint256().tstore(timestamp);
         }
     }
 
     /// @notice Gets the timestamp of the cached key-value pair from the transient storage.
+    /// @dev The pair is looked up with its value first, and then with the hash of its value.
     /// @param proofHeight The height of the proof.
-    /// @param kvPair The key-value pair.
+    /// @param kvPath The path of the key-value pair.
+    /// @param kvValue The value of the key-value pair.
     /// @return The timestamp of the cached key-value pair.
-    function getCachedKvPair(uint32 proofHeight, KVPair memory kvPair) private view returns (uint256) {
-        bytes32 kvPairHash = keccak256(abi.encode(proofHeight, kvPair));
+    function getCachedKvPair(
+        Height calldata proofHeight,
+        bytes[] calldata kvPath,
+        bytes calldata kvValue
+    )
+        private
+        view
+        returns (uint256)
+    {
+        bytes32 kvPairHash = keccak256(abi.encode(proofHeight, KVPair(kvPath, kvValue), false));
         uint256 timestamp = kvPairHash.asUint256().tload();
-        require(timestamp != 0, KeyValuePairNotInCache(kvPair.path, kvPair.value));
+        if (timestamp == 0) {
+            kvPairHash = keccak256(abi.encode(proofHeight, KVPair(kvPath, committedKvValue(kvValue, true)), true));
+            timestamp = kvPairHash.asUint256().tload();
+        }
+        require(timestamp != 0, KeyValuePairNotInCache(kvPath, kvValue));
         return timestamp;
     }
 
@@ -514,6 +804,11 @@ contract SP1ICS07Tendermint is
     /// @param o6 The SP1MembershipAndUpdateClientProof.
     /// @param o7 The MisbehaviourOutput.
     /// @param o8 The MsgSubmitMisbehaviour.
+    /// @param o9 The UpgradeClientOutput.
+    /// @param o10 The MsgUpgradeClient.
+    /// @param o11 The MultiUpdateClientOutput.
+    /// @param o12 The MultiMembershipOutput.
+    /// @param o13 The SP1MultiMembershipProof.
     function abiPublicTypes(
         MembershipOutput memory o1,
         UcAndMembershipOutput memory o2,
@@ -522,7 +817,12 @@ contract SP1ICS07Tendermint is
         SP1MembershipProof memory o5,
         SP1MembershipAndUpdateClientProof memory o6,
         MisbehaviourOutput memory o7,
-        MsgSubmitMisbehaviour memory o8
+        MsgSubmitMisbehaviour memory o8,
+        UpgradeClientOutput memory o9,
+        MsgUpgradeClient memory o10,
+        MultiUpdateClientOutput memory o11,
+        MultiMembershipOutput memory o12,
+        SP1MultiMembershipProof memory o13
     )
         public
         pure
@@ -44,6 +44,16 @@ interface ISP1ICS07TendermintErrors {
     /// @param actual The actual unbonding period in seconds.
     error UnbondingPeriodMismatch(uint256 expected, uint256 actual);
 
+    /// @notice The error that is returned when the max clock drift does not match the expected value.
+    /// @param expected The expected max clock drift in seconds.
+    /// @param actual The actual max clock drift in seconds.
+    error MaxClockDriftMismatch(uint256 expected, uint256 actual);
+
+    /// @notice The error that is returned when the proof specs hash does not match the expected value.
+    /// @param expected The expected proof specs hash.
+    /// @param actual The actual proof specs hash.
+    error ProofSpecsHashMismatch(bytes32 expected, bytes32 actual);
+
     /// @notice The error that is returned when the trusting period is longer than the unbonding period.
     /// @param trustingPeriod The trusting period in seconds.
     /// @param unbondingPeriod The unbonding period in seconds.
@@ -98,6 +108,15 @@ interface ISP1ICS07TendermintErrors {
         uint64 actualRevisionHeight
     );
 
+    /// @notice The error that is returned when the height of an upgraded client is not greater than the upgrade height.
+    /// @param upgradeRevisionNumber The revision number of the upgrade height.
+    /// @param upgradeRevisionHeight The revision height of the upgrade height.
+    /// @param newRevisionNumber The revision number of the upgraded client.
+    /// @param newRevisionHeight The revision height of the upgraded client.
+    error UpgradeHeightNotIncreasing(
+        uint64 upgradeRevisionNumber, uint64 upgradeRevisionHeight, uint64 newRevisionNumber, uint64 newRevisionHeight
+    );
+
     /// @notice The error that is returned when the membership proof type is unknown.
     /// @param proofType The unknown membership proof type.
     error UnknownMembershipProofType(uint8 proofType);
@@ -23,6 +23,8 @@ interface IICS07TendermintMsgs is IICS02ClientMsgs, ISP1Msgs {
     /// @param trustingPeriod duration of the period since the LatestTimestamp during which the
     /// submitted headers are valid for upgrade in seconds.
     /// @param unbondingPeriod duration of the staking unbonding period in seconds
+    /// @param maxClockDrift maximum duration in seconds that a header can be ahead of the time of the proof
+    /// @param proofSpecsHash sha256 hash of the ICS-23 proof specs of the (non)membership proofs
     /// @param isFrozen whether or not client is frozen (due to misbehavior)
     /// @param zkAlgorithm The zk algorithm supported by this contract.
     struct ClientState {
@@ -31,6 +33,8 @@ interface IICS07TendermintMsgs is IICS02ClientMsgs, ISP1Msgs {
         Height latestHeight;
         uint32 trustingPeriod;
         uint32 unbondingPeriod;
+        uint32 maxClockDrift;
+        bytes32 proofSpecsHash;
         bool isFrozen;
         SupportedZkAlgorithm zkAlgorithm;
     }
@@ -10,7 +10,8 @@ import { IICS07TendermintMsgs } from "./IICS07TendermintMsgs.sol";
 interface IMembershipMsgs is ISP1Msgs {
     /// @notice The key-value pair used in the verify (non)membership program.
     /// @param path The path of the value in the key-value store.
-    /// @param value The value of the key-value pair.
+    /// @param value The value of the key-value pair, or its keccak256 hash if the values are hashed. The value is
+    /// empty for a non-membership proof.
     struct KVPair {
         bytes[] path;
         bytes value;
@@ -19,9 +20,13 @@ interface IMembershipMsgs is ISP1Msgs {
     /// @notice The public value output for the sp1 verify (non)membership program.
     /// @param commitmentRoot The app hash of the header.
     /// @param kvPairs The key-value pairs verified by the program.
+    /// @param proofSpecsHash The sha256 hash of the ICS-23 proof specs used to verify the key-value pairs.
+    /// @param hashedValues Whether the values of the key-value pairs are committed as their keccak256 hashes.
     struct MembershipOutput {
         bytes32 commitmentRoot;
         KVPair[] kvPairs;
+        bytes32 proofSpecsHash;
+        bool hashedValues;
     }
 
     /// @notice The membership proof that can be submitted to the SP1Verifier contract.
@@ -46,11 +51,19 @@ interface IMembershipMsgs is ISP1Msgs {
         SP1Proof sp1Proof;
     }
 
+    /// @notice The membership proof for the sp1 verify (non)membership at multiple heights program.
+    /// @param sp1Proof The sp1 proof for the multi-height membership program.
+    struct SP1MultiMembershipProof {
+        SP1Proof sp1Proof;
+    }
+
     /// @notice The type of the membership proof.
     enum MembershipProofType {
         /// The proof is for the verify membership program.
         SP1MembershipProof,
         /// The proof is for the verify membership and update client program.
-        SP1MembershipAndUpdateClientProof
+        SP1MembershipAndUpdateClientProof,
+        /// The proof is for the verify membership at multiple heights program.
+        SP1MultiMembershipProof
     }
 }
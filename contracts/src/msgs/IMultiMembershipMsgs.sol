@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+import { IICS07TendermintMsgs } from "./IICS07TendermintMsgs.sol";
+import { IMembershipMsgs } from "./IMembershipMsgs.sol";
+
+/// @title Multi-Height Membership Program Messages
+/// @author srdtrk
+/// @notice Defines shared types for the verify (non)membership at multiple heights program.
+interface IMultiMembershipMsgs is IICS07TendermintMsgs {
+    /// @notice The key-value pairs verified against the root of a trusted consensus state.
+    /// @param height The height of the trusted consensus state.
+    /// @param trustedConsensusState The trusted consensus state that the key-value pairs are verified against.
+    /// @param kvPairs The key-value pairs verified by the program.
+    struct MembershipGroup {
+        Height height;
+        ConsensusState trustedConsensusState;
+        IMembershipMsgs.KVPair[] kvPairs;
+    }
+
+    /// @notice The public value output for the sp1 verify (non)membership at multiple heights program.
+    /// @param groups The groups of key-value pairs, ordered by strictly increasing height.
+    /// @param proofSpecsHash The sha256 hash of the ICS-23 proof specs used to verify the key-value pairs.
+    /// @param hashedValues Whether the values of the key-value pairs are committed as their keccak256 hashes.
+    struct MultiMembershipOutput {
+        MembershipGroup[] groups;
+        bytes32 proofSpecsHash;
+        bool hashedValues;
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+import { IICS07TendermintMsgs } from "./IICS07TendermintMsgs.sol";
+
+/// @title Multi-Header Update Client Program Messages
+/// @author srdtrk
+/// @notice Defines shared types for the multi-header update client program.
+/// @dev The proof of the program is submitted to the updateClient function in a MsgUpdateClient.
+interface IMultiUpdateClientMsgs is IICS07TendermintMsgs {
+    /// @notice A consensus state verified by the multi-header update client program.
+    /// @param height The height of the verified header.
+    /// @param consensusState The consensus state of the verified header.
+    struct ConsensusStateAtHeight {
+        Height height;
+        ConsensusState consensusState;
+    }
+
+    /// @notice The public value output for the sp1 multi-header update client program.
+    /// @param clientState The client state that was used to verify the headers.
+    /// @param trustedConsensusState The trusted consensus state of the first header.
+    /// @param newConsensusStates The new consensus states of the verified headers, in order, each verified from the
+    /// previous one.
+    /// @param time The time which the headers were verified in seconds.
+    /// @param trustedHeight The trusted height of the first header.
+    struct MultiUpdateClientOutput {
+        ClientState clientState;
+        ConsensusState trustedConsensusState;
+        ConsensusStateAtHeight[] newConsensusStates;
+        uint64 time;
+        Height trustedHeight;
+    }
+}
@@ -11,8 +11,10 @@ interface IUpdateClientAndMembershipMsgs is IUpdateClientMsgs {
     /// @notice The public value output for the sp1 update client and membership program.
     /// @param updateClientOutput The output of the update client program.
     /// @param kvPairs The key-value pairs verified by the membership program in the proposed header.
+    /// @param hashedValues Whether the values of the key-value pairs are committed as their keccak256 hashes.
     struct UcAndMembershipOutput {
         UpdateClientOutput updateClientOutput;
         IMembershipMsgs.KVPair[] kvPairs;
+        bool hashedValues;
     }
 }
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+import { IICS07TendermintMsgs } from "./IICS07TendermintMsgs.sol";
+
+/// @title Upgrade Client Program Messages
+/// @author srdtrk
+/// @notice Defines shared types for the upgrade client program.
+interface IUpgradeClientMsgs is IICS07TendermintMsgs {
+    /// @notice The message that is submitted to the upgradeClient function.
+    /// @param sp1Proof The SP1 proof for upgrading the client.
+    struct MsgUpgradeClient {
+        SP1Proof sp1Proof;
+    }
+
+    /// @notice The public value output for the sp1 upgrade client program.
+    /// @param clientState The client state that was used to verify the upgrade.
+    /// @param trustedConsensusState The trusted consensus state, whose root commits to the upgraded states.
+    /// @param newClientState The upgraded client state.
+    /// @param newConsensusState The upgraded consensus state.
+    /// @param time The time which the upgrade was verified in seconds.
+    /// @param trustedHeight The trusted height, i.e. the height of the upgrade plan.
+    struct UpgradeClientOutput {
+        ClientState clientState;
+        ConsensusState trustedConsensusState;
+        ClientState newClientState;
+        ConsensusState newConsensusState;
+        uint64 time;
+        Height trustedHeight;
+    }
+}
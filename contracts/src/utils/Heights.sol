@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { IICS02ClientMsgs } from "solidity-ibc/msgs/IICS02ClientMsgs.sol";
+import { Strings } from "@openzeppelin/utils/Strings.sol";
+
+library Heights {
+    /// @notice Compares two heights, ordered by revision number and then by revision height
+    /// @param a The first height
+    /// @param b The second height
+    /// @return True if the first height is greater than the second height, false otherwise
+    function gt(IICS02ClientMsgs.Height memory a, IICS02ClientMsgs.Height memory b) internal pure returns (bool) {
+        if (a.revisionNumber != b.revisionNumber) {
+            return a.revisionNumber > b.revisionNumber;
+        }
+        return a.revisionHeight > b.revisionHeight;
+    }
+
+    /// @notice Returns the chain ID of another revision of the same chain, e.g. `foo-2` for `foo-1` and revision 2
+    /// @dev A chain ID without a revision, e.g. `foo`, is treated as revision 0 of the chain `foo`. The revision is
+    /// @dev parsed as by `split_chain_id` of the programs, so e.g. `foo-01` is revision 0 of the chain `foo-01`.
+    /// @param chainId The chain ID
+    /// @param revisionNumber The revision number of the returned chain ID
+    /// @return The chain ID with the given revision number
+    function withRevision(string memory chainId, uint32 revisionNumber) internal pure returns (string memory) {
+        bytes memory id = bytes(chainId);
+        uint256 nameLength = chainNameLength(id);
+
+        bytes memory name = new bytes(nameLength);
+        for (uint256 i = 0; i < nameLength; i++) {
+            name[i] = id[i];
+        }
+        return string.concat(string(name), "-", Strings.toString(revisionNumber));
+    }
+
+    /// @notice Returns the length of the chain name of a chain ID, i.e. without its revision suffix
+    /// @dev The revision suffix is the digits after the last dash, without a leading zero, that fit in a uint64
+    /// @param id The chain ID
+    /// @return The length of the chain name
+    function chainNameLength(bytes memory id) private pure returns (uint256) {
+        // find the last dash, after which there must only be digits
+        uint256 dash = id.length;
+        for (uint256 i = id.length; i > 0; i--) {
+            bytes1 char = id[i - 1];
+            if (char == "-") {
+                dash = i - 1;
+                break;
+            }
+            if (char < "0" || char > "9") {
+                return id.length;
+            }
+        }
+
+        if (dash == id.length) {
+            return id.length;
+        }
+        uint256 digits = id.length - dash - 1;
+        if (digits == 0 || digits > 20 || (digits > 1 && id[dash + 1] == "0")) {
+            return id.length;
+        }
+
+        uint256 revision = 0;
+        for (uint256 i = dash + 1; i < id.length; i++) {
+            revision = revision * 10 + uint8(id[i]) - uint8(bytes1("0"));
+        }
+        if (revision > type(uint64).max) {
+            return id.length;
+        }
+        return dash;
+    }
+}
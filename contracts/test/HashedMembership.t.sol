@@ -0,0 +1,185 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+// solhint-disable-next-line no-global-import
+import "forge-std/console.sol";
+import { MembershipTest } from "./MembershipTest.sol";
+
+contract SP1ICS07HashedMembershipTest is MembershipTest {
+    function setUp() public {
+        setUpMockTest();
+    }
+
+    function test_ValidPlainMembership() public {
+        MsgMembership memory membershipMsg = mockMembershipMsg(false, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE);
+        mockIcs07Tendermint.membership(membershipMsg);
+
+        // the cached pairs are found with their values
+        membershipMsg.proof = bytes("");
+        mockIcs07Tendermint.membership(membershipMsg);
+        membershipMsg.path = verifyNonMembershipPath;
+        membershipMsg.value = bytes("");
+        mockIcs07Tendermint.membership(membershipMsg);
+    }
+
+    function test_ValidHashedMembership() public {
+        MsgMembership memory membershipMsg = mockMembershipMsg(true, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE);
+        mockIcs07Tendermint.membership(membershipMsg);
+        console.log("Hashed VerifyMembership gas used: ", vm.lastCallGas().gasTotalUsed);
+
+        // the value of a non-membership proof is not hashed
+        MsgMembership memory nonMembershipMsg = mockMembershipMsg(true, verifyNonMembershipPath, bytes(""));
+        mockIcs07Tendermint.membership(nonMembershipMsg);
+
+        // the cached pairs are found with their values
+        membershipMsg.proof = bytes("");
+        mockIcs07Tendermint.membership(membershipMsg);
+        nonMembershipMsg.proof = bytes("");
+        mockIcs07Tendermint.membership(nonMembershipMsg);
+    }
+
+    function test_ValidHashedUpdateClientAndMembership() public {
+        Height memory newHeight = Height({ revisionNumber: 4, revisionHeight: 110 });
+        UcAndMembershipOutput memory output = UcAndMembershipOutput({
+            updateClientOutput: UpdateClientOutput({
+                clientState: mockIcs07Tendermint.getClientState(),
+                trustedConsensusState: mockConsensusState(100),
+                newConsensusState: mockConsensusState(newHeight.revisionHeight),
+                time: MOCK_TIME,
+                trustedHeight: mockIcs07Tendermint.getClientState().latestHeight,
+                newHeight: newHeight
+            }),
+            kvPairs: committedKvPairs(true),
+            hashedValues: true
+        });
+        SP1MembershipAndUpdateClientProof memory proof = SP1MembershipAndUpdateClientProof({
+            sp1Proof: SP1Proof({
+                vKey: genesisFixture.ucAndMembershipVkey,
+                publicValues: abi.encode(output),
+                proof: bytes("")
+            })
+        });
+        MembershipProof memory membershipProof = MembershipProof({
+            proofType: MembershipProofType.SP1MembershipAndUpdateClientProof,
+            proof: abi.encode(proof)
+        });
+
+        MsgMembership memory membershipMsg = MsgMembership({
+            proof: abi.encode(membershipProof),
+            proofHeight: newHeight,
+            path: verifyMembershipPath,
+            value: VERIFY_MEMBERSHIP_VALUE
+        });
+        mockIcs07Tendermint.membership(membershipMsg);
+
+        membershipMsg.proof = bytes("");
+        membershipMsg.path = verifyNonMembershipPath;
+        membershipMsg.value = bytes("");
+        mockIcs07Tendermint.membership(membershipMsg);
+
+        // the hash is not a value of the proof
+        membershipMsg.proof = abi.encode(membershipProof);
+        membershipMsg.path = verifyMembershipPath;
+        membershipMsg.value = hashedValue();
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                MembershipProofValueMismatch.selector, abi.encodePacked(keccak256(hashedValue())), hashedValue()
+            )
+        );
+        mockIcs07Tendermint.membership(membershipMsg);
+    }
+
+    // A proof that commits the hash of a value must not prove the membership of the hash itself.
+    function test_Invalid_HashedValueAsPlainValue() public {
+        MsgMembership memory membershipMsg = mockMembershipMsg(true, verifyMembershipPath, hashedValue());
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                MembershipProofValueMismatch.selector, abi.encodePacked(keccak256(hashedValue())), hashedValue()
+            )
+        );
+        mockIcs07Tendermint.membership(membershipMsg);
+
+        // nor once the pairs are cached
+        membershipMsg.value = VERIFY_MEMBERSHIP_VALUE;
+        mockIcs07Tendermint.membership(membershipMsg);
+
+        membershipMsg.proof = bytes("");
+        membershipMsg.value = hashedValue();
+        vm.expectRevert(abi.encodeWithSelector(KeyValuePairNotInCache.selector, verifyMembershipPath, hashedValue()));
+        mockIcs07Tendermint.membership(membershipMsg);
+    }
+
+    // A proof that commits the values must not prove a value that hashes to a committed value.
+    function test_Invalid_HashedValueInPlainMode() public {
+        MembershipOutput memory output = MembershipOutput({
+            commitmentRoot: mockConsensusState(100).root,
+            kvPairs: committedKvPairs(true),
+            proofSpecsHash: mockIcs07Tendermint.getClientState().proofSpecsHash,
+            hashedValues: false
+        });
+        MsgMembership memory membershipMsg =
+            mockMembershipMsgWithOutput(output, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE);
+        vm.expectRevert(
+            abi.encodeWithSelector(MembershipProofValueMismatch.selector, VERIFY_MEMBERSHIP_VALUE, hashedValue())
+        );
+        mockIcs07Tendermint.membership(membershipMsg);
+    }
+
+    function hashedValue() private pure returns (bytes memory) {
+        return abi.encodePacked(keccak256(VERIFY_MEMBERSHIP_VALUE));
+    }
+
+    function committedKvPairs(bool hashedValues) private view returns (KVPair[] memory) {
+        KVPair[] memory kvPairs = new KVPair[](2);
+        kvPairs[0] =
+            KVPair({ path: verifyMembershipPath, value: hashedValues ? hashedValue() : VERIFY_MEMBERSHIP_VALUE });
+        kvPairs[1] = KVPair({ path: verifyNonMembershipPath, value: bytes("") });
+        return kvPairs;
+    }
+
+    function mockMembershipMsg(
+        bool hashedValues,
+        bytes[] memory path,
+        bytes memory value
+    )
+        private
+        view
+        returns (MsgMembership memory)
+    {
+        MembershipOutput memory output = MembershipOutput({
+            commitmentRoot: mockConsensusState(100).root,
+            kvPairs: committedKvPairs(hashedValues),
+            proofSpecsHash: mockIcs07Tendermint.getClientState().proofSpecsHash,
+            hashedValues: hashedValues
+        });
+        return mockMembershipMsgWithOutput(output, path, value);
+    }
+
+    function mockMembershipMsgWithOutput(
+        MembershipOutput memory output,
+        bytes[] memory path,
+        bytes memory value
+    )
+        private
+        view
+        returns (MsgMembership memory)
+    {
+        SP1MembershipProof memory proof = SP1MembershipProof({
+            sp1Proof: SP1Proof({
+                vKey: genesisFixture.membershipVkey,
+                publicValues: abi.encode(output),
+                proof: bytes("")
+            }),
+            trustedConsensusState: mockConsensusState(100)
+        });
+        MembershipProof memory membershipProof =
+            MembershipProof({ proofType: MembershipProofType.SP1MembershipProof, proof: abi.encode(proof) });
+
+        return MsgMembership({
+            proof: abi.encode(membershipProof),
+            proofHeight: mockIcs07Tendermint.getClientState().latestHeight,
+            path: path,
+            value: value
+        });
+    }
+}
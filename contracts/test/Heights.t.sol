@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.28;
+
+import { Test } from "forge-std/Test.sol";
+import { Heights } from "../src/utils/Heights.sol";
+
+contract HeightsTest is Test {
+    struct WithRevisionTestCase {
+        string name;
+        string chainId;
+        string expected;
+    }
+
+    function test_WithRevision() public pure {
+        // The chain IDs are parsed as by `split_chain_id` of the programs.
+        WithRevisionTestCase[] memory testCases = new WithRevisionTestCase[](9);
+        testCases[0] = WithRevisionTestCase({ name: "revision", chainId: "mocha-4", expected: "mocha-2" });
+        testCases[1] = WithRevisionTestCase({ name: "no revision", chainId: "mocha", expected: "mocha-2" });
+        testCases[2] = WithRevisionTestCase({ name: "revision 0", chainId: "mocha-0", expected: "mocha-2" });
+        testCases[3] = WithRevisionTestCase({ name: "leading zero", chainId: "mocha-04", expected: "mocha-04-2" });
+        testCases[4] = WithRevisionTestCase({ name: "empty revision", chainId: "mocha-", expected: "mocha--2" });
+        testCases[5] = WithRevisionTestCase({ name: "not a number", chainId: "mocha-4a", expected: "mocha-4a-2" });
+        testCases[6] = WithRevisionTestCase({ name: "dashes", chainId: "test-chain-12", expected: "test-chain-2" });
+        testCases[7] = WithRevisionTestCase({
+            name: "max uint64 revision",
+            chainId: "mocha-18446744073709551615",
+            expected: "mocha-2"
+        });
+        testCases[8] = WithRevisionTestCase({
+            name: "revision above max uint64",
+            chainId: "mocha-18446744073709551616",
+            expected: "mocha-18446744073709551616-2"
+        });
+
+        for (uint256 i = 0; i < testCases.length; i++) {
+            assertEq(Heights.withRevision(testCases[i].chainId, 2), testCases[i].expected, testCases[i].name);
+        }
+    }
+}
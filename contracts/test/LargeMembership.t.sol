@@ -13,6 +13,8 @@ contract SP1ICS07LargeMembershipTest is MembershipTest {
         setUpTestWithFixtures(fileName);
 
         proof = abi.decode(fixture.membershipProof.proof, (SP1MembershipProof));
+        MembershipOutput memory currentOutput;
+        skipIfStaleFixture(proof.sp1Proof.publicValues, abi.encode(currentOutput), 2);
     }
 
     function getOutput() public view returns (MembershipOutput memory) {
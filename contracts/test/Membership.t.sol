@@ -12,6 +12,8 @@ contract SP1ICS07MembershipTest is MembershipTest {
         setUpTestWithFixtures(fileName);
 
         proof = abi.decode(fixture.membershipProof.proof, (SP1MembershipProof));
+        MembershipOutput memory currentOutput;
+        skipIfStaleFixture(proof.sp1Proof.publicValues, abi.encode(currentOutput), 2);
     }
 
     function fixtureTestCases() public pure returns (FixtureTestCase[] memory) {
@@ -37,6 +39,8 @@ contract SP1ICS07MembershipTest is MembershipTest {
             assertEq(output.kvPairs[0].value, VERIFY_MEMBERSHIP_VALUE);
             assertEq(output.kvPairs[1].path, verifyNonMembershipPath);
             assertEq(output.kvPairs[1].value.length, 0);
+            assertEq(output.proofSpecsHash, mockIcs07Tendermint.getClientState().proofSpecsHash);
+            assertFalse(output.hashedValues);
         }
     }
 
@@ -159,7 +163,10 @@ contract SP1ICS07MembershipTest is MembershipTest {
         // It doesn't matter which fixture we use, as we use mock verifier
         setUpMembershipTestWithFixture("memberships_fixture-plonk.json");
 
-        MockInvalidMembershipTestCase[] memory testCases = new MockInvalidMembershipTestCase[](9);
+        MembershipOutput memory badProofSpecsOutput = abi.decode(proof.sp1Proof.publicValues, (MembershipOutput));
+        badProofSpecsOutput.proofSpecsHash = sha256("invalid");
+
+        MockInvalidMembershipTestCase[] memory testCases = new MockInvalidMembershipTestCase[](10);
         testCases[0] = MockInvalidMembershipTestCase({
             name: "success: valid mock",
             sp1Proof: SP1Proof({ proof: bytes(""), publicValues: proof.sp1Proof.publicValues, vKey: proof.sp1Proof.vKey }),
@@ -242,6 +249,18 @@ contract SP1ICS07MembershipTest is MembershipTest {
             value: bytes(""),
             expPass: false
         });
+        testCases[9] = MockInvalidMembershipTestCase({
+            name: "Invalid proof specs hash",
+            sp1Proof: SP1Proof({
+                proof: bytes(""),
+                publicValues: abi.encode(badProofSpecsOutput),
+                vKey: proof.sp1Proof.vKey
+            }),
+            proofHeight: fixture.proofHeight.revisionHeight,
+            path: verifyNonMembershipPath,
+            value: bytes(""),
+            expPass: false
+        });
 
         for (uint256 i = 0; i < testCases.length; i++) {
             MockInvalidMembershipTestCase memory tc = testCases[i];
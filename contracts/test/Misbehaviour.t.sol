@@ -170,6 +170,32 @@ contract SP1ICS07MisbehaviourTest is SP1ICS07TendermintTest {
         );
         ics07Tendermint.misbehaviour(submitMsgBz);
 
+        // max clock drift mismatch
+        badSubmitMsg = cloneSubmitMsg();
+        badOutput = cloneOutput();
+        badOutput.clientState.maxClockDrift = output.clientState.maxClockDrift + 1;
+        badSubmitMsg.sp1Proof.publicValues = abi.encode(badOutput);
+        submitMsgBz = abi.encode(badSubmitMsg);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                MaxClockDriftMismatch.selector, output.clientState.maxClockDrift, badOutput.clientState.maxClockDrift
+            )
+        );
+        ics07Tendermint.misbehaviour(submitMsgBz);
+
+        // proof specs hash mismatch
+        badSubmitMsg = cloneSubmitMsg();
+        badOutput = cloneOutput();
+        badOutput.clientState.proofSpecsHash = sha256("invalid");
+        badSubmitMsg.sp1Proof.publicValues = abi.encode(badOutput);
+        submitMsgBz = abi.encode(badSubmitMsg);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ProofSpecsHashMismatch.selector, output.clientState.proofSpecsHash, badOutput.clientState.proofSpecsHash
+            )
+        );
+        ics07Tendermint.misbehaviour(submitMsgBz);
+
         // invalid proof
         badSubmitMsg = cloneSubmitMsg();
         badOutput = cloneOutput();
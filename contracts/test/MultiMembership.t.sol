@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+// solhint-disable-next-line no-global-import
+import "forge-std/console.sol";
+import { MembershipTest } from "./MembershipTest.sol";
+
+contract SP1ICS07MultiMembershipTest is MembershipTest {
+    function setUp() public {
+        setUpMockTest();
+
+        // store a second consensus state to prove the key-value pairs at
+        UpdateClientOutput memory output = UpdateClientOutput({
+            clientState: mockIcs07Tendermint.getClientState(),
+            trustedConsensusState: mockConsensusState(100),
+            newConsensusState: mockConsensusState(110),
+            time: MOCK_TIME,
+            trustedHeight: height(100),
+            newHeight: height(110)
+        });
+        MsgUpdateClient memory updateMsg = MsgUpdateClient({
+            sp1Proof: SP1Proof({
+                vKey: genesisFixture.updateClientVkey,
+                publicValues: abi.encode(output),
+                proof: bytes("")
+            })
+        });
+        mockIcs07Tendermint.updateClient(abi.encode(updateMsg));
+    }
+
+    function test_ValidMultiMembership() public {
+        MultiMembershipOutput memory output = mockOutput(false);
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+        console.log("MultiMembership gas used: ", vm.lastCallGas().gasTotalUsed);
+
+        // the pairs of all the heights are cached
+        mockIcs07Tendermint.membership(cachedMembershipMsg(110, verifyNonMembershipPath, bytes("")));
+        mockIcs07Tendermint.membership(cachedMembershipMsg(100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // but only at their own height
+        vm.expectRevert(
+            abi.encodeWithSelector(KeyValuePairNotInCache.selector, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE)
+        );
+        mockIcs07Tendermint.membership(cachedMembershipMsg(110, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+    }
+
+    function test_ValidHashedMultiMembership() public {
+        MultiMembershipOutput memory output = mockOutput(true);
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        mockIcs07Tendermint.membership(cachedMembershipMsg(110, verifyNonMembershipPath, bytes("")));
+        mockIcs07Tendermint.membership(cachedMembershipMsg(100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // the hash is not a value of the proof
+        bytes memory hashedValue = abi.encodePacked(keccak256(VERIFY_MEMBERSHIP_VALUE));
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                MembershipProofValueMismatch.selector, abi.encodePacked(keccak256(hashedValue)), hashedValue
+            )
+        );
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, hashedValue));
+    }
+
+    function test_Invalid_MultiMembership() public {
+        // wrong vkey
+        MultiMembershipOutput memory output = mockOutput(false);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                VerificationKeyMismatch.selector, genesisFixture.multiMembershipVkey, genesisFixture.membershipVkey
+            )
+        );
+        mockIcs07Tendermint.membership(
+            membershipMsgWithVkey(
+                genesisFixture.membershipVkey, output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE
+            )
+        );
+
+        // no groups
+        output = mockOutput(false);
+        output.groups = new MembershipGroup[](0);
+        vm.expectRevert(abi.encodeWithSelector(LengthIsOutOfRange.selector, 0, 1, 256));
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // too many groups
+        output = mockOutput(false);
+        MembershipGroup[] memory groups = new MembershipGroup[](257);
+        for (uint256 i = 0; i < groups.length; i++) {
+            groups[i] = output.groups[0];
+        }
+        output.groups = groups;
+        vm.expectRevert(abi.encodeWithSelector(LengthIsOutOfRange.selector, 257, 1, 256));
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // group without key-value pairs
+        output = mockOutput(false);
+        output.groups[1].kvPairs = new KVPair[](0);
+        vm.expectRevert(abi.encodeWithSelector(LengthIsOutOfRange.selector, 0, 1, 256));
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // proof specs hash mismatch
+        output = mockOutput(false);
+        output.proofSpecsHash = sha256("invalid");
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ProofSpecsHashMismatch.selector,
+                mockIcs07Tendermint.getClientState().proofSpecsHash,
+                output.proofSpecsHash
+            )
+        );
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // trusted consensus state mismatch
+        output = mockOutput(false);
+        output.groups[1].trustedConsensusState = mockConsensusState(111);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ConsensusStateHashMismatch.selector,
+                keccak256(abi.encode(mockConsensusState(110))),
+                keccak256(abi.encode(mockConsensusState(111)))
+            )
+        );
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // no group at the proof height
+        output = mockOutput(false);
+        vm.expectRevert(abi.encodeWithSelector(MembershipProofKeyNotFound.selector, verifyMembershipPath));
+        mockIcs07Tendermint.membership(membershipMsg(output, 105, verifyMembershipPath, VERIFY_MEMBERSHIP_VALUE));
+
+        // path not in the group at the proof height
+        output = mockOutput(false);
+        vm.expectRevert(abi.encodeWithSelector(MembershipProofKeyNotFound.selector, verifyNonMembershipPath));
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyNonMembershipPath, bytes("")));
+
+        // value mismatch
+        output = mockOutput(false);
+        vm.expectRevert(
+            abi.encodeWithSelector(MembershipProofValueMismatch.selector, bytes("invalid"), VERIFY_MEMBERSHIP_VALUE)
+        );
+        mockIcs07Tendermint.membership(membershipMsg(output, 100, verifyMembershipPath, bytes("invalid")));
+    }
+
+    function height(uint32 revisionHeight) private pure returns (Height memory) {
+        return Height({ revisionNumber: 4, revisionHeight: revisionHeight });
+    }
+
+    /// @notice Returns the output of a proof of the membership path at height 100 and of the non-membership path at
+    /// height 110.
+    function mockOutput(bool hashedValues) private view returns (MultiMembershipOutput memory) {
+        KVPair[] memory membershipKvPairs = new KVPair[](1);
+        membershipKvPairs[0] = KVPair({
+            path: verifyMembershipPath,
+            value: hashedValues ? abi.encodePacked(keccak256(VERIFY_MEMBERSHIP_VALUE)) : VERIFY_MEMBERSHIP_VALUE
+        });
+        KVPair[] memory nonMembershipKvPairs = new KVPair[](1);
+        nonMembershipKvPairs[0] = KVPair({ path: verifyNonMembershipPath, value: bytes("") });
+
+        MembershipGroup[] memory groups = new MembershipGroup[](2);
+        groups[0] = MembershipGroup({
+            height: height(100),
+            trustedConsensusState: mockConsensusState(100),
+            kvPairs: membershipKvPairs
+        });
+        groups[1] = MembershipGroup({
+            height: height(110),
+            trustedConsensusState: mockConsensusState(110),
+            kvPairs: nonMembershipKvPairs
+        });
+
+        return MultiMembershipOutput({
+            groups: groups,
+            proofSpecsHash: mockIcs07Tendermint.getClientState().proofSpecsHash,
+            hashedValues: hashedValues
+        });
+    }
+
+    function membershipMsg(
+        MultiMembershipOutput memory output,
+        uint32 proofRevisionHeight,
+        bytes[] memory path,
+        bytes memory value
+    )
+        private
+        view
+        returns (MsgMembership memory)
+    {
+        return membershipMsgWithVkey(genesisFixture.multiMembershipVkey, output, proofRevisionHeight, path, value);
+    }
+
+    function membershipMsgWithVkey(
+        bytes32 vKey,
+        MultiMembershipOutput memory output,
+        uint32 proofRevisionHeight,
+        bytes[] memory path,
+        bytes memory value
+    )
+        private
+        pure
+        returns (MsgMembership memory)
+    {
+        SP1MultiMembershipProof memory proof = SP1MultiMembershipProof({
+            sp1Proof: SP1Proof({ vKey: vKey, publicValues: abi.encode(output), proof: bytes("") })
+        });
+        MembershipProof memory membershipProof =
+            MembershipProof({ proofType: MembershipProofType.SP1MultiMembershipProof, proof: abi.encode(proof) });
+
+        return MsgMembership({
+            proof: abi.encode(membershipProof),
+            proofHeight: height(proofRevisionHeight),
+            path: path,
+            value: value
+        });
+    }
+
+    function cachedMembershipMsg(
+        uint32 proofRevisionHeight,
+        bytes[] memory path,
+        bytes memory value
+    )
+        private
+        pure
+        returns (MsgMembership memory)
+    {
+        return MsgMembership({ proof: bytes(""), proofHeight: height(proofRevisionHeight), path: path, value: value });
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+// solhint-disable-next-line no-global-import
+import "forge-std/console.sol";
+import { SP1ICS07TendermintTest } from "./SP1ICS07TendermintTest.sol";
+import { ISP1ICS07Tendermint } from "../src/ISP1ICS07Tendermint.sol";
+import { Vm } from "forge-std/Vm.sol";
+
+contract SP1ICS07MultiUpdateClientTest is SP1ICS07TendermintTest {
+    Height public trustedHeight = Height({ revisionNumber: 4, revisionHeight: 100 });
+
+    function setUp() public {
+        setUpMockTest();
+    }
+
+    function test_ValidMultiUpdateClient() public {
+        MultiUpdateClientOutput memory output = mockOutput(revisionHeights(110, 3));
+        for (uint256 i = 0; i < output.newConsensusStates.length; i++) {
+            vm.expectEmit();
+            emit ISP1ICS07Tendermint.ConsensusStateAdded(
+                output.newConsensusStates[i].height, keccak256(abi.encode(output.newConsensusStates[i].consensusState))
+            );
+        }
+        UpdateResult res = mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+        console.log("MultiUpdateClient gas used: ", vm.lastCallGas().gasTotalUsed);
+        assert(res == UpdateResult.Update);
+
+        ClientState memory clientState = mockIcs07Tendermint.getClientState();
+        assertEq(clientState.latestHeight.revisionNumber, 4);
+        assertEq(clientState.latestHeight.revisionHeight, 130);
+        assertFalse(clientState.isFrozen);
+        for (uint256 i = 0; i < output.newConsensusStates.length; i++) {
+            assertEq(
+                mockIcs07Tendermint.getConsensusStateHash(output.newConsensusStates[i].height),
+                keccak256(abi.encode(output.newConsensusStates[i].consensusState))
+            );
+        }
+
+        // the same update again is a no-op, which stores no consensus state
+        vm.recordLogs();
+        res = mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+        assert(res == UpdateResult.NoOp);
+        Vm.Log[] memory logs = vm.getRecordedLogs();
+        assertEq(logs.length, 0);
+    }
+
+    function test_ValidMultiUpdateClientWithKnownHeaders() public {
+        MultiUpdateClientOutput memory output = mockOutput(revisionHeights(110, 1));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // the consensus states that are already stored are skipped
+        output = mockOutput(revisionHeights(110, 2));
+        UpdateResult res = mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+        assert(res == UpdateResult.Update);
+        assertEq(mockIcs07Tendermint.getClientState().latestHeight.revisionHeight, 120);
+    }
+
+    function test_Invalid_MultiUpdateClient() public {
+        // wrong vkey
+        MultiUpdateClientOutput memory output = mockOutput(revisionHeights(110, 3));
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                VerificationKeyMismatch.selector, genesisFixture.updateClientVkey, genesisFixture.misbehaviourVkey
+            )
+        );
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.misbehaviourVkey, output));
+
+        // no headers
+        output = mockOutput(new uint32[](0));
+        vm.expectRevert(abi.encodeWithSelector(LengthIsOutOfRange.selector, 0, 1, 256));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // too many headers
+        output = mockOutput(revisionHeights(110, 257));
+        vm.expectRevert(abi.encodeWithSelector(LengthIsOutOfRange.selector, 257, 1, 256));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // proof is in the future
+        output = mockOutput(revisionHeights(110, 3));
+        output.time = MOCK_TIME + 1;
+        vm.expectRevert(abi.encodeWithSelector(ProofIsInTheFuture.selector, block.timestamp, output.time));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // trusted consensus state mismatch
+        output = mockOutput(revisionHeights(110, 3));
+        output.trustedConsensusState = mockConsensusState(99);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ConsensusStateHashMismatch.selector,
+                keccak256(abi.encode(mockConsensusState(100))),
+                keccak256(abi.encode(mockConsensusState(99)))
+            )
+        );
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // trusted height of another revision
+        output = mockOutput(revisionHeights(110, 3));
+        output.trustedHeight.revisionNumber = 3;
+        vm.expectRevert(abi.encodeWithSelector(ConsensusStateNotFound.selector));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // the client is unchanged
+        assertEq(
+            keccak256(abi.encode(mockIcs07Tendermint.getClientState())), keccak256(genesisFixture.trustedClientState)
+        );
+    }
+
+    function test_MisbehaviourInMultiUpdateClient() public {
+        MultiUpdateClientOutput memory output = mockOutput(revisionHeights(120, 1));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+
+        // the second header conflicts with the stored consensus state
+        output = mockOutput(revisionHeights(110, 3));
+        output.newConsensusStates[1].consensusState.root = keccak256("conflicting root");
+        UpdateResult res = mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+        assert(res == UpdateResult.Misbehaviour);
+
+        assertTrue(mockIcs07Tendermint.getClientState().isFrozen);
+        assertEq(
+            mockIcs07Tendermint.getConsensusStateHash(Height({ revisionNumber: 4, revisionHeight: 120 })),
+            keccak256(abi.encode(mockConsensusState(120)))
+        );
+        // the headers after the misbehaviour are not stored
+        vm.expectRevert(abi.encodeWithSelector(ConsensusStateNotFound.selector));
+        mockIcs07Tendermint.getConsensusStateHash(Height({ revisionNumber: 4, revisionHeight: 130 }));
+
+        vm.expectRevert(abi.encodeWithSelector(FrozenClientState.selector));
+        mockIcs07Tendermint.updateClient(updateMsg(genesisFixture.multiUpdateClientVkey, output));
+    }
+
+    /// @notice Returns `count` revision heights, 10 blocks apart, starting from `first`.
+    function revisionHeights(uint32 first, uint32 count) private pure returns (uint32[] memory) {
+        uint32[] memory heights = new uint32[](count);
+        for (uint32 i = 0; i < count; i++) {
+            heights[i] = first + 10 * i;
+        }
+        return heights;
+    }
+
+    function mockOutput(uint32[] memory heights) private view returns (MultiUpdateClientOutput memory) {
+        ConsensusStateAtHeight[] memory newConsensusStates = new ConsensusStateAtHeight[](heights.length);
+        for (uint256 i = 0; i < heights.length; i++) {
+            newConsensusStates[i] = ConsensusStateAtHeight({
+                height: Height({ revisionNumber: 4, revisionHeight: heights[i] }),
+                consensusState: mockConsensusState(heights[i])
+            });
+        }
+
+        return MultiUpdateClientOutput({
+            clientState: mockIcs07Tendermint.getClientState(),
+            trustedConsensusState: mockConsensusState(trustedHeight.revisionHeight),
+            newConsensusStates: newConsensusStates,
+            time: MOCK_TIME,
+            trustedHeight: trustedHeight
+        });
+    }
+
+    function updateMsg(bytes32 vKey, MultiUpdateClientOutput memory output) private pure returns (bytes memory) {
+        return abi.encode(
+            MsgUpdateClient({ sp1Proof: SP1Proof({ vKey: vKey, publicValues: abi.encode(output), proof: bytes("") }) })
+        );
+    }
+}
@@ -10,6 +10,9 @@ import { IUpdateClientMsgs } from "../src/msgs/IUpdateClientMsgs.sol";
 import { IMembershipMsgs } from "../src/msgs/IMembershipMsgs.sol";
 import { IUpdateClientAndMembershipMsgs } from "../src/msgs/IUcAndMembershipMsgs.sol";
 import { IMisbehaviourMsgs } from "../src/msgs/IMisbehaviourMsgs.sol";
+import { IUpgradeClientMsgs } from "../src/msgs/IUpgradeClientMsgs.sol";
+import { IMultiUpdateClientMsgs } from "../src/msgs/IMultiUpdateClientMsgs.sol";
+import { IMultiMembershipMsgs } from "../src/msgs/IMultiMembershipMsgs.sol";
 import { SP1ICS07Tendermint } from "../src/SP1ICS07Tendermint.sol";
 import { ISP1ICS07TendermintErrors } from "../src/errors/ISP1ICS07TendermintErrors.sol";
 import { ISP1Verifier } from "@sp1-contracts/ISP1Verifier.sol";
@@ -23,6 +26,9 @@ struct SP1ICS07GenesisFixtureJson {
     bytes32 membershipVkey;
     bytes32 ucAndMembershipVkey;
     bytes32 misbehaviourVkey;
+    bytes32 upgradeClientVkey;
+    bytes32 multiUpdateClientVkey;
+    bytes32 multiMembershipVkey;
 }
 
 abstract contract SP1ICS07TendermintTest is
@@ -32,6 +38,9 @@ abstract contract SP1ICS07TendermintTest is
     IMembershipMsgs,
     IUpdateClientAndMembershipMsgs,
     IMisbehaviourMsgs,
+    IUpgradeClientMsgs,
+    IMultiUpdateClientMsgs,
+    IMultiMembershipMsgs,
     ISP1ICS07TendermintErrors,
     ILightClientMsgs
 {
@@ -43,9 +52,82 @@ abstract contract SP1ICS07TendermintTest is
 
     SP1ICS07GenesisFixtureJson internal genesisFixture;
 
+    /// @notice The time of the mock proofs, which the block time is set to in the mock tests.
+    uint64 public constant MOCK_TIME = 1_730_000_000;
+
     function setUpTest(string memory fileName) public {
         genesisFixture = loadGenesisFixture(fileName);
+        ClientState memory currentClientState;
+        skipIfStaleFixture(genesisFixture.trustedClientState, abi.encode(currentClientState), 1);
+
+        deployClients();
+    }
+
+    /// @notice Skips the test if a struct encoded in a fixture has another layout than the same struct encoded with
+    /// the current contracts, i.e. if the fixture was generated before a member was added to the struct.
+    /// @dev The layouts are compared by the offset of the first dynamic member of the struct, which is the size of its
+    /// head, and is the word at `offsetWord` of the encoding. Outdated fixtures are regenerated with `just fixtures`.
+    /// @param fixtureEncoded The struct encoded in the fixture
+    /// @param currentEncoded The same struct encoded with the current contracts
+    /// @param offsetWord The index of the word holding the offset of the first dynamic member of the struct
+    function skipIfStaleFixture(bytes memory fixtureEncoded, bytes memory currentEncoded, uint256 offsetWord) public {
+        bool stale = fixtureEncoded.length < (offsetWord + 1) * 32
+            || abiWord(fixtureEncoded, offsetWord) != abiWord(currentEncoded, offsetWord);
+        if (stale) {
+            console.log("Skipping the test: the fixture is outdated, regenerate it with `just fixtures`");
+        }
+        vm.skip(stale);
+    }
+
+    /// @notice Returns the word at the given index of an ABI encoding.
+    function abiWord(bytes memory encoded, uint256 index) private pure returns (bytes32 word) {
+        for (uint256 i = 0; i < 32; i++) {
+            word |= bytes32(encoded[index * 32 + i]) >> (i * 8);
+        }
+    }
+
+    /// @notice Sets up the clients with a genesis that is not generated by the operator, for the tests that only
+    /// submit mock proofs to the client with the mock verifier.
+    function setUpMockTest() public {
+        ClientState memory clientState = ClientState({
+            chainId: "mocha-4",
+            trustLevel: TrustThreshold({ numerator: 1, denominator: 3 }),
+            latestHeight: Height({ revisionNumber: 4, revisionHeight: 100 }),
+            trustingPeriod: 1_209_600,
+            unbondingPeriod: 1_814_400,
+            maxClockDrift: 15,
+            proofSpecsHash: sha256("proof specs"),
+            isFrozen: false,
+            zkAlgorithm: SupportedZkAlgorithm.Plonk
+        });
+
+        genesisFixture = SP1ICS07GenesisFixtureJson({
+            trustedClientState: abi.encode(clientState),
+            trustedConsensusState: abi.encode(mockConsensusState(clientState.latestHeight.revisionHeight)),
+            updateClientVkey: keccak256("update client"),
+            membershipVkey: keccak256("membership"),
+            ucAndMembershipVkey: keccak256("update client and membership"),
+            misbehaviourVkey: keccak256("misbehaviour"),
+            upgradeClientVkey: keccak256("upgrade client"),
+            multiUpdateClientVkey: keccak256("multi update client"),
+            multiMembershipVkey: keccak256("multi membership")
+        });
+
+        deployClients();
+
+        vm.warp(MOCK_TIME);
+    }
+
+    /// @notice Returns a mock consensus state at the given revision height, whose timestamp increases with the height.
+    function mockConsensusState(uint32 revisionHeight) public pure returns (ConsensusState memory) {
+        return ConsensusState({
+            timestamp: MOCK_TIME - 10_000 + revisionHeight,
+            root: keccak256(abi.encode(bytes32("root"), revisionHeight)),
+            nextValidatorsHash: keccak256(abi.encode(bytes32("next validators"), revisionHeight))
+        });
+    }
 
+    function deployClients() private {
         ConsensusState memory trustedConsensusState = abi.decode(genesisFixture.trustedConsensusState, (ConsensusState));
 
         bytes32 trustedConsensusHash = keccak256(abi.encode(trustedConsensusState));
@@ -55,6 +137,9 @@ abstract contract SP1ICS07TendermintTest is
             genesisFixture.membershipVkey,
             genesisFixture.ucAndMembershipVkey,
             genesisFixture.misbehaviourVkey,
+            genesisFixture.upgradeClientVkey,
+            genesisFixture.multiUpdateClientVkey,
+            genesisFixture.multiMembershipVkey,
             genesisFixture.trustedClientState,
             trustedConsensusHash
         );
@@ -64,6 +149,9 @@ abstract contract SP1ICS07TendermintTest is
             genesisFixture.membershipVkey,
             genesisFixture.ucAndMembershipVkey,
             genesisFixture.misbehaviourVkey,
+            genesisFixture.upgradeClientVkey,
+            genesisFixture.multiUpdateClientVkey,
+            genesisFixture.multiMembershipVkey,
             genesisFixture.trustedClientState,
             trustedConsensusHash
         );
@@ -77,7 +165,7 @@ abstract contract SP1ICS07TendermintTest is
         ClientState memory clientState = mockIcs07Tendermint.getClientState();
         assert(keccak256(abi.encode(clientState)) == keccak256(genesisFixture.trustedClientState));
 
-        bytes32 consensusHash = mockIcs07Tendermint.getConsensusStateHash(clientState.latestHeight.revisionHeight);
+        bytes32 consensusHash = mockIcs07Tendermint.getConsensusStateHash(clientState.latestHeight);
         assert(consensusHash == trustedConsensusHash);
     }
 
@@ -91,6 +179,15 @@ abstract contract SP1ICS07TendermintTest is
         bytes32 membershipVkey = json.readBytes32(".membershipVkey");
         bytes32 ucAndMembershipVkey = json.readBytes32(".ucAndMembershipVkey");
         bytes32 misbehaviourVkey = json.readBytes32(".misbehaviourVkey");
+        // fixtures generated before the upgrade client program do not contain its key
+        bytes32 upgradeClientVkey =
+            json.keyExists(".upgradeClientVkey") ? json.readBytes32(".upgradeClientVkey") : bytes32(0);
+        // fixtures generated before the multi-header update client program do not contain its key
+        bytes32 multiUpdateClientVkey =
+            json.keyExists(".multiUpdateClientVkey") ? json.readBytes32(".multiUpdateClientVkey") : bytes32(0);
+        // fixtures generated before the multi-height membership program do not contain its key
+        bytes32 multiMembershipVkey =
+            json.keyExists(".multiMembershipVkey") ? json.readBytes32(".multiMembershipVkey") : bytes32(0);
 
         SP1ICS07GenesisFixtureJson memory fix = SP1ICS07GenesisFixtureJson({
             trustedClientState: trustedClientState,
@@ -98,7 +195,10 @@ abstract contract SP1ICS07TendermintTest is
             updateClientVkey: updateClientVkey,
             membershipVkey: membershipVkey,
             ucAndMembershipVkey: ucAndMembershipVkey,
-            misbehaviourVkey: misbehaviourVkey
+            misbehaviourVkey: misbehaviourVkey,
+            upgradeClientVkey: upgradeClientVkey,
+            multiUpdateClientVkey: multiUpdateClientVkey,
+            multiMembershipVkey: multiMembershipVkey
         });
 
         return fix;
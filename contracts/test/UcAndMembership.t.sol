@@ -15,11 +15,14 @@ contract SP1ICS07UpdateClientAndMembershipTest is MembershipTest {
         setUpTestWithFixtures(fileName);
 
         proof = abi.decode(fixture.membershipProof.proof, (SP1MembershipAndUpdateClientProof));
+        UcAndMembershipOutput memory currentOutput;
+        skipIfStaleFixture(proof.sp1Proof.publicValues, abi.encode(currentOutput), 1);
 
         UcAndMembershipOutput memory output = abi.decode(proof.sp1Proof.publicValues, (UcAndMembershipOutput));
 
         ClientState memory clientState = mockIcs07Tendermint.getClientState();
         assert(clientState.latestHeight.revisionHeight < output.updateClientOutput.newHeight.revisionHeight);
+        assert(!output.hashedValues);
     }
 
     function fixtureTestCases() public pure returns (FixtureTestCase[] memory) {
@@ -59,8 +62,7 @@ contract SP1ICS07UpdateClientAndMembershipTest is MembershipTest {
             assert(clientState.latestHeight.revisionHeight == output.updateClientOutput.newHeight.revisionHeight);
             assert(clientState.isFrozen == false);
 
-            bytes32 consensusHash =
-                ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight.revisionHeight);
+            bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight);
             assert(consensusHash == keccak256(abi.encode(output.updateClientOutput.newConsensusState)));
         }
     }
@@ -94,8 +96,7 @@ contract SP1ICS07UpdateClientAndMembershipTest is MembershipTest {
             assert(clientState.latestHeight.revisionHeight == output.updateClientOutput.newHeight.revisionHeight);
             assert(clientState.isFrozen == false);
 
-            bytes32 consensusHash =
-                ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight.revisionHeight);
+            bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight);
             assert(consensusHash == keccak256(abi.encode(output.updateClientOutput.newConsensusState)));
         }
     }
@@ -123,8 +124,7 @@ contract SP1ICS07UpdateClientAndMembershipTest is MembershipTest {
         assert(clientState.latestHeight.revisionHeight == output.updateClientOutput.newHeight.revisionHeight);
         assert(clientState.isFrozen == false);
 
-        bytes32 consensusHash =
-            ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight.revisionHeight);
+        bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(output.updateClientOutput.newHeight);
         assert(consensusHash == keccak256(abi.encode(output.updateClientOutput.newConsensusState)));
 
         // submit cached membership proof
@@ -31,7 +31,7 @@ contract SP1ICS07UpdateClientTest is SP1ICS07TendermintTest {
         assert(clientState.latestHeight.revisionHeight < output.newHeight.revisionHeight);
 
         vm.expectRevert();
-        mockIcs07Tendermint.getConsensusStateHash(output.newHeight.revisionHeight);
+        mockIcs07Tendermint.getConsensusStateHash(output.newHeight);
     }
 
     function fixtureTestCases() public pure returns (FixtureTestCase[] memory) {
@@ -64,7 +64,7 @@ contract SP1ICS07UpdateClientTest is SP1ICS07TendermintTest {
             assert(clientState.latestHeight.revisionHeight == output.newHeight.revisionHeight);
             assert(clientState.isFrozen == false);
 
-            bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(output.newHeight.revisionHeight);
+            bytes32 consensusHash = ics07Tendermint.getConsensusStateHash(output.newHeight);
             assertEq(consensusHash, keccak256(abi.encode(output.newConsensusState)));
         }
     }
@@ -96,6 +96,29 @@ contract SP1ICS07UpdateClientTest is SP1ICS07TendermintTest {
         ics07Tendermint.updateClient(bytes("invalid"));
     }
 
+    // Confirm that the consensus states are stored at their revision number as well as their revision height.
+    function test_Invalid_TrustedHeightOfOtherRevision() public {
+        // Doesn't matter which fixture we use since this is a fail
+        setUpTestWithFixture("update_client_fixture-plonk.json");
+        vm.warp(output.time + 300);
+
+        UpdateClientOutput memory badOutput = output;
+        badOutput.trustedHeight.revisionNumber += 1;
+
+        vm.expectRevert(abi.encodeWithSelector(ConsensusStateNotFound.selector));
+        mockIcs07Tendermint.getConsensusStateHash(badOutput.trustedHeight);
+
+        MsgUpdateClient memory updateMsg = MsgUpdateClient({
+            sp1Proof: SP1Proof({
+                vKey: genesisFixture.updateClientVkey,
+                publicValues: abi.encode(badOutput),
+                proof: bytes("")
+            })
+        });
+        vm.expectRevert(abi.encodeWithSelector(ConsensusStateNotFound.selector));
+        mockIcs07Tendermint.updateClient(abi.encode(updateMsg));
+    }
+
     function loadFixture(string memory fileName) public view returns (SP1ICS07UpdateClientFixtureJson memory) {
         string memory root = vm.projectRoot();
         string memory path = string.concat(root, "/contracts/fixtures/", fileName);
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.28;
+
+// solhint-disable-next-line no-global-import
+import "forge-std/console.sol";
+import { SP1ICS07TendermintTest } from "./SP1ICS07TendermintTest.sol";
+
+contract SP1ICS07UpgradeClientTest is SP1ICS07TendermintTest {
+    Height public upgradeHeight = Height({ revisionNumber: 4, revisionHeight: 100 });
+    Height public newHeight = Height({ revisionNumber: 5, revisionHeight: 1 });
+
+    function setUp() public {
+        setUpMockTest();
+    }
+
+    function test_ValidUpgradeClient() public {
+        UpgradeClientOutput memory output = mockOutput();
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+        console.log("UpgradeClient gas used: ", vm.lastCallGas().gasTotalUsed);
+
+        ClientState memory clientState = mockIcs07Tendermint.getClientState();
+        assertEq(keccak256(abi.encode(clientState)), keccak256(abi.encode(output.newClientState)));
+        assertEq(mockIcs07Tendermint.getConsensusStateHash(newHeight), keccak256(abi.encode(output.newConsensusState)));
+        // the consensus states of the previous revision are kept
+        assertEq(
+            mockIcs07Tendermint.getConsensusStateHash(upgradeHeight),
+            keccak256(abi.encode(output.trustedConsensusState))
+        );
+    }
+
+    function test_Invalid_UpgradeClient() public {
+        // wrong vkey
+        UpgradeClientOutput memory output = mockOutput();
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                VerificationKeyMismatch.selector, genesisFixture.upgradeClientVkey, genesisFixture.updateClientVkey
+            )
+        );
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.updateClientVkey, output));
+
+        // proof is too old
+        output = mockOutput();
+        output.time = MOCK_TIME - mockIcs07Tendermint.ALLOWED_SP1_CLOCK_DRIFT() - 1;
+        vm.expectRevert(abi.encodeWithSelector(ProofIsTooOld.selector, block.timestamp, output.time));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // chain id mismatch
+        output = mockOutput();
+        output.clientState.chainId = "mocha-5";
+        vm.expectRevert(abi.encodeWithSelector(ChainIdMismatch.selector, "mocha-4", "mocha-5"));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // not at the latest height of the client
+        output = mockOutput();
+        output.trustedHeight.revisionHeight = 99;
+        vm.expectRevert(abi.encodeWithSelector(ProofHeightMismatch.selector, 4, 100, 4, 99));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // trusted consensus state mismatch
+        output = mockOutput();
+        output.trustedConsensusState = mockConsensusState(99);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ConsensusStateHashMismatch.selector,
+                keccak256(abi.encode(mockConsensusState(100))),
+                keccak256(abi.encode(mockConsensusState(99)))
+            )
+        );
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // height not increasing
+        output = mockOutput();
+        output.newClientState.latestHeight = upgradeHeight;
+        vm.expectRevert(abi.encodeWithSelector(UpgradeHeightNotIncreasing.selector, 4, 100, 4, 100));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        output = mockOutput();
+        output.newClientState.latestHeight = Height({ revisionNumber: 3, revisionHeight: 200 });
+        vm.expectRevert(abi.encodeWithSelector(UpgradeHeightNotIncreasing.selector, 4, 100, 3, 200));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // frozen upgraded client state
+        output = mockOutput();
+        output.newClientState.isFrozen = true;
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ClientStateMismatch.selector,
+                abi.encode(mockIcs07Tendermint.getClientState()),
+                abi.encode(output.newClientState)
+            )
+        );
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // proof specs of the upgraded client state mismatch
+        output = mockOutput();
+        output.newClientState.proofSpecsHash = sha256("invalid");
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ClientStateMismatch.selector,
+                abi.encode(mockIcs07Tendermint.getClientState()),
+                abi.encode(output.newClientState)
+            )
+        );
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // trusting period of the upgraded client state too long
+        output = mockOutput();
+        output.newClientState.trustingPeriod = output.newClientState.unbondingPeriod + 1;
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                TrustingPeriodTooLong.selector,
+                output.newClientState.trustingPeriod,
+                output.newClientState.unbondingPeriod
+            )
+        );
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, output));
+
+        // the client is unchanged
+        ClientState memory clientState = mockIcs07Tendermint.getClientState();
+        assertEq(keccak256(abi.encode(clientState)), keccak256(genesisFixture.trustedClientState));
+        vm.expectRevert(abi.encodeWithSelector(ConsensusStateNotFound.selector));
+        mockIcs07Tendermint.getConsensusStateHash(newHeight);
+    }
+
+    function test_FrozenUpgradeClient() public {
+        // freeze the client
+        MisbehaviourOutput memory misbehaviourOutput = MisbehaviourOutput({
+            clientState: mockIcs07Tendermint.getClientState(),
+            time: MOCK_TIME,
+            trustedHeight1: upgradeHeight,
+            trustedHeight2: upgradeHeight,
+            trustedConsensusState1: mockConsensusState(upgradeHeight.revisionHeight),
+            trustedConsensusState2: mockConsensusState(upgradeHeight.revisionHeight)
+        });
+        MsgSubmitMisbehaviour memory submitMsg = MsgSubmitMisbehaviour({
+            sp1Proof: SP1Proof({
+                vKey: genesisFixture.misbehaviourVkey,
+                publicValues: abi.encode(misbehaviourOutput),
+                proof: bytes("")
+            })
+        });
+        mockIcs07Tendermint.misbehaviour(abi.encode(submitMsg));
+
+        vm.expectRevert(abi.encodeWithSelector(FrozenClientState.selector));
+        mockIcs07Tendermint.upgradeClient(upgradeMsg(genesisFixture.upgradeClientVkey, mockOutput()));
+    }
+
+    function mockOutput() private view returns (UpgradeClientOutput memory) {
+        ClientState memory clientState = mockIcs07Tendermint.getClientState();
+        ClientState memory newClientState = mockIcs07Tendermint.getClientState();
+        newClientState.chainId = "mocha-5";
+        newClientState.latestHeight = newHeight;
+        newClientState.unbondingPeriod = 1_209_600;
+        newClientState.trustingPeriod = 806_400;
+
+        return UpgradeClientOutput({
+            clientState: clientState,
+            trustedConsensusState: mockConsensusState(upgradeHeight.revisionHeight),
+            newClientState: newClientState,
+            newConsensusState: ConsensusState({
+                timestamp: MOCK_TIME - 100,
+                root: keccak256("upgraded root"),
+                nextValidatorsHash: keccak256("upgraded next validators")
+            }),
+            time: MOCK_TIME,
+            trustedHeight: upgradeHeight
+        });
+    }
+
+    function upgradeMsg(bytes32 vKey, UpgradeClientOutput memory output) private pure returns (bytes memory) {
+        return abi.encode(
+            MsgUpgradeClient({ sp1Proof: SP1Proof({ vKey: vKey, publicValues: abi.encode(output), proof: bytes("") }) })
+        );
+    }
+}
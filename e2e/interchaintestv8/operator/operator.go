@@ -29,6 +29,9 @@ type GenesisFixture struct {
 	MembershipVkey        string `json:"membershipVkey"`
 	UcAndMembershipVkey   string `json:"ucAndMembershipVkey"`
 	MisbehaviourVKey      string `json:"misbehaviourVkey"`
+	UpgradeClientVkey     string `json:"upgradeClientVkey"`
+	MultiUpdateClientVkey string `json:"multiUpdateClientVkey"`
+	MultiMembershipVkey   string `json:"multiMembershipVkey"`
 }
 
 // membershipFixture is a struct that contains the membership proof and proof height
@@ -241,10 +244,14 @@ func MisbehaviourProof(cdc codec.Codec, misbehaviour tmclient.Misbehaviour, writ
 func ToBase64KeyPaths(paths ...[][]byte) string {
 	var keyPaths []string
 	for _, path := range paths {
-		if len(path) != 2 {
-			panic("path must have 2 elements")
+		if len(path) < 2 {
+			panic("path must have at least 2 elements")
 		}
-		keyPaths = append(keyPaths, base64.StdEncoding.EncodeToString(path[0])+"\\"+base64.StdEncoding.EncodeToString(path[1]))
+		segments := make([]string, len(path))
+		for i, segment := range path {
+			segments[i] = base64.StdEncoding.EncodeToString(segment)
+		}
+		keyPaths = append(keyPaths, strings.Join(segments, "\\"))
 	}
 	return strings.Join(keyPaths, ",")
 }
@@ -23,6 +23,8 @@ async fn main() -> anyhow::Result<()> {
     match cli.command {
         Commands::Start(args) => runners::operator::run(args).await,
         Commands::Genesis(args) => runners::genesis::run(args).await,
+        Commands::Upgrade(args) => runners::upgrade::run(args).await,
+        Commands::Recover(args) => runners::recover::run(args).await,
         Commands::Fixtures(cmd) => match cmd.command {
             fixtures::Cmds::UpdateClient(args) => update_client::run(args).await,
             fixtures::Cmds::Membership(args) => membership::run(args).await,
@@ -3,7 +3,9 @@
 use std::convert::Infallible;
 
 use clap::{command, Parser};
+use ibc_core_commitment_types::specs::ProofSpecs;
 use sp1_ics07_tendermint_prover::prover::SupportedProofType;
+use sp1_ics07_tendermint_utils::{key_path::CommitmentPrefix, proof_specs::load_proof_specs};
 use tendermint_light_client_verifier::types::TrustThreshold;
 
 /// The command line interface for the operator.
@@ -24,6 +26,10 @@ pub enum Commands {
     Genesis(genesis::Args),
     /// The subcommand to produce the fixtures for testing.
     Fixtures(fixtures::Cmd),
+    /// The subcommand to upgrade the client after a planned upgrade of the chain.
+    Upgrade(upgrade::Args),
+    /// The subcommand to produce a substitute `genesis.json` file for an expired or frozen client.
+    Recover(recover::Args),
 }
 
 /// The trust options for client operations.
@@ -41,6 +47,10 @@ pub struct TrustOptions {
     /// Trusting period. [default: 2/3 of unbonding period]
     #[clap(long)]
     pub trusting_period: Option<u32>,
+
+    /// Maximum duration in seconds that a header can be ahead of the time of the proof.
+    #[clap(long, default_value = "10")]
+    pub max_clock_drift: u32,
 }
 
 /// The output path for files.
@@ -75,6 +85,12 @@ pub mod genesis {
         /// Supported proof types: groth16, plonk.
         #[clap(long, short = 'p', value_parser = super::parse_proof_type, default_value = "plonk")]
         pub proof_type: super::SupportedProofType,
+
+        /// The ICS-23 proof specs of the (non)membership proofs of the chain.
+        /// Either a preset, i.e. 'iavl', 'smt' or 'jmt', or the path to a JSON file of proof
+        /// specs.
+        #[clap(long, value_parser = super::load_proof_specs, default_value = "iavl")]
+        pub proof_specs: super::ProofSpecs,
     }
 }
 
@@ -88,12 +104,156 @@ pub mod operator {
         /// Run update-client only once and then exit.
         #[clap(long)]
         pub only_once: bool,
+
+        /// Simulate the updates with `eth_call` instead of submitting them, and print the result.
+        /// Implies `--only-once`.
+        #[clap(long)]
+        pub dry_run: bool,
+
+        /// Prove the next update of every client while the transaction of the previous update
+        /// confirms, see `pipelined` in the configuration file.
+        #[clap(long)]
+        pub pipelined: bool,
+
+        /// Path to the configuration file describing the clients to operate.
+        /// If not provided, a single client is configured from the environment variables.
+        #[clap(long, short = 'c', env = "OPERATOR_CONFIG")]
+        pub config: Option<String>,
+
+        /// Path to the state file used to resume after a restart.
+        /// Only used if no configuration file is provided.
+        #[clap(
+            long,
+            env = "OPERATOR_STATE_PATH",
+            default_value = "operator-state.json"
+        )]
+        pub state_path: String,
+
+        /// Address to serve the Prometheus metrics and the health endpoints on, e.g.
+        /// `0.0.0.0:9090`. The server is disabled if not provided.
+        #[clap(long, env = "OPERATOR_METRICS_ADDR")]
+        pub metrics_addr: Option<std::net::SocketAddr>,
+
+        /// Address to serve the proof service API for relayers on, e.g. `127.0.0.1:8080`.
+        /// The API is disabled if not provided.
+        #[clap(long, env = "OPERATOR_API_ADDR")]
+        pub api_addr: Option<std::net::SocketAddr>,
+
+        /// The signer of the transactions.
+        /// Only used if no configuration file is provided.
+        #[clap(flatten)]
+        pub signer: SignerArgs,
+    }
+
+    /// The signer options of the operator. The hex encoded private key in the `PRIVATE_KEY`
+    /// environment variable is used if none is provided.
+    #[derive(Parser, Debug, Clone)]
+    pub struct SignerArgs {
+        /// Path to an encrypted JSON keystore to sign with.
+        #[clap(
+            long,
+            env = "KEYSTORE_PATH",
+            requires = "keystore_password_file",
+            conflicts_with_all = ["mnemonic_var", "remote_signer_url"]
+        )]
+        pub keystore: Option<String>,
+
+        /// Path to the file containing the password of the keystore.
+        #[clap(long, env = "KEYSTORE_PASSWORD_FILE")]
+        pub keystore_password_file: Option<String>,
+
+        /// Name of the environment variable containing a BIP-39 mnemonic to sign with.
+        #[clap(long, conflicts_with = "remote_signer_url")]
+        pub mnemonic_var: Option<String>,
+
+        /// The derivation path of the mnemonic account.
+        #[clap(long, default_value = sp1_ics07_tendermint_utils::eth::DEFAULT_DERIVATION_PATH)]
+        pub derivation_path: String,
+
+        /// URL of a remote signer to sign with over JSON-RPC (`eth_signTransaction`).
+        #[clap(long, env = "REMOTE_SIGNER_URL", requires = "remote_signer_address")]
+        pub remote_signer_url: Option<String>,
+
+        /// Address of the account of the remote signer.
+        #[clap(long, env = "REMOTE_SIGNER_ADDRESS")]
+        pub remote_signer_address: Option<alloy::primitives::Address>,
+    }
+}
+
+/// The cli interface for the upgrade command.
+pub mod upgrade {
+    use super::Parser;
+
+    /// Command line arguments for the `upgrade` command.
+    #[derive(Parser, Debug, Clone)]
+    pub struct Args {
+        /// The name of an upgrade plan that was already applied by the chain.
+        /// If not provided, the current upgrade plan of the chain is used.
+        #[clap(long)]
+        pub plan_name: Option<String>,
+
+        /// Simulate the upgrade, and the updates to the upgrade height, with `eth_call` instead
+        /// of submitting them, and print the result.
+        #[clap(long)]
+        pub dry_run: bool,
+
+        /// The ICS-23 proof specs of the proofs of the upgraded states, which must be the proof
+        /// specs of the client.
+        /// Either a preset, i.e. 'iavl', 'smt' or 'jmt', or the path to a JSON file of proof
+        /// specs.
+        #[clap(long, value_parser = super::load_proof_specs, default_value = "iavl")]
+        pub proof_specs: super::ProofSpecs,
+
+        /// The signer of the transactions.
+        #[clap(flatten)]
+        pub signer: super::operator::SignerArgs,
+    }
+}
+
+/// The cli interface for the recover command.
+pub mod recover {
+    use super::Parser;
+
+    /// Command line arguments for the `recover` command.
+    #[derive(Parser, Debug, Clone)]
+    pub struct Args {
+        /// The address of the expired or frozen client contract.
+        #[clap(long)]
+        pub contract_address: alloy::primitives::Address,
+
+        /// Trusted block height of the substitute client. [default: latest]
+        #[clap(long)]
+        pub trusted_block: Option<u32>,
+
+        /// Trusting period of the substitute client. [default: the trusting period of the old
+        /// client]
+        #[clap(long)]
+        pub trusting_period: Option<u32>,
+
+        /// Genesis path. If not provided, the output will be written to stdout.
+        #[clap(long, short = 'o', value_parser = super::parse_output_path, default_value = "-")]
+        pub output_path: super::OutputPath,
+
+        /// The ICS-23 proof specs of the (non)membership proofs of the chain, either a preset,
+        /// i.e. 'iavl', 'smt' or 'jmt', or the path to a JSON file of proof specs. They should be
+        /// the proof specs of the old client.
+        #[clap(long, value_parser = super::load_proof_specs, default_value = "iavl")]
+        pub proof_specs: super::ProofSpecs,
+
+        /// Path to write the report comparing the old and new client parameters to.
+        /// If not provided, the report is logged.
+        #[clap(long)]
+        pub report_path: Option<String>,
+
+        /// Recover the client even if it is neither frozen nor expired.
+        #[clap(long)]
+        pub force: bool,
     }
 }
 
 /// The cli interface for the fixtures.
 pub mod fixtures {
-    use super::{command, Parser};
+    use super::{command, CommitmentPrefix, Parser};
 
     /// The cli interface for the fixtures.
     #[derive(Clone, Debug, Parser)]
@@ -141,6 +301,11 @@ pub mod fixtures {
         /// Supported proof types: groth16, plonk.
         #[clap(long, short = 'p', value_parser = super::parse_proof_type, default_value = "plonk")]
         pub proof_type: super::SupportedProofType,
+
+        /// Simulate the submission of the proof to the contract at `CONTRACT_ADDRESS` with
+        /// `eth_call`, without broadcasting it.
+        #[clap(long)]
+        pub dry_run: bool,
     }
 
     /// The arguments for the `Membership` fixture executable.
@@ -176,10 +341,35 @@ pub mod fixtures {
         #[clap(flatten)]
         pub trust_options: super::TrustOptions,
 
-        /// Indicates that the key paths are base64 encoded.
-        /// Module store keys seperated by backslash, '\', eg. 'aWJj\a2V5' for 'ibc/key'.
+        /// Indicates that the key paths are full merkle paths of base64 encoded segments, i.e.
+        /// the store key followed by the key, seperated by backslash, '\', eg. 'aWJj\a2V5' for
+        /// 'ibc/key'. Nested stores are not supported, since they cannot be queried.
         #[clap(long)]
         pub base64: bool,
+
+        /// The commitment prefix that the key paths are under, as segments seperated by
+        /// backslash, '\'. The key is appended to the last segment, so 'ibc\' is the 'ibc' store
+        /// of the Cosmos SDK. Ignored for base64 key paths.
+        #[clap(
+            long,
+            default_value = sp1_ics07_tendermint_utils::key_path::DEFAULT_COMMITMENT_PREFIX
+        )]
+        pub commitment_prefix: CommitmentPrefix,
+
+        /// The ICS-23 proof specs of the key paths, either a preset, i.e. 'iavl', 'smt' or 'jmt',
+        /// or the path to a JSON file of proof specs.
+        #[clap(long, value_parser = super::load_proof_specs, default_value = "iavl")]
+        pub proof_specs: super::ProofSpecs,
+
+        /// Commit the keccak256 hashes of the values in the proof instead of the values
+        /// themselves, to reduce the calldata for large values.
+        #[clap(long)]
+        pub hash_values: bool,
+
+        /// Simulate the submission of the proof to the contract at `CONTRACT_ADDRESS` with
+        /// `eth_call`, without broadcasting it.
+        #[clap(long)]
+        pub dry_run: bool,
     }
 
     /// The arguments for the `UpdateClientAndMembership` fixture executable.
@@ -220,6 +410,11 @@ pub mod fixtures {
         /// Supported proof types: groth16, plonk.
         #[clap(long, short = 'p', value_parser = super::parse_proof_type, default_value = "plonk")]
         pub proof_type: super::SupportedProofType,
+
+        /// Simulate the submission of the proof to the contract at `CONTRACT_ADDRESS` with
+        /// `eth_call`, without broadcasting it.
+        #[clap(long)]
+        pub dry_run: bool,
     }
 }
 
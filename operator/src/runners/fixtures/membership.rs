@@ -2,23 +2,23 @@
 
 use crate::{
     cli::command::{fixtures::MembershipCmd, OutputPath},
-    runners::genesis::SP1ICS07TendermintGenesis,
+    runners::{genesis::SP1ICS07TendermintGenesis, simulation},
 };
 use alloy_sol_types::SolValue;
-use core::str;
 use ibc_client_tendermint_types::ConsensusState;
-use ibc_core_commitment_types::merkle::MerkleProof;
+use ibc_core_commitment_types::{merkle::MerkleProof, specs::ProofSpecs};
 use serde::{Deserialize, Serialize};
-use sp1_ics07_tendermint_prover::{
-    programs::MembershipProgram,
-    prover::{SP1ICS07TendermintProver, SupportedProofType},
-};
+use sp1_ics07_tendermint_prover::{programs::MembershipProgram, prover::SP1ICS07TendermintProver};
 use sp1_ics07_tendermint_solidity::{
     IICS07TendermintMsgs::{ClientState, ConsensusState as SolConsensusState},
-    IMembershipMsgs::{MembershipOutput, MembershipProof, SP1MembershipProof},
+    IMembershipMsgs::{KVPair, MembershipOutput, MembershipProof, SP1MembershipProof},
     ISP1Msgs::SP1Proof,
 };
-use sp1_ics07_tendermint_utils::{merkle::convert_tm_to_ics_merkle_proof, rpc::TendermintRpcExt};
+use sp1_ics07_tendermint_utils::{
+    key_path::{self, parse_key_path, CommitmentPrefix},
+    merkle::convert_tm_to_ics_merkle_proof,
+    rpc::TendermintRpcExt,
+};
 use sp1_sdk::HashableKey;
 use std::path::PathBuf;
 use tendermint_rpc::{Client, HttpClient};
@@ -47,13 +47,15 @@ pub async fn run(args: MembershipCmd) -> anyhow::Result<()> {
     let tm_rpc_client = HttpClient::from_env();
 
     let trusted_light_block = tm_rpc_client
-        .get_light_block(Some(args.membership.trusted_block))
+        .get_light_block(Some(args.membership.trusted_block.into()))
         .await?;
 
     let genesis = SP1ICS07TendermintGenesis::from_env(
         &trusted_light_block,
         args.membership.trust_options.trusting_period,
         args.membership.trust_options.trust_level,
+        args.membership.trust_options.max_clock_drift,
+        args.membership.proof_specs.clone(),
         args.proof_type,
     )
     .await?;
@@ -62,15 +64,36 @@ pub async fn run(args: MembershipCmd) -> anyhow::Result<()> {
     let trusted_consensus_state =
         SolConsensusState::abi_decode(&genesis.trusted_consensus_state, false)?;
 
-    let membership_proof = run_sp1_membership(
+    let verify_mem_prover = SP1ICS07TendermintProver::<MembershipProgram>::new(args.proof_type);
+    let kv_proofs = fetch_kv_proofs(
         &tm_rpc_client,
         args.membership.base64,
+        &args.membership.commitment_prefix,
         args.membership.key_paths,
         args.membership.trusted_block,
-        trusted_consensus_state,
-        args.proof_type,
     )
     .await?;
+    let kv_pairs = kv_pairs(&kv_proofs);
+    let membership_proof = prove_membership(
+        &verify_mem_prover,
+        trusted_consensus_state,
+        &genesis.proof_specs,
+        args.membership.hash_values,
+        kv_proofs,
+    )?;
+
+    if args.membership.dry_run {
+        let contract = simulation::contract_from_env()?;
+        let timestamps = simulation::simulate_membership(
+            &contract,
+            &membership_proof,
+            &trusted_client_state.latestHeight,
+            &kv_pairs,
+        )
+        .await?
+        .into_result()?;
+        log::info!("Simulated membership on the contract: timestamps {timestamps:?}");
+    }
 
     let fixture = SP1ICS07MembershipFixture {
         genesis,
@@ -91,58 +114,99 @@ pub async fn run(args: MembershipCmd) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Generates an sp1 membership proof for the given args
-#[allow(
-    clippy::missing_errors_doc,
-    clippy::missing_panics_doc,
-    clippy::module_name_repetitions
-)]
-pub async fn run_sp1_membership(
+/// Returns the key-value pairs of the given key-value proofs, with the values that the membership
+/// messages carry, i.e. the values themselves even if the proof commits their hashes.
+#[must_use]
+pub fn kv_pairs(kv_proofs: &[(Vec<Vec<u8>>, Vec<u8>, MerkleProof)]) -> Vec<KVPair> {
+    kv_proofs
+        .iter()
+        .map(|(path, value, _)| KVPair {
+            path: path.iter().cloned().map(Into::into).collect(),
+            value: value.clone().into(),
+        })
+        .collect()
+}
+
+/// Fetches the values and merkle proofs of the given key paths, against the app hash of the
+/// trusted block.
+/// Key paths are parsed with [`parse_key_path`], i.e. they are full merkle paths of base64
+/// encoded segments separated by a backslash if `is_base64` is set, and keys under the
+/// commitment prefix otherwise.
+#[allow(clippy::missing_errors_doc)]
+pub async fn fetch_kv_proofs(
     tm_rpc_client: &HttpClient,
     is_base64: bool,
+    prefix: &CommitmentPrefix,
     key_paths: Vec<String>,
     trusted_block: u32,
+) -> anyhow::Result<Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>> {
+    futures::future::try_join_all(key_paths.into_iter().map(|key_path| async move {
+        let path = parse_key_path(&key_path, is_base64, prefix)?;
+        fetch_kv_proof(tm_rpc_client, path, trusted_block).await
+    }))
+    .await
+}
+
+/// Fetches the value and merkle proof of the given merkle path, i.e. the store key followed by
+/// the key in the store, against the app hash of the trusted block.
+/// The value is empty if the key does not exist.
+#[allow(clippy::missing_errors_doc)]
+pub async fn fetch_kv_proof(
+    tm_rpc_client: &HttpClient,
+    path: Vec<Vec<u8>>,
+    trusted_block: u32,
+) -> anyhow::Result<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)> {
+    let (query_path, key) = key_path::abci_query_path(&path)?;
+    let res = tm_rpc_client
+        .abci_query(
+            Some(query_path),
+            key.as_slice(),
+            // Proof height should be the block before the target block.
+            Some((trusted_block - 1).into()),
+            true,
+        )
+        .await?;
+
+    anyhow::ensure!(u32::try_from(res.height.value())? + 1 == trusted_block);
+    anyhow::ensure!(res.key == key);
+    let vm_proof = convert_tm_to_ics_merkle_proof(
+        &res.proof
+            .ok_or_else(|| anyhow::anyhow!("no proof returned for the key path"))?,
+    )?;
+    // The proof has one commitment proof per segment, from the innermost store to the app hash.
+    anyhow::ensure!(
+        vm_proof.proofs.len() == path.len(),
+        "the chain returned {} proofs for a merkle path of {} segments",
+        vm_proof.proofs.len(),
+        path.len()
+    );
+
+    Ok((path, res.value, vm_proof))
+}
+
+/// Generates an sp1 membership proof of the given key-value proofs against the root of the
+/// trusted consensus state, verified with the given proof specs. If `hash_values` is set, the
+/// proof commits the keccak256 hashes of the values instead of the values themselves.
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+pub fn prove_membership(
+    verify_mem_prover: &SP1ICS07TendermintProver<MembershipProgram>,
     trusted_consensus_state: SolConsensusState,
-    proof_type: SupportedProofType,
+    proof_specs: &ProofSpecs,
+    hash_values: bool,
+    kv_proofs: Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
 ) -> anyhow::Result<MembershipProof> {
-    let verify_mem_prover = SP1ICS07TendermintProver::<MembershipProgram>::new(proof_type);
     let commitment_root_bytes = ConsensusState::from(trusted_consensus_state.clone())
         .root
         .as_bytes()
         .to_vec();
 
-    let kv_proofs: Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)> =
-        futures::future::try_join_all(key_paths.into_iter().map(|path| async {
-            let path: Vec<Vec<u8>> = if is_base64 {
-                path.split('\\')
-                    .map(subtle_encoding::base64::decode)
-                    .collect::<Result<_, _>>()?
-            } else {
-                vec![b"ibc".into(), path.into_bytes()]
-            };
-            assert_eq!(path.len(), 2);
-
-            let res = tm_rpc_client
-                .abci_query(
-                    Some(format!("store/{}/key", str::from_utf8(&path[0])?)),
-                    path[1].as_slice(),
-                    // Proof height should be the block before the target block.
-                    Some((trusted_block - 1).into()),
-                    true,
-                )
-                .await?;
-
-            assert_eq!(u32::try_from(res.height.value())? + 1, trusted_block);
-            assert_eq!(res.key.as_slice(), path[1].as_slice());
-            let vm_proof = convert_tm_to_ics_merkle_proof(&res.proof.unwrap())?;
-            assert!(!vm_proof.proofs.is_empty());
-
-            anyhow::Ok((path, res.value, vm_proof))
-        }))
-        .await?;
-
     // Generate a header update proof for the specified blocks.
-    let proof_data = verify_mem_prover.generate_proof(&commitment_root_bytes, kv_proofs);
+    let proof_data = verify_mem_prover.generate_proof(
+        &commitment_root_bytes,
+        proof_specs,
+        hash_values,
+        kv_proofs,
+    );
 
     let bytes = proof_data.public_values.as_slice();
     let output = MembershipOutput::abi_decode(bytes, true)?;
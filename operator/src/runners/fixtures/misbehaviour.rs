@@ -2,10 +2,11 @@
 
 use crate::{
     cli::command::{fixtures::MisbehaviourCmd, OutputPath},
-    runners::genesis::SP1ICS07TendermintGenesis,
+    runners::{genesis::SP1ICS07TendermintGenesis, simulation},
 };
 use alloy_sol_types::SolValue;
 use ibc_client_tendermint_types::Misbehaviour;
+use ibc_core_commitment_types::specs::ProofSpecs;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use serde::{Deserialize, Serialize};
 use sp1_ics07_tendermint_prover::{
@@ -46,7 +47,6 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
     let tm_rpc_client = HttpClient::from_env();
 
     // get light block for trusted height of header 1
-    #[allow(clippy::cast_possible_truncation)]
     let trusted_light_block_1 = tm_rpc_client
         .get_light_block(Some(
             raw_misbehaviour
@@ -55,10 +55,9 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
                 .unwrap()
                 .trusted_height
                 .unwrap()
-                .revision_height as u32,
+                .revision_height,
         ))
         .await?;
-    #[allow(clippy::cast_possible_truncation)]
     // get light block for trusted height of header 2
     let trusted_light_block_2 = tm_rpc_client
         .get_light_block(Some(
@@ -68,7 +67,7 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
                 .unwrap()
                 .trusted_height
                 .unwrap()
-                .revision_height as u32,
+                .revision_height,
         ))
         .await?;
 
@@ -77,6 +76,8 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
         &trusted_light_block_1,
         args.trust_options.trusting_period,
         args.trust_options.trust_level,
+        args.trust_options.max_clock_drift,
+        ProofSpecs::cosmos(),
         args.proof_type,
     )
     .await?;
@@ -85,6 +86,8 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
         &trusted_light_block_2,
         args.trust_options.trusting_period,
         args.trust_options.trust_level,
+        args.trust_options.max_clock_drift,
+        ProofSpecs::cosmos(),
         args.proof_type,
     )
     .await?;
@@ -122,6 +125,14 @@ pub async fn run(args: MisbehaviourCmd) -> anyhow::Result<()> {
         ),
     };
 
+    if args.dry_run {
+        let contract = simulation::contract_from_env()?;
+        simulation::simulate_misbehaviour(&contract, &submit_msg)
+            .await?
+            .into_result()?;
+        log::info!("Simulated misbehaviour on the contract: the client would be frozen");
+    }
+
     let fixture = SP1ICS07SubmitMisbehaviourFixture {
         genesis: genesis_2,
         submit_msg: submit_msg.abi_encode(),
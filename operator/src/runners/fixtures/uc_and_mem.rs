@@ -3,13 +3,13 @@
 use crate::{
     cli::command::{fixtures::UpdateClientAndMembershipCmd, OutputPath},
     runners::{
-        fixtures::membership::SP1ICS07MembershipFixture, genesis::SP1ICS07TendermintGenesis,
+        fixtures::membership::{fetch_kv_proofs, kv_pairs, SP1ICS07MembershipFixture},
+        genesis::SP1ICS07TendermintGenesis,
+        simulation,
     },
 };
 use alloy_sol_types::SolValue;
-use core::str;
 use ibc_client_tendermint_types::ConsensusState;
-use ibc_core_commitment_types::merkle::MerkleProof;
 use sp1_ics07_tendermint_prover::{
     programs::UpdateClientAndMembershipProgram, prover::SP1ICS07TendermintProver,
 };
@@ -19,11 +19,10 @@ use sp1_ics07_tendermint_solidity::{
     ISP1Msgs::SP1Proof,
     IUpdateClientAndMembershipMsgs::UcAndMembershipOutput,
 };
-use sp1_ics07_tendermint_utils::merkle::convert_tm_to_ics_merkle_proof;
 use sp1_ics07_tendermint_utils::{light_block::LightBlockExt, rpc::TendermintRpcExt};
 use sp1_sdk::HashableKey;
 use std::path::PathBuf;
-use tendermint_rpc::{Client, HttpClient};
+use tendermint_rpc::HttpClient;
 
 /// Writes the proof data for the given trusted and target blocks to the given fixture path.
 #[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
@@ -38,16 +37,18 @@ pub async fn run(args: UpdateClientAndMembershipCmd) -> anyhow::Result<()> {
         SP1ICS07TendermintProver::<UpdateClientAndMembershipProgram>::new(args.proof_type);
 
     let trusted_light_block = tm_rpc_client
-        .get_light_block(Some(args.membership.trusted_block))
+        .get_light_block(Some(args.membership.trusted_block.into()))
         .await?;
     let target_light_block = tm_rpc_client
-        .get_light_block(Some(args.target_block))
+        .get_light_block(Some(args.target_block.into()))
         .await?;
 
     let genesis = SP1ICS07TendermintGenesis::from_env(
         &trusted_light_block,
         args.membership.trust_options.trusting_period,
         args.membership.trust_options.trust_level,
+        args.membership.trust_options.max_clock_drift,
+        args.membership.proof_specs.clone(),
         args.proof_type,
     )
     .await?;
@@ -60,49 +61,30 @@ pub async fn run(args: UpdateClientAndMembershipCmd) -> anyhow::Result<()> {
         .duration_since(std::time::UNIX_EPOCH)?
         .as_secs();
 
-    let kv_proofs: Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)> =
-        futures::future::try_join_all(args.membership.key_paths.into_iter().map(|path| async {
-            let path: Vec<Vec<u8>> = if args.membership.base64 {
-                path.split('\\')
-                    .map(subtle_encoding::base64::decode)
-                    .collect::<Result<_, _>>()?
-            } else {
-                vec![b"ibc".into(), path.into_bytes()]
-            };
-            assert_eq!(path.len(), 2);
-
-            let res = tm_rpc_client
-                .abci_query(
-                    Some(format!("store/{}/key", str::from_utf8(&path[0])?)),
-                    path[1].as_slice(),
-                    // Proof height should be the block before the target block.
-                    Some((args.target_block - 1).into()),
-                    true,
-                )
-                .await?;
-
-            assert_eq!(u32::try_from(res.height.value())? + 1, args.target_block);
-            assert_eq!(res.key.as_slice(), path[1].as_slice());
-            let vm_proof = convert_tm_to_ics_merkle_proof(&res.proof.unwrap())?;
-            assert!(!vm_proof.proofs.is_empty());
-
-            anyhow::Ok((path, res.value, vm_proof))
-        }))
-        .await?;
+    let kv_proofs = fetch_kv_proofs(
+        &tm_rpc_client,
+        args.membership.base64,
+        &args.membership.commitment_prefix,
+        args.membership.key_paths,
+        args.target_block,
+    )
+    .await?;
 
-    let kv_len = kv_proofs.len();
+    let kv_pairs = kv_pairs(&kv_proofs);
     // Generate a header update proof for the specified blocks.
     let proof_data = uc_mem_prover.generate_proof(
         &trusted_client_state,
         &trusted_consensus_state.into(),
         &proposed_header,
         now,
+        &genesis.proof_specs,
+        args.membership.hash_values,
         kv_proofs,
     );
 
     let bytes = proof_data.public_values.as_slice();
     let output = UcAndMembershipOutput::abi_decode(bytes, false)?;
-    assert_eq!(output.kvPairs.len(), kv_len);
+    assert_eq!(output.kvPairs.len(), kv_pairs.len());
 
     let sp1_membership_proof = SP1MembershipAndUpdateClientProof {
         sp1Proof: SP1Proof::new(
@@ -112,10 +94,25 @@ pub async fn run(args: UpdateClientAndMembershipCmd) -> anyhow::Result<()> {
         ),
     };
 
+    let membership_proof = MembershipProof::from(sp1_membership_proof);
+
+    if args.membership.dry_run {
+        let contract = simulation::contract_from_env()?;
+        let timestamps = simulation::simulate_membership(
+            &contract,
+            &membership_proof,
+            &output.updateClientOutput.newHeight,
+            &kv_pairs,
+        )
+        .await?
+        .into_result()?;
+        log::info!("Simulated membership on the contract: timestamps {timestamps:?}");
+    }
+
     let fixture = SP1ICS07MembershipFixture {
         genesis,
         proof_height: output.updateClientOutput.newHeight.abi_encode(),
-        membership_proof: MembershipProof::from(sp1_membership_proof).abi_encode(),
+        membership_proof: membership_proof.abi_encode(),
     };
 
     match args.membership.output_path {
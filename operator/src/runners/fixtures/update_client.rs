@@ -2,9 +2,10 @@
 
 use crate::{
     cli::command::{fixtures::UpdateClientCmd, OutputPath},
-    runners::genesis::SP1ICS07TendermintGenesis,
+    runners::{genesis::SP1ICS07TendermintGenesis, simulation},
 };
 use alloy_sol_types::SolValue;
+use ibc_core_commitment_types::specs::ProofSpecs;
 use serde::{Deserialize, Serialize};
 use sp1_ics07_tendermint_prover::{
     programs::UpdateClientProgram, prover::SP1ICS07TendermintProver,
@@ -49,16 +50,18 @@ pub async fn run(args: UpdateClientCmd) -> anyhow::Result<()> {
     let uc_prover = SP1ICS07TendermintProver::<UpdateClientProgram>::new(args.proof_type);
 
     let trusted_light_block = tm_rpc_client
-        .get_light_block(Some(args.trusted_block))
+        .get_light_block(Some(args.trusted_block.into()))
         .await?;
     let target_light_block = tm_rpc_client
-        .get_light_block(Some(args.target_block))
+        .get_light_block(Some(args.target_block.into()))
         .await?;
 
     let genesis = SP1ICS07TendermintGenesis::from_env(
         &trusted_light_block,
         args.trust_options.trusting_period,
         args.trust_options.trust_level,
+        args.trust_options.max_clock_drift,
+        ProofSpecs::cosmos(),
         args.proof_type,
     )
     .await?;
@@ -90,6 +93,14 @@ pub async fn run(args: UpdateClientCmd) -> anyhow::Result<()> {
         ),
     };
 
+    if args.dry_run {
+        let contract = simulation::contract_from_env()?;
+        let results = simulation::simulate_update_client(&contract, &[update_msg.clone()])
+            .await?
+            .into_result()?;
+        log::info!("Simulated updateClient on the contract: {results:?}");
+    }
+
     let fixture = SP1ICS07UpdateClientFixture {
         genesis,
         target_consensus_state: output.newConsensusState.abi_encode(),
@@ -2,10 +2,11 @@
 
 use crate::cli::command::{genesis::Args, OutputPath};
 use alloy_sol_types::SolValue;
+use ibc_core_commitment_types::specs::ProofSpecs;
 use sp1_ics07_tendermint_prover::{
     programs::{
-        MembershipProgram, MisbehaviourProgram, SP1Program, UpdateClientAndMembershipProgram,
-        UpdateClientProgram,
+        MembershipProgram, MisbehaviourProgram, MultiMembershipProgram, MultiUpdateClientProgram,
+        SP1Program, UpdateClientAndMembershipProgram, UpdateClientProgram, UpgradeClientProgram,
     },
     prover::SupportedProofType,
 };
@@ -28,6 +29,8 @@ pub struct SP1ICS07TendermintGenesis {
     /// The encoded trusted consensus state.
     #[serde_as(as = "serde_with::hex::Hex")]
     pub trusted_consensus_state: Vec<u8>,
+    /// The ICS-23 proof specs of the client, whose hash is in the trusted client state.
+    pub proof_specs: ProofSpecs,
     /// The encoded key for [`UpdateClientProgram`].
     update_client_vkey: String,
     /// The encoded key for [`MembershipProgram`].
@@ -36,6 +39,12 @@ pub struct SP1ICS07TendermintGenesis {
     uc_and_membership_vkey: String,
     /// The encoded key for [`MisbehaviourProgram`].
     misbehaviour_vkey: String,
+    /// The encoded key for [`UpgradeClientProgram`].
+    upgrade_client_vkey: String,
+    /// The encoded key for [`MultiUpdateClientProgram`].
+    multi_update_client_vkey: String,
+    /// The encoded key for [`MultiMembershipProgram`].
+    multi_membership_vkey: String,
 }
 
 impl SP1ICS07TendermintGenesis {
@@ -46,6 +55,8 @@ impl SP1ICS07TendermintGenesis {
         trusted_light_block: &LightBlock,
         trusting_period: Option<u32>,
         trust_level: TrustThreshold,
+        max_clock_drift: u32,
+        proof_specs: ProofSpecs,
         proof_type: SupportedProofType,
     ) -> anyhow::Result<Self> {
         setup_logger();
@@ -75,6 +86,8 @@ impl SP1ICS07TendermintGenesis {
             trust_level.try_into()?,
             unbonding_period,
             trusting_period,
+            max_clock_drift,
+            &proof_specs,
             proof_type.into(),
         )?;
         let trusted_consensus_state = trusted_light_block.to_consensus_state();
@@ -82,10 +95,14 @@ impl SP1ICS07TendermintGenesis {
         Ok(Self {
             trusted_consensus_state: SolConsensusState::from(trusted_consensus_state).abi_encode(),
             trusted_client_state: trusted_client_state.abi_encode(),
+            proof_specs,
             update_client_vkey: UpdateClientProgram::get_vkey().bytes32(),
             membership_vkey: MembershipProgram::get_vkey().bytes32(),
             uc_and_membership_vkey: UpdateClientAndMembershipProgram::get_vkey().bytes32(),
             misbehaviour_vkey: MisbehaviourProgram::get_vkey().bytes32(),
+            upgrade_client_vkey: UpgradeClientProgram::get_vkey().bytes32(),
+            multi_update_client_vkey: MultiUpdateClientProgram::get_vkey().bytes32(),
+            multi_membership_vkey: MultiMembershipProgram::get_vkey().bytes32(),
         })
     }
 }
@@ -95,7 +112,9 @@ impl SP1ICS07TendermintGenesis {
 pub async fn run(args: Args) -> anyhow::Result<()> {
     let tm_rpc_client = HttpClient::from_env();
 
-    let trusted_light_block = tm_rpc_client.get_light_block(args.trusted_block).await?;
+    let trusted_light_block = tm_rpc_client
+        .get_light_block(args.trusted_block.map(Into::into))
+        .await?;
     if args.trusted_block.is_none() {
         log::info!(
             "Latest block height: {}",
@@ -107,6 +126,8 @@ pub async fn run(args: Args) -> anyhow::Result<()> {
         &trusted_light_block,
         args.trust_options.trusting_period,
         args.trust_options.trust_level,
+        args.trust_options.max_clock_drift,
+        args.proof_specs,
         args.proof_type,
     )
     .await?;
@@ -3,3 +3,6 @@
 pub mod fixtures;
 pub mod genesis;
 pub mod operator;
+pub mod recover;
+pub mod simulation;
+pub mod upgrade;
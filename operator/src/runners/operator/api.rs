@@ -0,0 +1,429 @@
+//! Contains the proof service API of the operator, which generates proofs on demand for
+//! relayers.
+//!
+//! The API serves:
+//! - `POST /update`: proves the update of a client from its latest height to a target height, and
+//!   returns the encoded `MsgUpdateClient`s to submit in order.
+//! - `POST /membership`: proves the (non)membership of key paths at a height of a client, and
+//!   returns an encoded `MsgMembership` for each key path.
+//!
+//! Heights are full ICS-02 heights, i.e. `{ "revisionNumber": 4, "revisionHeight": 100 }`, so
+//! that a height of a previous revision of the chain is not mistaken for one of the current
+//! revision.
+//!
+//! Nothing is submitted to the contracts. Requests are queued and processed one at a time, since
+//! proving uses most of the resources of the machine.
+
+use std::{collections::HashMap, future::Future, net::SocketAddr, sync::Arc};
+
+use alloy::{
+    primitives::{keccak256, Bytes},
+    providers::ProviderBuilder,
+};
+use alloy_sol_types::SolValue;
+use anyhow::anyhow;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use futures::future::BoxFuture;
+use log::{error, info};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_prover::{
+    programs::{MembershipProgram, UpdateClientProgram},
+    prover::SupportedProofType,
+};
+use sp1_ics07_tendermint_solidity::{
+    proof_specs_hash,
+    sp1_ics07_tendermint::{self, sp1_ics07_tendermintErrors as ContractError},
+    IICS02ClientMsgs::Height,
+    IICS07TendermintMsgs::ConsensusState as SolConsensusState,
+    ILightClientMsgs::MsgMembership,
+};
+use sp1_ics07_tendermint_utils::{light_block::LightBlockExt, rpc::TendermintRpcExt};
+use tendermint::Time;
+use tendermint_rpc::HttpClient;
+use tokio::sync::{mpsc, oneshot};
+
+use super::{
+    bisection, config::ClientConfig, prove_update, provers::SharedProvers, unix_now,
+    verifier_options,
+};
+use crate::runners::{fixtures::membership, simulation::contract_revert};
+
+/// The maximum number of requests waiting to be processed.
+const QUEUE_CAPACITY: usize = 64;
+
+/// A request for the update of a client to a target height.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateRequest {
+    /// The name of the client.
+    pub client: String,
+    /// The height to update the client to.
+    pub target_height: Height,
+}
+
+/// The update client messages reaching the requested height.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateResponse {
+    /// The heights reached by the messages, the last one being the requested height.
+    pub heights: Vec<Height>,
+    /// The encoded update client messages, to submit in order.
+    #[serde_as(as = "Vec<serde_with::hex::Hex>")]
+    pub update_msgs: Vec<Vec<u8>>,
+}
+
+/// A request for the (non)membership proof of key paths.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MembershipRequest {
+    /// The name of the client.
+    pub client: String,
+    /// The key paths to prove, in the format of the `fixtures membership` command, under the
+    /// commitment prefix of the client.
+    pub key_paths: Vec<String>,
+    /// Indicates that the key paths are base64 encoded.
+    #[serde(default)]
+    pub base64: bool,
+    /// Indicates that the proof commits the keccak256 hashes of the values instead of the values,
+    /// which the contract checks against the hashes of the values of the messages.
+    #[serde(default)]
+    pub hash_values: bool,
+    /// The height to prove the key paths at, which must be known to the client.
+    /// Defaults to the latest height of the client.
+    pub height: Option<Height>,
+}
+
+/// The membership messages for the requested key paths.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipResponse {
+    /// The height of the proof.
+    pub proof_height: Height,
+    /// The encoded membership messages, one per key path in the order of the request.
+    #[serde_as(as = "Vec<serde_with::hex::Hex>")]
+    pub membership_msgs: Vec<Vec<u8>>,
+}
+
+/// An error returned by the API.
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+/// The state shared by the handlers of the API.
+#[derive(Clone)]
+struct ProofService {
+    clients: Arc<HashMap<String, ClientConfig>>,
+    update_provers: Arc<SharedProvers<UpdateClientProgram>>,
+    membership_provers: Arc<SharedProvers<MembershipProgram>>,
+    queue: mpsc::Sender<BoxFuture<'static, ()>>,
+}
+
+/// Serves the proof service API on the given address for the given clients.
+///
+/// # Errors
+/// Returns an error if the server fails.
+pub async fn serve(
+    addr: SocketAddr,
+    clients: Vec<ClientConfig>,
+    update_provers: Arc<SharedProvers<UpdateClientProgram>>,
+    membership_provers: Arc<SharedProvers<MembershipProgram>>,
+) -> anyhow::Result<()> {
+    let (queue, mut jobs) = mpsc::channel::<BoxFuture<'static, ()>>(QUEUE_CAPACITY);
+    tokio::spawn(async move {
+        while let Some(job) = jobs.recv().await {
+            // Every job runs in its own task, so that a panicking prover does not stop the queue.
+            if let Err(err) = tokio::spawn(job).await {
+                error!("A proof request has failed: {err}");
+            }
+        }
+    });
+
+    let service = ProofService {
+        clients: Arc::new(clients.into_iter().map(|c| (c.name.clone(), c)).collect()),
+        update_provers,
+        membership_provers,
+        queue,
+    };
+
+    let app = Router::new()
+        .route("/update", post(update_handler))
+        .route("/membership", post(membership_handler))
+        .with_state(service);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("Serving the proof service on http://{addr}");
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn update_handler(
+    State(service): State<ProofService>,
+    Json(request): Json<UpdateRequest>,
+) -> Result<Json<UpdateResponse>, ApiError> {
+    let job = service.clone().handle_update(request);
+    service.enqueue(job).await.map(Json)
+}
+
+async fn membership_handler(
+    State(service): State<ProofService>,
+    Json(request): Json<MembershipRequest>,
+) -> Result<Json<MembershipResponse>, ApiError> {
+    let job = service.clone().handle_membership(request);
+    service.enqueue(job).await.map(Json)
+}
+
+impl ProofService {
+    /// Queues a job and waits for its result.
+    async fn enqueue<T: Send + 'static>(
+        &self,
+        job: impl Future<Output = Result<T, ApiError>> + Send + 'static,
+    ) -> Result<T, ApiError> {
+        let (tx, rx) = oneshot::channel();
+        self.queue
+            .try_send(Box::pin(async move {
+                // The receiver is dropped if the request was cancelled.
+                let _ = tx.send(job.await);
+            }))
+            .map_err(|_| ApiError {
+                status: StatusCode::SERVICE_UNAVAILABLE,
+                message: "too many pending requests".to_string(),
+            })?;
+
+        rx.await
+            .map_err(|_| anyhow!("the request has failed unexpectedly"))?
+    }
+
+    /// Returns the configuration of a client.
+    fn client(&self, name: &str) -> Result<&ClientConfig, ApiError> {
+        self.clients
+            .get(name)
+            .ok_or_else(|| ApiError::bad_request(format!("unknown client '{name}'")))
+    }
+
+    /// Proves the update of a client from its latest height to the requested height.
+    async fn handle_update(self, request: UpdateRequest) -> Result<UpdateResponse, ApiError> {
+        let config = self.client(&request.client)?;
+        let contract = sp1_ics07_tendermint::new(
+            config.contract_address,
+            ProviderBuilder::new().on_http(Url::parse(&config.rpc_url)?),
+        );
+        let client_state = contract.getClientState().call().await?._0;
+
+        let trusted_height = client_state.latestHeight.clone();
+        if request.target_height <= trusted_height {
+            return Err(ApiError::bad_request(format!(
+                "the client is already at height {trusted_height}"
+            )));
+        }
+
+        info!(
+            "[{}] Proving an update from height {trusted_height} to height {}",
+            request.client, request.target_height
+        );
+        let tendermint_rpc_client = HttpClient::new(config.tendermint_rpc_url.as_str())?;
+        let prover = self
+            .update_provers
+            .get(SupportedProofType::try_from(client_state.zkAlgorithm).map_err(|e| anyhow!(e))?)
+            .await?;
+
+        let trusted_light_block = tendermint_rpc_client
+            .get_light_block(Some(trusted_height.revisionHeight.into()))
+            .await?;
+        let target_light_block = tendermint_rpc_client
+            .get_light_block(Some(request.target_height.revisionHeight.into()))
+            .await?;
+        if target_light_block.to_sol_height()? != request.target_height {
+            return Err(ApiError::bad_request(format!(
+                "the block at height {} is of chain {}",
+                request.target_height, target_light_block.signed_header.header.chain_id
+            )));
+        }
+        let light_blocks = bisection::bisect(
+            &tendermint_rpc_client,
+            &trusted_light_block,
+            target_light_block,
+            &verifier_options(&client_state),
+            Time::from_unix_timestamp(unix_now()?.try_into()?, 0)?,
+        )
+        .await?;
+
+        let mut response = UpdateResponse {
+            heights: Vec::with_capacity(light_blocks.len()),
+            update_msgs: Vec::with_capacity(light_blocks.len()),
+        };
+        let mut trusted_light_block = trusted_light_block;
+        for target_light_block in light_blocks {
+            let update_msg = prove_update(
+                prover.clone(),
+                &client_state,
+                &trusted_light_block,
+                &target_light_block,
+            )
+            .await?;
+
+            response.heights.push(target_light_block.to_sol_height()?);
+            response.update_msgs.push(update_msg.abi_encode());
+            trusted_light_block = target_light_block;
+        }
+
+        Ok(response)
+    }
+
+    /// Proves the (non)membership of key paths at a height of a client.
+    async fn handle_membership(
+        self,
+        request: MembershipRequest,
+    ) -> Result<MembershipResponse, ApiError> {
+        let config = self.client(&request.client)?;
+        if request.key_paths.is_empty() {
+            return Err(ApiError::bad_request("no key paths requested"));
+        }
+
+        let contract = sp1_ics07_tendermint::new(
+            config.contract_address,
+            ProviderBuilder::new().on_http(Url::parse(&config.rpc_url)?),
+        );
+        let client_state = contract.getClientState().call().await?._0;
+        let proof_height = request
+            .height
+            .unwrap_or_else(|| client_state.latestHeight.clone());
+        let height = proof_height.revisionHeight;
+
+        // The contract verifies the proof against its consensus state at the proof height.
+        let tendermint_rpc_client = HttpClient::new(config.tendermint_rpc_url.as_str())?;
+        let light_block = tendermint_rpc_client
+            .get_light_block(Some(height.into()))
+            .await?;
+        let consensus_state = SolConsensusState::from(light_block.to_consensus_state());
+        let contract_hash = match contract
+            .getConsensusStateHash(proof_height.clone())
+            .call()
+            .await
+        {
+            Ok(ret) => ret._0,
+            Err(alloy::contract::Error::TransportError(err))
+                if matches!(
+                    contract_revert(&err),
+                    Some(ContractError::ConsensusStateNotFound(_))
+                ) =>
+            {
+                return Err(ApiError::bad_request(format!(
+                    "the client has no consensus state at height {proof_height}, request an update first"
+                )));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if keccak256(consensus_state.abi_encode()) != contract_hash {
+            return Err(anyhow!(
+                "the consensus state of the client at height {proof_height} does not match the chain"
+            )
+            .into());
+        }
+
+        info!(
+            "[{}] Proving {} key paths at height {proof_height}",
+            request.client,
+            request.key_paths.len()
+        );
+        let proof_specs = config.proof_specs()?;
+        if proof_specs_hash(&proof_specs) != client_state.proofSpecsHash {
+            return Err(anyhow!(
+                "the proof specs of the configuration do not match the proof specs of the client"
+            )
+            .into());
+        }
+
+        let prover = self
+            .membership_provers
+            .get(SupportedProofType::try_from(client_state.zkAlgorithm).map_err(|e| anyhow!(e))?)
+            .await?;
+        let kv_proofs = membership::fetch_kv_proofs(
+            &tendermint_rpc_client,
+            request.base64,
+            &config.commitment_prefix(),
+            request.key_paths,
+            height,
+        )
+        .await?;
+        let kv_pairs: Vec<(Vec<Bytes>, Bytes)> = kv_proofs
+            .iter()
+            .map(|(path, value, _)| {
+                (
+                    path.iter().cloned().map(Into::into).collect(),
+                    value.clone().into(),
+                )
+            })
+            .collect();
+
+        // Proving is blocking, so it runs on a separate thread to not block the clients.
+        let proof: Bytes = tokio::task::spawn_blocking(move || {
+            membership::prove_membership(
+                &prover,
+                consensus_state,
+                &proof_specs,
+                request.hash_values,
+                kv_proofs,
+            )
+        })
+        .await??
+        .abi_encode()
+        .into();
+
+        Ok(MembershipResponse {
+            proof_height: proof_height.clone(),
+            membership_msgs: kv_pairs
+                .into_iter()
+                .map(|(path, value)| {
+                    MsgMembership {
+                        proof: proof.clone(),
+                        proofHeight: proof_height.clone(),
+                        path,
+                        value,
+                    }
+                    .abi_encode()
+                })
+                .collect(),
+        })
+    }
+}
+
+impl ApiError {
+    /// Creates an error for an invalid request.
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+}
+
+impl<E: Into<anyhow::Error>> From<E> for ApiError {
+    fn from(err: E) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: format!("{:#}", err.into()),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            Json(serde_json::json!({ "error": self.message })),
+        )
+            .into_response()
+    }
+}
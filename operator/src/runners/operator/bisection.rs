@@ -0,0 +1,163 @@
+//! Contains the host-side bisection of the Tendermint light client.
+//!
+//! The update client program panics if the proposed header cannot be verified from the trusted
+//! consensus state, for example if the validator set changed by more than the trust threshold.
+//! Running the same verification on the host before proving lets the operator find intermediate
+//! heights that can each be verified, instead of paying for a proof that cannot be generated.
+//!
+//! As in the update client program, a block of a later revision of the chain, e.g. `foo-2` after
+//! `foo-1`, is verified against its own chain identifier, so that the client can follow the chain
+//! across a revision change.
+
+use std::collections::HashMap;
+
+use sp1_ics07_tendermint_solidity::revision_chain_id;
+use sp1_ics07_tendermint_utils::rpc::TendermintRpcExt;
+use tendermint::{chain, Time};
+use tendermint_light_client_verifier::{
+    options::Options, types::LightBlock, ProdVerifier, Verdict, Verifier,
+};
+use tendermint_rpc::HttpClient;
+
+/// Finds a sequence of light blocks from `trusted` to `target` in which every block can be
+/// verified from the previous one, using the skipping verification of the light client.
+///
+/// The returned sequence does not include `trusted` and ends with `target`. If `target` can be
+/// verified directly from `trusted`, the sequence only contains `target`.
+///
+/// # Errors
+/// Returns an error if a light block cannot be fetched, if a light block is invalid, or if two
+/// adjacent blocks cannot be verified.
+pub async fn bisect(
+    tm_rpc_client: &HttpClient,
+    trusted: &LightBlock,
+    target: LightBlock,
+    options: &Options,
+    now: Time,
+) -> anyhow::Result<Vec<LightBlock>> {
+    let verifier = ProdVerifier::default();
+    let target_height = target.height().value();
+    let mut light_blocks = HashMap::from([(target_height, target)]);
+
+    let mut trusted = trusted.clone();
+    let mut pivot_height = target_height;
+    let mut path = Vec::new();
+
+    while trusted.height().value() < target_height {
+        if !light_blocks.contains_key(&pivot_height) {
+            let light_block = tm_rpc_client.get_light_block(Some(pivot_height)).await?;
+            light_blocks.insert(pivot_height, light_block);
+        }
+        let pivot = &light_blocks[&pivot_height];
+
+        let trusted_height = trusted.height().value();
+        let mut trusted_state = trusted.as_trusted_state();
+        trusted_state.chain_id =
+            verified_chain_id(trusted_state.chain_id, &pivot.signed_header.header.chain_id);
+        match verifier.verify_update_header(pivot.as_untrusted_state(), trusted_state, options, now)
+        {
+            Verdict::Success => {
+                log::debug!("Block {pivot_height} can be verified from block {trusted_height}");
+                trusted = pivot.clone();
+                path.push(pivot.clone());
+                pivot_height = target_height;
+            }
+            Verdict::NotEnoughTrust(tally) => {
+                let Some(lower_pivot_height) = lower_pivot(trusted_height, pivot_height) else {
+                    anyhow::bail!(
+                        "block {pivot_height} cannot be verified from block {trusted_height}: {tally:?}"
+                    );
+                };
+                log::debug!(
+                    "Not enough trust to verify block {pivot_height} from block {trusted_height}, bisecting"
+                );
+                pivot_height = lower_pivot_height;
+            }
+            Verdict::Invalid(detail) => {
+                anyhow::bail!(
+                    "block {pivot_height} is invalid with respect to block {trusted_height}: {detail:?}"
+                );
+            }
+        }
+    }
+
+    Ok(path)
+}
+
+/// Returns the height halfway between `trusted_height` and `pivot_height`, to verify next when
+/// there is not enough trust to verify `pivot_height` from `trusted_height`.
+///
+/// Returns `None` if `pivot_height` is the block right after `trusted_height`, which cannot be
+/// bisected further.
+const fn lower_pivot(trusted_height: u64, pivot_height: u64) -> Option<u64> {
+    if pivot_height <= trusted_height + 1 {
+        return None;
+    }
+    Some(trusted_height + (pivot_height - trusted_height) / 2)
+}
+
+/// Returns the chain identifier that a block of `pivot_chain_id` is verified against from a
+/// trusted block of `trusted_chain_id`, which is the chain identifier of the block if it is a
+/// later revision of the chain of the trusted block.
+fn verified_chain_id<'a>(
+    trusted_chain_id: &'a chain::Id,
+    pivot_chain_id: &'a chain::Id,
+) -> &'a chain::Id {
+    if revision_chain_id(trusted_chain_id.as_str(), pivot_chain_id.as_str())
+        == pivot_chain_id.as_str()
+    {
+        pivot_chain_id
+    } else {
+        trusted_chain_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::chain;
+
+    use super::{lower_pivot, verified_chain_id};
+
+    fn chain_id(id: &str) -> chain::Id {
+        chain::Id::try_from(id).unwrap()
+    }
+
+    #[test]
+    fn test_lower_pivot() {
+        assert_eq!(lower_pivot(100, 200), Some(150));
+        assert_eq!(lower_pivot(100, 150), Some(125));
+        // rounded down, but always above the trusted height
+        assert_eq!(lower_pivot(100, 103), Some(101));
+        assert_eq!(lower_pivot(100, 102), Some(101));
+        // the block right after the trusted block cannot be bisected
+        assert_eq!(lower_pivot(100, 101), None);
+        assert_eq!(lower_pivot(100, 100), None);
+    }
+
+    #[test]
+    fn test_lower_pivot_converges() {
+        // bisecting repeatedly reaches the block right after the trusted block
+        let mut pivots = vec![];
+        let mut pivot_height = 116;
+        while let Some(lower_pivot_height) = lower_pivot(100, pivot_height) {
+            pivots.push(lower_pivot_height);
+            pivot_height = lower_pivot_height;
+        }
+        assert_eq!(pivots, vec![108, 104, 102, 101]);
+    }
+
+    #[test]
+    fn test_verified_chain_id() {
+        let (foo_1, foo_2) = (chain_id("foo-1"), chain_id("foo-2"));
+        assert_eq!(verified_chain_id(&foo_1, &foo_1), &foo_1);
+        // a later revision is verified against its own chain identifier
+        assert_eq!(verified_chain_id(&foo_1, &foo_2), &foo_2);
+        // an earlier revision or another chain is verified against the trusted chain identifier
+        assert_eq!(verified_chain_id(&foo_2, &foo_1), &foo_2);
+        let bar_2 = chain_id("bar-2");
+        assert_eq!(verified_chain_id(&foo_1, &bar_2), &foo_1);
+        let foo = chain_id("foo");
+        assert_eq!(verified_chain_id(&foo, &foo_1), &foo_1);
+        assert_eq!(verified_chain_id(&foo_1, &foo), &foo_1);
+    }
+}
@@ -0,0 +1,347 @@
+//! Contains the configuration file of the operator.
+//!
+//! The configuration describes one or more light clients that are operated concurrently by a
+//! single operator process. It can be written either in TOML or in JSON, e.g.:
+//!
+//! ```toml
+//! [[clients]]
+//! name = "celestia-mocha"
+//! tendermint_rpc_url = "http://public-celestia-mocha4-consensus.numia.xyz/"
+//! rpc_url = "https://ethereum-holesky-rpc.publicnode.com"
+//! contract_address = "0x..."
+//! signer = { type = "env", var = "PRIVATE_KEY" }
+//! pipelined = true
+//! # or: signer = { type = "keystore", path = "keystore.json", password_file = "password.txt" }
+//! # or: signer = { type = "mnemonic", var = "MNEMONIC", derivation_path = "m/44'/60'/0'/0/0" }
+//! # or: signer = { type = "remote", url = "http://localhost:9000", address = "0x..." }
+//! proof_specs = "iavl"
+//! commitment_prefix = "ibc\\"
+//!
+//! [clients.update_policy]
+//! max_block_lag = 100
+//! trusting_period_fraction = 0.5
+//! schedule = "0 0 * * * *"
+//! min_spacing = 300
+//!
+//! [clients.watchdog]
+//! warning_threshold = 0.6
+//! critical_threshold = 0.8
+//! alert_webhook = "https://alerts.example.com/hook"
+//!
+//! [clients.retry]
+//! initial_backoff = 5
+//! max_backoff = 600
+//!
+//! [clients.fees]
+//! max_fee_per_gas = 100_000_000_000
+//! max_gas_price = 30_000_000_000
+//! stuck_timeout = 180
+//!
+//! [clients.misbehaviour_monitor]
+//! tendermint_rpc_urls = ["https://celestia-testnet-rpc.example.com/"]
+//! ```
+
+use std::{convert::Infallible, env, path::Path};
+
+use alloy::{network::EthereumWallet, primitives::Address};
+use ibc_core_commitment_types::specs::ProofSpecs;
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_utils::{
+    eth::{self, SignerError},
+    key_path::{CommitmentPrefix, DEFAULT_COMMITMENT_PREFIX},
+    proof_specs::{load_proof_specs, ProofSpecsPreset},
+};
+
+use crate::cli::command::operator::SignerArgs;
+
+use super::{
+    misbehaviour::MisbehaviourMonitorConfig, policy::UpdatePolicy, retry::RetryConfig,
+    tx::FeeConfig, watchdog::WatchdogConfig,
+};
+
+/// The configuration of the operator.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OperatorConfig {
+    /// The light clients to operate.
+    pub clients: Vec<ClientConfig>,
+}
+
+/// The configuration of a single light client.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// A unique name for the client, used in logs and for the default state path.
+    pub name: String,
+    /// URL of the Tendermint RPC node of the counterparty chain.
+    pub tendermint_rpc_url: String,
+    /// URL of the EVM RPC node.
+    pub rpc_url: String,
+    /// Address of the light client contract.
+    pub contract_address: Address,
+    /// The signer used to submit transactions.
+    #[serde(default)]
+    pub signer: SignerConfig,
+    /// The update policy of the client.
+    #[serde(default)]
+    pub update_policy: UpdatePolicyConfig,
+    /// The trusting period expiry watchdog of the client.
+    #[serde(default)]
+    pub watchdog: WatchdogConfig,
+    /// The misbehaviour monitor of the client. The client is not monitored if not set.
+    pub misbehaviour_monitor: Option<MisbehaviourMonitorConfig>,
+    /// The retries of the client after transient or proof errors.
+    #[serde(default)]
+    pub retry: RetryConfig,
+    /// The fees of the transactions of the client.
+    #[serde(default)]
+    pub fees: FeeConfig,
+    /// Prove the next update while the transaction of the previous update confirms.
+    #[serde(default)]
+    pub pipelined: bool,
+    /// The ICS-23 proof specs of the (non)membership proofs of the client, either a preset, i.e.
+    /// `iavl`, `smt` or `jmt`, or the path to a JSON file of proof specs. [default: `iavl`]
+    #[serde(default = "default_proof_specs")]
+    pub proof_specs: String,
+    /// The commitment prefix of the key paths requested from the proof service API, as segments
+    /// separated by a backslash. The key is appended to the last segment. [default: `ibc\`]
+    #[serde(default = "default_commitment_prefix")]
+    pub commitment_prefix: String,
+    /// Path to the state file of the client. [default: `operator-state-<name>.json`]
+    pub state_path: Option<String>,
+}
+
+/// The signer used by a client to submit transactions.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum SignerConfig {
+    /// A hex encoded private key read from an environment variable.
+    Env {
+        /// The name of the environment variable.
+        var: String,
+    },
+    /// An encrypted JSON keystore.
+    Keystore {
+        /// Path to the keystore file.
+        path: String,
+        /// Path to the file containing the password of the keystore.
+        password_file: String,
+    },
+    /// A BIP-39 mnemonic read from an environment variable.
+    Mnemonic {
+        /// The name of the environment variable.
+        var: String,
+        /// The derivation path of the account. [default: `m/44'/60'/0'/0/0`]
+        #[serde(default = "default_derivation_path")]
+        derivation_path: String,
+    },
+    /// An account of a remote signer, which signs transactions over JSON-RPC.
+    Remote {
+        /// URL of the remote signer.
+        url: String,
+        /// Address of the account.
+        address: Address,
+    },
+}
+
+/// The update policy of a client.
+/// A client is updated as soon as any of the configured conditions is met, but never more often
+/// than `min_spacing`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UpdatePolicyConfig {
+    /// Update when the chain is ahead of the client by at least this many blocks.
+    pub max_block_lag: Option<u64>,
+    /// Update when the time since the latest consensus state exceeds this fraction of the
+    /// trusting period of the client.
+    pub trusting_period_fraction: Option<f64>,
+    /// Update at the times given by this cron expression, with a seconds field,
+    /// e.g. `0 */30 * * * *` for every 30 minutes.
+    pub schedule: Option<String>,
+    /// The minimum time between two updates in seconds.
+    pub min_spacing: u64,
+    /// The time between two evaluations of the policy in seconds.
+    pub poll_interval: u64,
+}
+
+impl OperatorConfig {
+    /// Reads the configuration from a file.
+    /// Files with a `.json` extension are parsed as JSON, all other files are parsed as TOML.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed, or if the configuration is
+    /// invalid.
+    pub fn from_file(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        let config: Self = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&contents)?
+        } else {
+            toml::from_str(&contents)?
+        };
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Creates a configuration with a single client from the `TENDERMINT_RPC_URL`, `RPC_URL` and
+    /// `CONTRACT_ADDRESS` environment variables, signing with the given signer.
+    ///
+    /// # Errors
+    /// Returns an error if an environment variable is not set or cannot be parsed.
+    pub fn from_env(state_path: String, signer: SignerConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            clients: vec![ClientConfig {
+                name: "default".to_string(),
+                tendermint_rpc_url: env::var("TENDERMINT_RPC_URL")
+                    .map_err(|_| anyhow::anyhow!("TENDERMINT_RPC_URL not set"))?,
+                rpc_url: env::var("RPC_URL").map_err(|_| anyhow::anyhow!("RPC_URL not set"))?,
+                contract_address: env::var("CONTRACT_ADDRESS")
+                    .map_err(|_| anyhow::anyhow!("CONTRACT_ADDRESS not set"))?
+                    .parse()?,
+                signer,
+                update_policy: UpdatePolicyConfig::default(),
+                watchdog: WatchdogConfig::default(),
+                misbehaviour_monitor: None,
+                retry: RetryConfig::default(),
+                fees: FeeConfig::default(),
+                pipelined: false,
+                proof_specs: default_proof_specs(),
+                commitment_prefix: default_commitment_prefix(),
+                state_path: Some(state_path),
+            }],
+        })
+    }
+
+    /// Validates the configuration.
+    ///
+    /// # Errors
+    /// Returns an error if no clients are configured, if client names are not unique or if an
+    /// update policy, watchdog or proof specs are invalid.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.clients.is_empty() {
+            anyhow::bail!("no clients configured");
+        }
+
+        let mut names = std::collections::HashSet::new();
+        for client in &self.clients {
+            if !names.insert(client.name.as_str()) {
+                anyhow::bail!("duplicate client name: {}", client.name);
+            }
+            UpdatePolicy::try_from(&client.update_policy)
+                .map_err(|e| anyhow::anyhow!("client {}: {e}", client.name))?;
+            client
+                .watchdog
+                .validate()
+                .map_err(|e| anyhow::anyhow!("client {}: {e}", client.name))?;
+            client
+                .proof_specs()
+                .map_err(|e| anyhow::anyhow!("client {}: {e}", client.name))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClientConfig {
+    /// Returns the path to the state file of the client.
+    #[must_use]
+    pub fn state_path(&self) -> String {
+        self.state_path
+            .clone()
+            .unwrap_or_else(|| format!("operator-state-{}.json", self.name))
+    }
+
+    /// Returns the commitment prefix of the key paths of the client.
+    #[must_use]
+    pub fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.commitment_prefix
+            .parse()
+            .unwrap_or_else(|e: Infallible| match e {})
+    }
+
+    /// Loads the proof specs of the client.
+    ///
+    /// # Errors
+    /// Returns an error if the proof specs are neither a preset nor a valid proof specs file.
+    pub fn proof_specs(&self) -> anyhow::Result<ProofSpecs> {
+        load_proof_specs(&self.proof_specs)
+    }
+}
+
+impl SignerConfig {
+    /// Creates the wallet described by the signer configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the key cannot be loaded.
+    pub fn wallet(&self) -> Result<EthereumWallet, SignerError> {
+        match self {
+            Self::Env { var } => eth::wallet_from_env_var(var),
+            Self::Keystore {
+                path,
+                password_file,
+            } => eth::wallet_from_keystore(path, password_file),
+            Self::Mnemonic {
+                var,
+                derivation_path,
+            } => eth::wallet_from_mnemonic_env_var(var, derivation_path),
+            Self::Remote { url, address } => eth::wallet_from_remote_signer(url, *address),
+        }
+    }
+}
+
+impl From<SignerArgs> for SignerConfig {
+    fn from(args: SignerArgs) -> Self {
+        if let Some(path) = args.keystore {
+            Self::Keystore {
+                path,
+                password_file: args.keystore_password_file.unwrap_or_default(),
+            }
+        } else if let Some(var) = args.mnemonic_var {
+            Self::Mnemonic {
+                var,
+                derivation_path: args.derivation_path,
+            }
+        } else if let (Some(url), Some(address)) =
+            (args.remote_signer_url, args.remote_signer_address)
+        {
+            Self::Remote { url, address }
+        } else {
+            Self::default()
+        }
+    }
+}
+
+impl Default for SignerConfig {
+    fn default() -> Self {
+        Self::Env {
+            var: "PRIVATE_KEY".to_string(),
+        }
+    }
+}
+
+fn default_derivation_path() -> String {
+    eth::DEFAULT_DERIVATION_PATH.to_string()
+}
+
+fn default_commitment_prefix() -> String {
+    DEFAULT_COMMITMENT_PREFIX.to_string()
+}
+
+fn default_proof_specs() -> String {
+    ProofSpecsPreset::Iavl.to_string()
+}
+
+impl Default for UpdatePolicyConfig {
+    /// Updates the client whenever it is behind the chain, at most once a minute, which is the
+    /// behaviour of the operator without a configured policy.
+    fn default() -> Self {
+        Self {
+            max_block_lag: Some(1),
+            trusting_period_fraction: Some(1.0 / 3.0),
+            schedule: None,
+            min_spacing: 60,
+            poll_interval: 30,
+        }
+    }
+}
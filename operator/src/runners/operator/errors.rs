@@ -0,0 +1,265 @@
+//! Contains the classification of the errors of the operator.
+//!
+//! Errors are classified to decide whether the operator should retry:
+//! - [`ErrorClass::Transient`] errors are caused by the network or an RPC node, and are retried.
+//! - [`ErrorClass::Proof`] errors are caused by a proof that could not be generated or was
+//!   rejected by the contract, and are retried with a new proof.
+//! - [`ErrorClass::Fatal`] errors cannot be fixed by retrying, e.g. a frozen client or a
+//!   verification key mismatch, and stop the client.
+
+use std::fmt;
+
+use alloy::{providers::PendingTransactionError, transports::TransportError};
+use sp1_ics07_tendermint_solidity::sp1_ics07_tendermint::sp1_ics07_tendermintErrors as ContractError;
+
+use crate::runners::simulation::contract_revert;
+
+/// The class of an error of the operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    /// A network or RPC error.
+    Transient,
+    /// A proof generation or verification error.
+    Proof,
+    /// An error that cannot be fixed by retrying.
+    Fatal,
+}
+
+/// An error raised by the operator itself, with a known class.
+#[derive(Debug)]
+pub struct OperatorError {
+    class: ErrorClass,
+    message: String,
+}
+
+impl ErrorClass {
+    /// Classifies an error by looking for known errors in its chain of causes.
+    /// Unknown errors are fatal.
+    #[must_use]
+    pub fn of(err: &anyhow::Error) -> Self {
+        for cause in err.chain() {
+            if let Some(err) = cause.downcast_ref::<OperatorError>() {
+                return err.class;
+            }
+            if let Some(err) = cause.downcast_ref::<tokio::task::JoinError>() {
+                // Proving runs in blocking tasks, and the prover panics if it fails.
+                return if err.is_panic() {
+                    Self::Proof
+                } else {
+                    Self::Transient
+                };
+            }
+            if let Some(alloy::contract::Error::TransportError(err)) =
+                cause.downcast_ref::<alloy::contract::Error>()
+            {
+                return Self::of_transport_error(err);
+            }
+            if let Some(err) = cause.downcast_ref::<TransportError>() {
+                return Self::of_transport_error(err);
+            }
+            if cause.is::<PendingTransactionError>()
+                || cause.is::<tendermint_rpc::Error>()
+                || cause.is::<reqwest::Error>()
+                || cause.is::<std::io::Error>()
+            {
+                return Self::Transient;
+            }
+        }
+
+        Self::Fatal
+    }
+
+    /// Classifies a transport error, decoding the revert reason of the contract if any.
+    fn of_transport_error(err: &TransportError) -> Self {
+        contract_revert(err).map_or(
+            // Errors of the node, e.g. a nonce that is too low, or of the connection.
+            Self::Transient,
+            |revert| Self::of_revert(&revert),
+        )
+    }
+
+    /// Classifies a revert of the contract.
+    #[must_use]
+    pub const fn of_revert(revert: &ContractError) -> Self {
+        match revert {
+            ContractError::FrozenClientState(_)
+            | ContractError::VerificationKeyMismatch(_)
+            | ContractError::ChainIdMismatch(_)
+            | ContractError::ClientStateMismatch(_)
+            | ContractError::TrustThresholdMismatch(_)
+            | ContractError::TrustingPeriodMismatch(_)
+            | ContractError::TrustingPeriodTooLong(_)
+            | ContractError::UnbondingPeriodMismatch(_)
+            | ContractError::MaxClockDriftMismatch(_)
+            | ContractError::ProofSpecsHashMismatch(_)
+            | ContractError::UnknownZkAlgorithm(_)
+            | ContractError::FeatureNotSupported(_) => Self::Fatal,
+            _ => Self::Proof,
+        }
+    }
+
+    /// Returns the name of the class, as used in logs and metrics.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Transient => "transient",
+            Self::Proof => "proof",
+            Self::Fatal => "fatal",
+        }
+    }
+}
+
+impl OperatorError {
+    /// Creates a new error of the given class.
+    #[must_use]
+    pub fn new(class: ErrorClass, message: impl Into<String>) -> Self {
+        Self {
+            class,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a new transient error.
+    #[must_use]
+    pub fn transient(message: impl Into<String>) -> Self {
+        Self {
+            class: ErrorClass::Transient,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a new proof error.
+    #[must_use]
+    pub fn proof(message: impl Into<String>) -> Self {
+        Self {
+            class: ErrorClass::Proof,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a new fatal error.
+    #[must_use]
+    pub fn fatal(message: impl Into<String>) -> Self {
+        Self {
+            class: ErrorClass::Fatal,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Display for OperatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for OperatorError {}
+
+#[cfg(test)]
+mod tests {
+    use alloy::{
+        primitives::{Bytes, U256},
+        transports::{RpcError, TransportError, TransportErrorKind},
+    };
+    use alloy_sol_types::SolInterface;
+    use anyhow::anyhow;
+    use sp1_ics07_tendermint_solidity::sp1_ics07_tendermint::{
+        sp1_ics07_tendermintErrors as ContractError, ConsensusStateNotFound, FrozenClientState,
+        ProofIsTooOld,
+    };
+
+    use super::{ErrorClass, OperatorError};
+
+    fn error_resp(message: &str, data: Option<Bytes>) -> TransportError {
+        let data = data.map_or_else(|| "null".to_string(), |data| format!("\"{data}\""));
+        let payload = format!(r#"{{"code":3,"message":"{message}","data":{data}}}"#);
+        RpcError::ErrorResp(serde_json::from_str(&payload).unwrap())
+    }
+
+    fn revert(err: &ContractError) -> TransportError {
+        error_resp("execution reverted", Some(err.abi_encode().into()))
+    }
+
+    #[test]
+    fn test_operator_errors() {
+        assert_eq!(
+            ErrorClass::of(&OperatorError::transient("timeout").into()),
+            ErrorClass::Transient
+        );
+        assert_eq!(
+            ErrorClass::of(&OperatorError::proof("invalid proof").into()),
+            ErrorClass::Proof
+        );
+        assert_eq!(
+            ErrorClass::of(&OperatorError::fatal("frozen").into()),
+            ErrorClass::Fatal
+        );
+        // the class is found in the chain of causes
+        let err = anyhow::Error::from(OperatorError::transient("timeout")).context("updating");
+        assert_eq!(ErrorClass::of(&err), ErrorClass::Transient);
+    }
+
+    #[test]
+    fn test_rpc_errors() {
+        let err = TransportErrorKind::custom_str("connection refused");
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Transient);
+        // errors of the node that are not reverts of the contract
+        let err = error_resp("nonce too low", None);
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Transient);
+        let err = error_resp("execution reverted", None);
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Transient);
+
+        let err = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Transient);
+    }
+
+    #[test]
+    fn test_contract_reverts() {
+        let proof_reverts = [
+            ContractError::ProofIsTooOld(ProofIsTooOld {
+                now: U256::from(100),
+                proofTimestamp: U256::from(1),
+            }),
+            ContractError::ConsensusStateNotFound(ConsensusStateNotFound {}),
+        ];
+        for revert_err in &proof_reverts {
+            assert_eq!(
+                ErrorClass::of(&revert(revert_err).into()),
+                ErrorClass::Proof
+            );
+        }
+
+        let frozen = ContractError::FrozenClientState(FrozenClientState {});
+        assert_eq!(ErrorClass::of(&revert(&frozen).into()), ErrorClass::Fatal);
+        // reverts of contract calls are classified the same way
+        let err = alloy::contract::Error::TransportError(revert(&frozen));
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Fatal);
+        let err = alloy::contract::Error::TransportError(revert(&proof_reverts[1]));
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Proof);
+    }
+
+    #[tokio::test]
+    async fn test_proof_errors() {
+        // the prover panics if it fails to generate a proof
+        let err = tokio::task::spawn_blocking(|| panic!("failed to generate the proof"))
+            .await
+            .unwrap_err();
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Proof);
+
+        let task = tokio::spawn(futures::future::pending::<()>());
+        task.abort();
+        let err = task.await.unwrap_err();
+        assert_eq!(ErrorClass::of(&err.into()), ErrorClass::Transient);
+    }
+
+    #[test]
+    fn test_unknown_errors() {
+        assert_eq!(ErrorClass::of(&anyhow!("unknown")), ErrorClass::Fatal);
+    }
+}
@@ -0,0 +1,103 @@
+//! Contains the events emitted by the operator, e.g. to drive its metrics.
+
+use std::time::Duration;
+
+use sp1_ics07_tendermint_prover::prover::{ProofEvent, ProofObserver, SupportedProofType};
+use sp1_ics07_tendermint_solidity::UpdateResult;
+use tokio::sync::mpsc;
+
+/// An event emitted by the operator.
+#[derive(Debug, Clone)]
+pub enum OperatorEvent {
+    /// The latest heights of a client and of its chain were observed.
+    Heights {
+        /// The name of the client.
+        client: String,
+        /// The latest height of the client.
+        client_height: u64,
+        /// The latest height of the chain.
+        chain_height: u64,
+    },
+    /// The time left until the trusting period of a client expires was observed.
+    Expiry {
+        /// The name of the client.
+        client: String,
+        /// The seconds until the trusting period expires, negative if it has expired.
+        seconds_left: i64,
+    },
+    /// A proof was generated.
+    ProofGenerated {
+        /// The name of the program.
+        program: &'static str,
+        /// The proof type.
+        proof_type: SupportedProofType,
+        /// The time it took to generate the proof.
+        duration: Duration,
+    },
+    /// A transaction of a client was included in a block.
+    TxIncluded {
+        /// The name of the client.
+        client: String,
+        /// The gas used by the transaction.
+        gas_used: u128,
+    },
+    /// An update of a client was submitted.
+    Updated {
+        /// The name of the client.
+        client: String,
+        /// The result of the update.
+        result: UpdateResult,
+    },
+    /// An operation of a client failed.
+    Failure {
+        /// The name of the client.
+        client: String,
+        /// The class of the error.
+        class: &'static str,
+    },
+    /// A client completed its first iteration.
+    ClientReady {
+        /// The name of the client.
+        client: String,
+    },
+    /// A client stopped.
+    ClientStopped {
+        /// The name of the client.
+        client: String,
+    },
+}
+
+/// A cheaply cloneable handle to emit [`OperatorEvent`]s.
+/// Events are dropped if nothing listens to them.
+#[derive(Debug, Clone, Default)]
+pub struct EventSender(Option<mpsc::UnboundedSender<OperatorEvent>>);
+
+impl EventSender {
+    /// Creates a new event sender and the receiver of its events.
+    #[must_use]
+    pub fn channel() -> (Self, mpsc::UnboundedReceiver<OperatorEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        (Self(Some(tx)), rx)
+    }
+
+    /// Emits an event.
+    pub fn emit(&self, event: OperatorEvent) {
+        if let Some(tx) = &self.0 {
+            // The receiver is only dropped when the operator is shutting down.
+            let _ = tx.send(event);
+        }
+    }
+
+    /// Returns a prover observer that emits [`OperatorEvent::ProofGenerated`] events.
+    #[must_use]
+    pub fn proof_observer(&self) -> ProofObserver {
+        let events = self.clone();
+        std::sync::Arc::new(move |event: &ProofEvent| {
+            events.emit(OperatorEvent::ProofGenerated {
+                program: event.program,
+                proof_type: event.proof_type,
+                duration: event.duration,
+            });
+        })
+    }
+}
@@ -0,0 +1,253 @@
+//! Contains the metrics and health HTTP server of the operator.
+//!
+//! The server exposes:
+//! - `/metrics`: the Prometheus metrics of the operator.
+//! - `/healthz`: a liveness probe, which succeeds as long as the server runs.
+//! - `/readyz`: a readiness probe, which succeeds once every client completed its first
+//!   iteration and none of them stopped.
+
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use axum::{extract::State, http::StatusCode, routing::get, Router};
+use prometheus::{
+    HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use sp1_ics07_tendermint_prover::prover::SupportedProofType;
+use sp1_ics07_tendermint_solidity::UpdateResult;
+use tokio::sync::mpsc;
+
+use super::events::OperatorEvent;
+
+/// The Prometheus metrics of the operator.
+struct Metrics {
+    registry: Registry,
+    client_height: IntGaugeVec,
+    chain_height: IntGaugeVec,
+    lag: IntGaugeVec,
+    expiry_seconds: IntGaugeVec,
+    proof_duration: HistogramVec,
+    proofs: IntCounterVec,
+    gas_used: HistogramVec,
+    updates: IntCounterVec,
+    failures: IntCounterVec,
+    /// Whether each client is ready.
+    ready: Mutex<HashMap<String, bool>>,
+}
+
+/// Serves the metrics and health endpoints on the given address, and updates the metrics from
+/// the received events.
+///
+/// # Errors
+/// Returns an error if the metrics cannot be registered or the server fails.
+pub async fn serve(
+    addr: SocketAddr,
+    clients: Vec<String>,
+    mut events: mpsc::UnboundedReceiver<OperatorEvent>,
+) -> anyhow::Result<()> {
+    let metrics = Arc::new(Metrics::new(clients)?);
+
+    tokio::spawn({
+        let metrics = metrics.clone();
+        async move {
+            while let Some(event) = events.recv().await {
+                metrics.observe(event);
+            }
+        }
+    });
+
+    let app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .route("/healthz", get(|| async { StatusCode::OK }))
+        .route("/readyz", get(ready_handler))
+        .with_state(metrics);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    log::info!("Serving metrics on http://{addr}/metrics");
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn metrics_handler(State(metrics): State<Arc<Metrics>>) -> (StatusCode, String) {
+    match TextEncoder::new().encode_to_string(&metrics.registry.gather()) {
+        Ok(body) => (StatusCode::OK, body),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn ready_handler(State(metrics): State<Arc<Metrics>>) -> StatusCode {
+    let ready = metrics.ready.lock().expect("poisoned lock");
+    if ready.values().all(|&ready| ready) {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
+impl Metrics {
+    fn new(clients: Vec<String>) -> anyhow::Result<Self> {
+        let registry = Registry::new_custom(Some("sp1_ics07_operator".to_string()), None)?;
+
+        let client_height = IntGaugeVec::new(
+            Opts::new("client_height", "The latest height of the client."),
+            &["client"],
+        )?;
+        let chain_height = IntGaugeVec::new(
+            Opts::new(
+                "chain_height",
+                "The latest height of the counterparty chain.",
+            ),
+            &["client"],
+        )?;
+        let lag = IntGaugeVec::new(
+            Opts::new(
+                "lag_blocks",
+                "The number of blocks the client lags behind the chain.",
+            ),
+            &["client"],
+        )?;
+        let expiry_seconds = IntGaugeVec::new(
+            Opts::new(
+                "trusting_period_expiry_seconds",
+                "The seconds until the trusting period of the client expires.",
+            ),
+            &["client"],
+        )?;
+        let proof_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "proof_duration_seconds",
+                "The time it took to generate a proof.",
+            )
+            .buckets(vec![
+                10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1200.0, 1800.0, 3600.0,
+            ]),
+            &["program", "proof_type"],
+        )?;
+        let proofs = IntCounterVec::new(
+            Opts::new("proofs_total", "The number of generated proofs."),
+            &["program", "proof_type"],
+        )?;
+        let gas_used = HistogramVec::new(
+            HistogramOpts::new("tx_gas_used", "The gas used by the transactions.")
+                .buckets(prometheus::exponential_buckets(100_000.0, 2.0, 8)?),
+            &["client"],
+        )?;
+        let updates = IntCounterVec::new(
+            Opts::new(
+                "updates_total",
+                "The number of submitted updates by result.",
+            ),
+            &["client", "result"],
+        )?;
+        let failures = IntCounterVec::new(
+            Opts::new("failures_total", "The number of failures."),
+            &["client", "class"],
+        )?;
+
+        registry.register(Box::new(client_height.clone()))?;
+        registry.register(Box::new(chain_height.clone()))?;
+        registry.register(Box::new(lag.clone()))?;
+        registry.register(Box::new(expiry_seconds.clone()))?;
+        registry.register(Box::new(proof_duration.clone()))?;
+        registry.register(Box::new(proofs.clone()))?;
+        registry.register(Box::new(gas_used.clone()))?;
+        registry.register(Box::new(updates.clone()))?;
+        registry.register(Box::new(failures.clone()))?;
+
+        Ok(Self {
+            registry,
+            client_height,
+            chain_height,
+            lag,
+            expiry_seconds,
+            proof_duration,
+            proofs,
+            gas_used,
+            updates,
+            failures,
+            ready: Mutex::new(clients.into_iter().map(|c| (c, false)).collect()),
+        })
+    }
+
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    fn observe(&self, event: OperatorEvent) {
+        match event {
+            OperatorEvent::Heights {
+                client,
+                client_height,
+                chain_height,
+            } => {
+                self.client_height
+                    .with_label_values(&[&client])
+                    .set(client_height as i64);
+                self.chain_height
+                    .with_label_values(&[&client])
+                    .set(chain_height as i64);
+                self.lag
+                    .with_label_values(&[&client])
+                    .set(chain_height.saturating_sub(client_height) as i64);
+            }
+            OperatorEvent::Expiry {
+                client,
+                seconds_left,
+            } => self
+                .expiry_seconds
+                .with_label_values(&[&client])
+                .set(seconds_left),
+            OperatorEvent::ProofGenerated {
+                program,
+                proof_type,
+                duration,
+            } => {
+                let labels = [program, proof_type_label(proof_type)];
+                self.proof_duration
+                    .with_label_values(&labels)
+                    .observe(duration.as_secs_f64());
+                self.proofs.with_label_values(&labels).inc();
+            }
+            OperatorEvent::TxIncluded { client, gas_used } => self
+                .gas_used
+                .with_label_values(&[&client])
+                .observe(gas_used as f64),
+            OperatorEvent::Updated { client, result } => {
+                self.updates
+                    .with_label_values(&[&client, update_result_label(result)])
+                    .inc();
+            }
+            OperatorEvent::Failure { client, class } => {
+                self.failures.with_label_values(&[&client, class]).inc();
+            }
+            OperatorEvent::ClientReady { client } => {
+                self.ready
+                    .lock()
+                    .expect("poisoned lock")
+                    .insert(client, true);
+            }
+            OperatorEvent::ClientStopped { client } => {
+                self.ready
+                    .lock()
+                    .expect("poisoned lock")
+                    .insert(client, false);
+            }
+        }
+    }
+}
+
+const fn proof_type_label(proof_type: SupportedProofType) -> &'static str {
+    match proof_type {
+        SupportedProofType::Groth16 => "groth16",
+        SupportedProofType::Plonk => "plonk",
+    }
+}
+
+const fn update_result_label(result: UpdateResult) -> &'static str {
+    match result {
+        UpdateResult::Update => "update",
+        UpdateResult::Misbehaviour => "misbehaviour",
+        UpdateResult::NoOp => "noop",
+    }
+}
@@ -0,0 +1,516 @@
+//! Contains the misbehaviour monitor of the operator.
+//!
+//! The monitor compares the consensus states stored in the contract with the headers served by
+//! one or more Tendermint RPC endpoints. If two endpoints serve conflicting headers for a height
+//! of the client, a misbehaviour is built from them, proven, and submitted to the contract to
+//! freeze the client.
+//!
+//! If all the endpoints serve the same header, but it does not match the consensus state of the
+//! contract, the client may have been updated to a fork that none of the endpoints follow. No
+//! misbehaviour can be built without the header of the fork, so an alert is sent to the webhook
+//! of the watchdog instead.
+//!
+//! The monitor learns about new consensus states from the `ConsensusStateAdded` events of the
+//! contract, which it scans from the EVM block of the last update persisted in the state store,
+//! or from the latest block if there is none. The consensus states added before that block are
+//! only known from the last update persisted in the state store.
+//!
+//! The progress of the monitor is kept in a [`MonitorState`] across its retries, so that a
+//! restart does not scan the same blocks again, and a proven misbehaviour is submitted again
+//! instead of being proven again. A misbehaviour that cannot be proven or submitted for a reason
+//! other than a transient error is not retried, since it would fail the same way: an alert is
+//! sent to the webhook of the watchdog instead, and the monitor moves on.
+//!
+//! The misbehaviour is submitted through the [`TxSender`] of the client, which it shares with
+//! the update loop, so that their transactions do not get the same nonce.
+
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use alloy::{
+    network::{Network, ReceiptResponse},
+    primitives::{keccak256, Bytes, B256},
+    providers::Provider,
+    transports::Transport,
+};
+use alloy_sol_types::SolValue;
+use anyhow::anyhow;
+use ibc_client_tendermint_types::{Misbehaviour, TENDERMINT_CLIENT_TYPE};
+use ibc_core_host_types::identifiers::ClientId;
+use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_prover::{programs::MisbehaviourProgram, prover::SupportedProofType};
+use sp1_ics07_tendermint_solidity::{
+    sp1_ics07_tendermint::{self, sp1_ics07_tendermintInstance},
+    IICS02ClientMsgs::Height,
+    IICS07TendermintMsgs::{ClientState, ConsensusState as SolConsensusState},
+    IMisbehaviourMsgs::MsgSubmitMisbehaviour,
+    ISP1Msgs::SP1Proof,
+};
+use sp1_ics07_tendermint_utils::{light_block::LightBlockExt, rpc::TendermintRpcExt};
+use sp1_sdk::HashableKey;
+use tendermint_light_client_verifier::types::LightBlock;
+use tendermint_rpc::HttpClient;
+use tokio::sync::Mutex;
+
+use super::{
+    config::ClientConfig,
+    errors::{ErrorClass, OperatorError},
+    proof_time,
+    provers::SharedProvers,
+    retry::Backoff,
+    state::{has_consensus_state, StateStore},
+    tx::TxSender,
+    watchdog::Watchdog,
+};
+
+/// The configuration of the misbehaviour monitor of a client.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MisbehaviourMonitorConfig {
+    /// URLs of additional Tendermint RPC nodes whose headers are compared with the headers of
+    /// the primary node of the client.
+    pub tendermint_rpc_urls: Vec<String>,
+    /// The time between two checks of the contract in seconds.
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval: u64,
+}
+
+/// The outcome of the comparison of a consensus state of the client with the headers served by
+/// the Tendermint RPC endpoints at its height.
+enum Check {
+    /// The endpoints serve the same header, which matches the consensus state.
+    Consistent,
+    /// Two endpoints serve conflicting headers.
+    /// The light block matching the consensus state of the contract, if any, is first.
+    Conflict(Box<(LightBlock, LightBlock)>),
+    /// The endpoints serve the same header, which does not match the consensus state.
+    UnknownConsensusState,
+    /// No endpoint served the header.
+    Unavailable,
+}
+
+/// The progress of the misbehaviour monitor of a client, shared between the retries of the
+/// monitor.
+#[derive(Debug, Default)]
+pub struct MonitorState {
+    /// The next EVM block to scan for events, or `None` before the first run of the monitor.
+    next_block: Option<u64>,
+    /// The heights of the consensus states observed on the contract and checked.
+    known_heights: BTreeSet<Height>,
+    /// The heights of the consensus states observed on the contract and not checked yet.
+    unchecked_heights: BTreeSet<Height>,
+    /// The proven misbehaviour at a height, kept until it is submitted or fails for good.
+    proven: Option<(Height, Bytes)>,
+}
+
+/// Monitors the client for misbehaviour until the client is frozen.
+#[allow(clippy::missing_errors_doc, clippy::too_many_lines)]
+pub async fn run<T, P>(
+    config: ClientConfig,
+    provider: P,
+    tx_sender: TxSender,
+    monitor_config: MisbehaviourMonitorConfig,
+    provers: Arc<SharedProvers<MisbehaviourProgram>>,
+    backoff: Backoff,
+    state: Arc<Mutex<MonitorState>>,
+) -> anyhow::Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let name = config.name.as_str();
+
+    let contract = sp1_ics07_tendermint::new(config.contract_address, provider);
+    let watchdog = Watchdog::new(config.name.clone(), config.watchdog.clone())?;
+
+    let rpc_clients = std::iter::once(&config.tendermint_rpc_url)
+        .chain(&monitor_config.tendermint_rpc_urls)
+        .map(|url| Ok((url.clone(), HttpClient::new(url.as_str())?)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let poll_interval = Duration::from_secs(monitor_config.poll_interval);
+
+    let mut state = state.lock().await;
+    if state.next_block.is_none() {
+        let (start_block, heights) = persisted_heights(&config, &contract).await?;
+        let latest_height = contract.getClientState().call().await?._0.latestHeight;
+        state.unchecked_heights.extend(heights);
+        state.unchecked_heights.insert(latest_height);
+        state.next_block = Some(start_block);
+    }
+
+    loop {
+        let client_state = contract.getClientState().call().await?._0;
+        if client_state.isFrozen {
+            info!("[{name}] The client is frozen, stopping the misbehaviour monitor.");
+            return Ok(());
+        }
+        scan_events(name, &contract, &mut state).await?;
+
+        // The heights are checked in increasing order, so that the heights below a height are
+        // known when it is checked. A height is only marked as checked once it is handled, so
+        // that it is checked again if the monitor is restarted by a transient error.
+        while let Some(height) = state.unchecked_heights.first().cloned() {
+            if state.known_heights.contains(&height) {
+                state.unchecked_heights.remove(&height);
+                continue;
+            }
+
+            debug!("[{name}] Checking the consensus state at height {height} for misbehaviour");
+            match find_conflict(name, &contract, &rpc_clients, &height).await? {
+                Check::Consistent => {}
+                Check::Unavailable => warn!(
+                    "[{name}] Cannot check the consensus state at height {height}: no Tendermint RPC endpoint served the block"
+                ),
+                Check::UnknownConsensusState => {
+                    error!(
+                        "[{name}] The consensus state at height {height} does not match any Tendermint RPC endpoint, the client may have been updated to a fork"
+                    );
+                    watchdog.alert_unknown_consensus_state(&height).await;
+                }
+                Check::Conflict(light_blocks) => {
+                    let trusted_height = state
+                        .known_heights
+                        .range(..height.clone())
+                        .next_back()
+                        .cloned();
+                    let res = match trusted_height {
+                        Some(trusted_height) => {
+                            prove_and_submit(
+                                &contract,
+                                &tx_sender,
+                                name,
+                                &rpc_clients[0].1,
+                                &provers,
+                                &mut state,
+                                client_state.clone(),
+                                (&height, trusted_height),
+                                *light_blocks,
+                            )
+                            .await
+                        }
+                        None => Err(OperatorError::fatal(
+                            "no trusted consensus state is known below it",
+                        )
+                        .into()),
+                    };
+                    match res {
+                        Ok(()) => {
+                            error!(
+                                "[{name}] Submitted misbehaviour at height {height}, the client at address {} is frozen.",
+                                config.contract_address
+                            );
+                            return Ok(());
+                        }
+                        // The proven misbehaviour is kept, and submitted again by the retry.
+                        Err(err) if ErrorClass::of(&err) == ErrorClass::Transient => {
+                            return Err(err);
+                        }
+                        Err(err) => {
+                            error!(
+                                "[{name}] Cannot prove or submit the misbehaviour at height {height}: {err:#}"
+                            );
+                            watchdog
+                                .alert_misbehaviour_failed(&height, &format!("{err:#}"))
+                                .await;
+                            state.proven = None;
+                        }
+                    }
+                }
+            }
+
+            state.unchecked_heights.remove(&height);
+            state.known_heights.insert(height);
+        }
+
+        backoff.reset();
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Scans the blocks since the last scanned block for the `ConsensusStateAdded` events of the
+/// contract, and adds their heights to the unchecked heights.
+/// The blocks are scanned in ranges of at most [`MAX_BLOCK_RANGE`] blocks, which RPC nodes limit.
+async fn scan_events<T, P, N>(
+    name: &str,
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    state: &mut MonitorState,
+) -> anyhow::Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let latest_block = contract.provider().get_block_number().await?;
+    while let Some(from_block) = state.next_block.filter(|&block| block <= latest_block) {
+        let to_block = latest_block.min(from_block + MAX_BLOCK_RANGE - 1);
+        let events = contract
+            .ConsensusStateAdded_filter()
+            .from_block(from_block)
+            .to_block(to_block)
+            .query()
+            .await?;
+        debug!(
+            "[{name}] Found {} consensus states in blocks {from_block} to {to_block}",
+            events.len()
+        );
+        state
+            .unchecked_heights
+            .extend(events.into_iter().map(|(event, _)| event.height));
+        state.next_block = Some(to_block + 1);
+    }
+    Ok(())
+}
+
+/// Returns the EVM block to scan events from, and the heights of the last update persisted in
+/// the state store of the client which the contract still has.
+/// The contract does not list its consensus states, so these are the only consensus states added
+/// before the scanned blocks that can be checked, or used as trusted consensus states.
+/// The events are scanned from the block of the last update if it is known, and from the latest
+/// block otherwise.
+async fn persisted_heights<T, P, N>(
+    config: &ClientConfig,
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+) -> anyhow::Result<(u64, BTreeSet<Height>)>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let provider = contract.provider();
+    let mut heights = BTreeSet::new();
+    let store = StateStore::load(config.state_path())?;
+    let Some(last_update) = &store.state().last_update else {
+        return Ok((provider.get_block_number().await?, heights));
+    };
+
+    for height in [
+        &last_update.key.trusted_height,
+        &last_update.key.target_height,
+    ] {
+        if has_consensus_state(contract, height.clone()).await? {
+            heights.insert(height.clone());
+        }
+    }
+
+    let update_block = match last_update.tx_hash {
+        Some(tx_hash) => provider
+            .get_transaction_receipt(tx_hash)
+            .await?
+            .and_then(|receipt| receipt.block_number()),
+        None => None,
+    };
+    let start_block = match update_block {
+        Some(block) => block,
+        None => provider.get_block_number().await?,
+    };
+    Ok((start_block, heights))
+}
+
+/// Proves a misbehaviour made of two conflicting light blocks at `height`, verified from the
+/// consensus state at `trusted_height`, and submits it to the contract.
+/// The proof is kept in the monitor state until the submission succeeds, so that it is not
+/// proven again if the submission is retried.
+#[allow(clippy::too_many_arguments)]
+async fn prove_and_submit<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    tx_sender: &TxSender,
+    name: &str,
+    tm_rpc_client: &HttpClient,
+    provers: &SharedProvers<MisbehaviourProgram>,
+    state: &mut MonitorState,
+    client_state: ClientState,
+    (height, trusted_height): (&Height, Height),
+    light_blocks: (LightBlock, LightBlock),
+) -> anyhow::Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let submit_msg = match &state.proven {
+        Some((proven_height, submit_msg)) if proven_height == height => {
+            info!("[{name}] Submitting the misbehaviour at height {height} proven before");
+            submit_msg.clone()
+        }
+        _ => {
+            info!(
+                "[{name}] Proving the misbehaviour at height {height} from trusted height {trusted_height}"
+            );
+            let submit_msg = prove_misbehaviour(
+                contract,
+                tm_rpc_client,
+                provers,
+                client_state,
+                trusted_height,
+                light_blocks,
+            )
+            .await?;
+            state.proven = Some((height.clone(), submit_msg.clone()));
+            submit_msg
+        }
+    };
+
+    let tx_hash = tx_sender
+        .submit_misbehaviour(contract, name, submit_msg)
+        .await?;
+    info!("[{name}] The misbehaviour at height {height} was included in transaction {tx_hash}");
+    state.proven = None;
+    Ok(())
+}
+
+/// Proves a misbehaviour made of two conflicting light blocks, verified from the consensus state
+/// at `trusted_height`, and returns the encoded message to submit it.
+async fn prove_misbehaviour<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    tm_rpc_client: &HttpClient,
+    provers: &SharedProvers<MisbehaviourProgram>,
+    client_state: ClientState,
+    trusted_height: Height,
+    (light_block_1, light_block_2): (LightBlock, LightBlock),
+) -> anyhow::Result<Bytes>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let proof_type =
+        SupportedProofType::try_from(client_state.zkAlgorithm).map_err(|e| anyhow!(e))?;
+    let prover = provers.get(proof_type).await?;
+
+    let trusted_light_block = tm_rpc_client
+        .get_light_block(Some(trusted_height.revisionHeight.into()))
+        .await?;
+    if consensus_state_hash(&trusted_light_block)
+        != contract
+            .getConsensusStateHash(trusted_height.clone())
+            .call()
+            .await?
+            ._0
+    {
+        return Err(OperatorError::fatal(format!(
+            "the consensus state at trusted height {trusted_height} does not match the contract"
+        ))
+        .into());
+    }
+    let trusted_consensus_state = SolConsensusState::from(trusted_light_block.to_consensus_state());
+
+    let header_time = light_block_1
+        .signed_header
+        .header
+        .time
+        .max(light_block_2.signed_header.header.time);
+    let misbehaviour = Misbehaviour::new(
+        ClientId::new(TENDERMINT_CLIENT_TYPE, 0)?,
+        light_block_1.into_header(&trusted_light_block),
+        light_block_2.into_header(&trusted_light_block),
+    );
+
+    let now = proof_time(&client_state, header_time).await?;
+    // The program rejects a trusted consensus state outside of the trusting period, so proving
+    // it would only pay for a failed proof.
+    if now >= trusted_consensus_state.timestamp + u64::from(client_state.trustingPeriod) {
+        return Err(OperatorError::fatal(format!(
+            "the consensus state at trusted height {trusted_height} is outside of the trusting period"
+        ))
+        .into());
+    }
+
+    let proof_data = tokio::task::spawn_blocking({
+        let prover = prover.clone();
+        move || {
+            prover.generate_proof(
+                &client_state,
+                &misbehaviour,
+                &trusted_consensus_state,
+                &trusted_consensus_state,
+                now,
+            )
+        }
+    })
+    .await?;
+
+    let submit_msg = MsgSubmitMisbehaviour {
+        sp1Proof: SP1Proof::new(
+            &prover.vkey.bytes32(),
+            proof_data.bytes(),
+            proof_data.public_values.to_vec(),
+        ),
+    };
+    Ok(submit_msg.abi_encode().into())
+}
+
+/// Fetches the light block at the given height from every endpoint, and compares them with each
+/// other and with the consensus state stored in the contract.
+async fn find_conflict<T, P, N>(
+    name: &str,
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    rpc_clients: &[(String, HttpClient)],
+    height: &Height,
+) -> anyhow::Result<Check>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let contract_hash = contract
+        .getConsensusStateHash(height.clone())
+        .call()
+        .await?
+        ._0;
+
+    let mut light_blocks = Vec::with_capacity(rpc_clients.len());
+    for (url, client) in rpc_clients {
+        match client
+            .get_light_block(Some(height.revisionHeight.into()))
+            .await
+        {
+            // After a revision change, the block at the same revision height is of another
+            // revision, which the consensus state cannot be compared with.
+            Ok(light_block) if light_block.to_sol_height().ok().as_ref() == Some(height) => {
+                light_blocks.push(light_block);
+            }
+            Ok(light_block) => warn!(
+                "[{name}] Ignoring block {height} from {url}: it is of chain {}",
+                light_block.signed_header.header.chain_id
+            ),
+            Err(err) => warn!("[{name}] Failed to fetch block {height} from {url}: {err}"),
+        }
+    }
+
+    // Put the light block matching the contract first, so that it is compared with the others.
+    let matched = light_blocks
+        .iter()
+        .position(|lb| consensus_state_hash(lb) == contract_hash);
+    if let Some(i) = matched {
+        light_blocks.swap(0, i);
+    }
+
+    let mut light_blocks = light_blocks.into_iter();
+    let Some(first) = light_blocks.next() else {
+        return Ok(Check::Unavailable);
+    };
+    let first_hash = first.signed_header.header.hash();
+    if let Some(conflicting) = light_blocks.find(|lb| lb.signed_header.header.hash() != first_hash)
+    {
+        error!("[{name}] Found conflicting headers at height {height}");
+        return Ok(Check::Conflict(Box::new((first, conflicting))));
+    }
+
+    Ok(if matched.is_some() {
+        Check::Consistent
+    } else {
+        Check::UnknownConsensusState
+    })
+}
+
+/// Returns the hash of the consensus state of a light block, as stored in the contract.
+fn consensus_state_hash(light_block: &LightBlock) -> B256 {
+    keccak256(SolConsensusState::from(light_block.to_consensus_state()).abi_encode())
+}
+
+/// The maximum number of EVM blocks scanned for events in a single query.
+const MAX_BLOCK_RANGE: u64 = 2_000;
+
+const fn default_poll_interval() -> u64 {
+    30
+}
@@ -0,0 +1,690 @@
+//! Contains the runner for the `operator run` command.
+
+pub mod api;
+pub mod bisection;
+pub mod config;
+pub mod errors;
+pub mod events;
+pub mod metrics;
+pub mod misbehaviour;
+pub mod pipeline;
+pub mod policy;
+pub mod provers;
+pub mod retry;
+pub mod state;
+pub mod tx;
+pub mod watchdog;
+
+use std::{sync::Arc, time::Duration};
+
+use crate::{cli::command::operator::Args, runners::simulation};
+use alloy::{
+    eips::BlockNumberOrTag,
+    network::Network,
+    primitives::B256,
+    providers::{Provider, ProviderBuilder, WalletProvider},
+    rpc::types::BlockTransactionsKind,
+    transports::Transport,
+};
+use anyhow::anyhow;
+use config::{ClientConfig, OperatorConfig};
+use errors::OperatorError;
+use events::{EventSender, OperatorEvent};
+use log::{debug, error, info};
+use misbehaviour::MonitorState;
+use pipeline::Pipeline;
+use policy::{PolicyInput, UpdatePolicy};
+use provers::SharedProvers;
+use reqwest::Url;
+use retry::{with_retries, Backoff};
+use simulation::Simulation;
+use sp1_ics07_tendermint_prover::{
+    programs::{MembershipProgram, MisbehaviourProgram, UpdateClientProgram},
+    prover::{SP1ICS07TendermintProver, SupportedProofType},
+};
+use sp1_ics07_tendermint_solidity::{
+    revision_chain_id, sp1_ics07_tendermint, IICS07TendermintMsgs::ClientState, ISP1Msgs::SP1Proof,
+    IUpdateClientMsgs::MsgUpdateClient, UpdateResult,
+};
+use sp1_ics07_tendermint_utils::{light_block::LightBlockExt, rpc::TendermintRpcExt};
+use sp1_sdk::{utils::setup_logger, HashableKey};
+use state::{StateStore, StoredProof};
+use tendermint::Time;
+use tendermint_light_client_verifier::{options::Options, types::LightBlock};
+use tendermint_rpc::{Client, HttpClient};
+use tx::TxSender;
+use watchdog::{ExpiryLevel, Watchdog};
+
+/// Runs the update client program in a loop for every configured client, and monitors the
+/// clients with a configured misbehaviour monitor.
+/// If the `only_once` flag is set, the program will only run once per client and the clients are
+/// not monitored.
+/// If the `dry_run` flag is set, the updates are simulated instead of submitted, once per client.
+/// If an API address is set, proofs are also served on demand to relayers, see [`api`].
+/// If the `pipelined` flag is set, every client is run in pipelined mode, see [`pipeline`].
+#[allow(clippy::missing_errors_doc, clippy::missing_panics_doc)]
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    setup_logger();
+    if dotenv::dotenv().is_err() {
+        log::warn!("No .env file found");
+    }
+
+    let mut config = match &args.config {
+        Some(path) => OperatorConfig::from_file(path)?,
+        None => OperatorConfig::from_env(args.state_path.clone(), args.signer.clone().into())?,
+    };
+    if args.pipelined {
+        for client in &mut config.clients {
+            client.pipelined = true;
+        }
+    }
+
+    let events = match args.metrics_addr {
+        Some(addr) => {
+            let (events, receiver) = EventSender::channel();
+            let clients = config.clients.iter().map(|c| c.name.clone()).collect();
+            tokio::spawn(async move {
+                if let Err(err) = metrics::serve(addr, clients, receiver).await {
+                    error!("The metrics server has stopped: {err:?}");
+                }
+            });
+            events
+        }
+        None => EventSender::default(),
+    };
+
+    let provers = Arc::new(
+        SharedProvers::<UpdateClientProgram>::new().with_observer(events.proof_observer()),
+    );
+    let misbehaviour_provers = Arc::new(
+        SharedProvers::<MisbehaviourProgram>::new().with_observer(events.proof_observer()),
+    );
+
+    if let Some(addr) = args.api_addr {
+        let clients = config.clients.clone();
+        let provers = provers.clone();
+        let membership_provers = Arc::new(
+            SharedProvers::<MembershipProgram>::new().with_observer(events.proof_observer()),
+        );
+        tokio::spawn(async move {
+            if let Err(err) = api::serve(addr, clients, provers, membership_provers).await {
+                error!("The proof service has stopped: {err:?}");
+            }
+        });
+    }
+    let only_once = args.only_once || args.dry_run;
+    let dry_run = args.dry_run;
+
+    let results = futures::future::join_all(config.clients.into_iter().map(|client| {
+        let provers = provers.clone();
+        let misbehaviour_provers = misbehaviour_provers.clone();
+        let events = events.clone();
+        async move {
+            let name = client.name.clone();
+            let res = run_client_tasks(
+                client,
+                provers,
+                misbehaviour_provers,
+                events.clone(),
+                only_once,
+                dry_run,
+            )
+            .await;
+            if let Err(err) = &res {
+                error!("[{name}] The operator has stopped: {err:?}");
+            }
+            events.emit(OperatorEvent::ClientStopped { client: name });
+            res
+        }
+    }))
+    .await;
+
+    results.into_iter().collect()
+}
+
+/// Runs the update loop of a client, and its misbehaviour monitor if one is configured and the
+/// `only_once` flag is not set.
+/// Both tasks send their transactions through the same provider and [`TxSender`], so that their
+/// transactions do not get the same nonce.
+async fn run_client_tasks(
+    client: ClientConfig,
+    provers: Arc<SharedProvers<UpdateClientProgram>>,
+    misbehaviour_provers: Arc<SharedProvers<MisbehaviourProgram>>,
+    events: EventSender,
+    only_once: bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let name = client.name.as_str();
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(client.signer.wallet()?)
+        .on_http(Url::parse(&client.rpc_url)?);
+    let tx_sender = TxSender::new(provider.default_signer_address(), client.fees.clone());
+
+    let backoff = Backoff::new(&client.retry);
+    let update_client = with_retries(name, &events, &backoff, || {
+        run_client(
+            client.clone(),
+            provider.clone(),
+            tx_sender.clone(),
+            provers.clone(),
+            events.clone(),
+            backoff.clone(),
+            only_once,
+            dry_run,
+        )
+    });
+    match client.misbehaviour_monitor.clone() {
+        Some(monitor_config) if !only_once => {
+            let monitor_backoff = Backoff::new(&client.retry);
+            let monitor_state = Arc::new(tokio::sync::Mutex::new(MonitorState::default()));
+            let monitor = with_retries(name, &events, &monitor_backoff, || {
+                misbehaviour::run(
+                    client.clone(),
+                    provider.clone(),
+                    tx_sender.clone(),
+                    monitor_config.clone(),
+                    misbehaviour_provers.clone(),
+                    monitor_backoff.clone(),
+                    monitor_state.clone(),
+                )
+            });
+            tokio::try_join!(update_client, monitor).map(|_| ())
+        }
+        _ => update_client.await,
+    }
+}
+
+/// Runs the update client program in a loop for a single client, until the client is frozen.
+/// In a dry run, the update is simulated with `eth_call` instead of submitted.
+/// In pipelined mode, the next update is proven while the current one confirms, and submitted
+/// right after it, so that the updates follow each other back to back once the update policy
+/// triggered.
+#[allow(
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::too_many_arguments,
+    clippy::too_many_lines
+)]
+pub async fn run_client<T, P>(
+    config: ClientConfig,
+    provider: P,
+    tx_sender: TxSender,
+    provers: Arc<SharedProvers<UpdateClientProgram>>,
+    events: EventSender,
+    backoff: Backoff,
+    only_once: bool,
+    dry_run: bool,
+) -> anyhow::Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let name = config.name.as_str();
+
+    let contract = sp1_ics07_tendermint::new(config.contract_address, provider);
+    let contract_client_state = contract.getClientState().call().await?._0;
+    let tendermint_rpc_client = HttpClient::new(config.tendermint_rpc_url.as_str())?;
+    let prover = provers
+        .get(
+            SupportedProofType::try_from(contract_client_state.zkAlgorithm)
+                .map_err(|e| anyhow!(e))?,
+        )
+        .await?;
+
+    let vkey = prover.vkey.bytes32();
+    let contract_vkey = contract.UPDATE_CLIENT_PROGRAM_VKEY().call().await?._0;
+    if contract_vkey != vkey.parse::<B256>()? {
+        return Err(OperatorError::fatal(format!(
+            "the update client program vkey of the contract ({contract_vkey}) does not match the prover ({vkey})"
+        ))
+        .into());
+    }
+
+    let mut store = StateStore::load(config.state_path())?;
+    store.reconcile(&contract, &vkey).await?;
+
+    let policy = UpdatePolicy::try_from(&config.update_policy)?;
+    let mut watchdog = Watchdog::new(config.name.clone(), config.watchdog.clone())?;
+    let started_at = unix_now()?;
+    let mut last_update = None;
+    let mut ready = false;
+    let mut freeze_reason = None;
+
+    loop {
+        let contract_client_state = contract.getClientState().call().await?._0;
+        if contract_client_state.isFrozen {
+            // The contract does not record why it was frozen, so the reason is only known if
+            // this operator froze it.
+            let reason = freeze_reason.take().unwrap_or_else(|| {
+                format!(
+                    "it was frozen by another transaction, e.g. a misbehaviour submission, at height {}",
+                    contract_client_state.latestHeight.revisionHeight
+                )
+            });
+            error!("[{name}] The client is frozen because {reason}, stopping the updates.");
+            watchdog.alert_frozen(&reason).await;
+            return Ok(());
+        }
+
+        // Read the existing trusted header hash from the contract.
+        let trusted_block_height = contract_client_state.latestHeight.revisionHeight;
+        if trusted_block_height == 0 {
+            return Err(OperatorError::fatal(
+                "no trusted height found on the contract, something is wrong with the contract",
+            )
+            .into());
+        }
+
+        let chain_height = tendermint_rpc_client
+            .status()
+            .await?
+            .sync_info
+            .latest_block_height
+            .value();
+        events.emit(OperatorEvent::Heights {
+            client: config.name.clone(),
+            client_height: trusted_block_height.into(),
+            chain_height,
+        });
+
+        let consensus_timestamp: u64 = tendermint_rpc_client
+            .commit(trusted_block_height)
+            .await?
+            .signed_header
+            .header
+            .time
+            .unix_timestamp()
+            .try_into()?;
+
+        // The contract checks the trusting period against the EVM block time, so the watchdog
+        // does too.
+        let evm_time = contract
+            .provider()
+            .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+            .await?
+            .ok_or_else(|| OperatorError::transient("latest EVM block not found"))?
+            .header
+            .timestamp;
+        let expiry_level = watchdog
+            .check(
+                consensus_timestamp,
+                contract_client_state.trustingPeriod,
+                evm_time,
+            )
+            .await;
+        events.emit(OperatorEvent::Expiry {
+            client: config.name.clone(),
+            seconds_left: i64::try_from(consensus_timestamp)?
+                + i64::from(contract_client_state.trustingPeriod)
+                - i64::try_from(evm_time)?,
+        });
+        if expiry_level == ExpiryLevel::Expired {
+            return Err(OperatorError::fatal(
+                "the client has expired and can no longer be updated",
+            )
+            .into());
+        }
+        if !ready {
+            events.emit(OperatorEvent::ClientReady {
+                client: config.name.clone(),
+            });
+            ready = true;
+        }
+
+        // A stored proof from a previous run is submitted regardless of the update policy, since
+        // it was already paid for. A client close to expiry is updated regardless of the policy.
+        let stored_proof = store
+            .find_proof(&contract_client_state.latestHeight, &vkey)
+            .cloned();
+        if expiry_level >= ExpiryLevel::Warning {
+            info!("[{name}] Updating the client because it is close to expiry ({expiry_level}).");
+        } else if stored_proof.is_none() && !only_once {
+            let input = PolicyInput {
+                client_height: trusted_block_height.into(),
+                chain_height,
+                consensus_timestamp,
+                trusting_period: contract_client_state.trustingPeriod,
+                now: unix_now()?,
+            };
+
+            let Some(reason) =
+                policy.evaluate(&input, last_update, last_update.unwrap_or(started_at))
+            else {
+                debug!(
+                    "[{name}] No update needed, sleeping for {:?}",
+                    policy.poll_interval
+                );
+                backoff.reset();
+                tokio::time::sleep(policy.poll_interval).await;
+                continue;
+            };
+
+            if let Some(gas_price) =
+                tx::gas_price_above_max(contract.provider(), &config.fees).await?
+            {
+                info!(
+                    "[{name}] Delaying the update because the gas price ({gas_price} wei) is above the maximum, although {reason}."
+                );
+                backoff.reset();
+                tokio::time::sleep(policy.poll_interval).await;
+                continue;
+            }
+            info!("[{name}] Updating the client because {reason}.");
+        }
+
+        // The next update is only pipelined if there is no stored proof for it yet.
+        let pipeline = (config.pipelined && !only_once).then_some(Pipeline {
+            name,
+            tendermint_rpc_client: &tendermint_rpc_client,
+            prover: &prover,
+            client_state: &contract_client_state,
+            vkey: &vkey,
+        });
+        let mut next_proofs = Vec::new();
+
+        if let Some(proof) = stored_proof {
+            // Reuse a proof from a previous run if there is one for the current trusted height.
+            info!(
+                "[{name}] Found a stored proof from height {} to height {}, skipping proof generation.",
+                proof.key.trusted_height, proof.key.target_height
+            );
+            if dry_run {
+                return simulate_updates(&contract, name, &[proof.msg()?]).await;
+            }
+            let target_height = proof.key.target_height.clone();
+            let pipeline = pipeline
+                .as_ref()
+                .filter(|_| store.find_proof(&target_height, &vkey).is_none());
+            let (result, proofs) = Pipeline::submit(
+                pipeline,
+                tx_sender.submit_update(&contract, &mut store, &events, name, proof),
+                &target_height,
+            )
+            .await?;
+            next_proofs = proofs;
+            freeze_reason = report_update(
+                &config,
+                &events,
+                trusted_block_height.into(),
+                target_height.revisionHeight.into(),
+                result,
+            );
+        } else {
+            let trusted_light_block = tendermint_rpc_client
+                .get_light_block(Some(trusted_block_height.into()))
+                .await?;
+            let target_light_block = tendermint_rpc_client.get_light_block(None).await?;
+            check_revision(name, &contract_client_state, &target_light_block)?;
+
+            // Find the intermediate blocks needed to reach the target block, so that every
+            // proof is known to succeed before paying for it.
+            let light_blocks = bisection::bisect(
+                &tendermint_rpc_client,
+                &trusted_light_block,
+                target_light_block,
+                &verifier_options(&contract_client_state),
+                Time::from_unix_timestamp(unix_now()?.try_into()?, 0)?,
+            )
+            .await?;
+            if light_blocks.len() > 1 {
+                info!(
+                    "[{name}] Updating through {} intermediate blocks: {:?}",
+                    light_blocks.len() - 1,
+                    light_blocks
+                        .iter()
+                        .map(|lb| lb.height().value())
+                        .collect::<Vec<_>>()
+                );
+            }
+
+            let hops = light_blocks.len();
+            let mut trusted_light_block = trusted_light_block;
+            let mut update_msgs = Vec::new();
+            for (i, target_light_block) in light_blocks.into_iter().enumerate() {
+                let trusted_height = trusted_light_block.height().value();
+                let target_height = target_light_block.height().value();
+
+                let update_msg = prove_update(
+                    prover.clone(),
+                    &contract_client_state,
+                    &trusted_light_block,
+                    &target_light_block,
+                )
+                .await?;
+
+                if dry_run {
+                    update_msgs.push(update_msg);
+                    trusted_light_block = target_light_block;
+                    continue;
+                }
+
+                // Persist the proof before submitting it, so that it is not lost if the
+                // submission fails.
+                let proof = StoredProof::new(vkey.clone(), &update_msg)?;
+                store.insert_proof(proof.clone())?;
+
+                // Only the last hop is pipelined, since the next hops are already proven.
+                let next_height = proof.key.target_height.clone();
+                let pipeline = pipeline
+                    .as_ref()
+                    .filter(|_| i + 1 == hops && store.find_proof(&next_height, &vkey).is_none());
+                let (result, proofs) = Pipeline::submit(
+                    pipeline,
+                    tx_sender.submit_update(&contract, &mut store, &events, name, proof),
+                    &next_height,
+                )
+                .await?;
+                next_proofs = proofs;
+                freeze_reason =
+                    report_update(&config, &events, trusted_height, target_height, result);
+                if freeze_reason.is_some() {
+                    break;
+                }
+                trusted_light_block = target_light_block;
+            }
+
+            if dry_run {
+                return simulate_updates(&contract, name, &update_msgs).await;
+            }
+        }
+        if freeze_reason.is_some() {
+            // The next iteration stops on the frozen client.
+            continue;
+        }
+        last_update = Some(unix_now()?);
+        backoff.reset();
+
+        if only_once {
+            info!("[{name}] Exiting because '--only-once' flag is set.");
+            return Ok(());
+        }
+
+        // The pipelined proofs are submitted by the next iteration as stored proofs.
+        if !next_proofs.is_empty() {
+            for proof in next_proofs {
+                store.insert_proof(proof)?;
+            }
+            debug!("[{name}] Submitting the pipelined update without sleeping");
+            continue;
+        }
+
+        debug!("[{name}] sleeping for {:?}", policy.poll_interval);
+        tokio::time::sleep(policy.poll_interval).await;
+    }
+}
+
+/// Generates the update client message for the transition from the trusted block to the target
+/// block.
+/// Proving is blocking, so it runs on a separate thread to not block the other clients.
+#[allow(clippy::missing_errors_doc)]
+pub async fn prove_update(
+    prover: Arc<SP1ICS07TendermintProver<UpdateClientProgram>>,
+    client_state: &ClientState,
+    trusted_light_block: &LightBlock,
+    target_light_block: &LightBlock,
+) -> anyhow::Result<MsgUpdateClient> {
+    // Get trusted consensus state from the trusted light block.
+    let trusted_consensus_state = trusted_light_block.to_consensus_state().into();
+
+    // Get the proposed header from the target light block.
+    let proposed_header = target_light_block.clone().into_header(trusted_light_block);
+
+    let now = proof_time(client_state, target_light_block.signed_header.header.time).await?;
+
+    let proof_data = {
+        let prover = prover.clone();
+        let client_state = client_state.clone();
+        tokio::task::spawn_blocking(move || {
+            prover.generate_proof(
+                &client_state,
+                &trusted_consensus_state,
+                &proposed_header,
+                now,
+            )
+        })
+        .await?
+    };
+
+    Ok(MsgUpdateClient {
+        sp1Proof: SP1Proof::new(
+            &prover.vkey.bytes32(),
+            proof_data.bytes(),
+            proof_data.public_values.to_vec(),
+        ),
+    })
+}
+
+/// Checks that the client can be updated to a light block, following the chain to a new revision
+/// if the chain identifier of the light block is a later revision of the chain of the client.
+fn check_revision(
+    name: &str,
+    client_state: &ClientState,
+    light_block: &LightBlock,
+) -> anyhow::Result<()> {
+    let chain_id = light_block.signed_header.header.chain_id.as_str();
+    if chain_id == client_state.chainId {
+        return Ok(());
+    }
+    if revision_chain_id(&client_state.chainId, chain_id) != chain_id {
+        return Err(OperatorError::fatal(format!(
+            "the chain identifier {chain_id} is not a later revision of the chain of the client ({}), the client must be upgraded",
+            client_state.chainId
+        ))
+        .into());
+    }
+
+    info!(
+        "[{name}] The chain was upgraded from {} to {chain_id}, updating the client across the revision change.",
+        client_state.chainId
+    );
+    Ok(())
+}
+
+/// Logs the result of a submitted update and returns the freeze reason if it was a misbehaviour.
+fn report_update(
+    config: &ClientConfig,
+    events: &EventSender,
+    trusted_height: u64,
+    target_height: u64,
+    result: UpdateResult,
+) -> Option<String> {
+    let name = &config.name;
+    events.emit(OperatorEvent::Updated {
+        client: name.clone(),
+        result,
+    });
+    match result {
+        UpdateResult::Update => {
+            info!(
+                "[{name}] Updated the ICS-07 Tendermint light client at address {} from block {trusted_height} to block {target_height}.",
+                config.contract_address
+            );
+            None
+        }
+        UpdateResult::NoOp => {
+            info!(
+                "[{name}] The ICS-07 Tendermint light client at address {} already had block {target_height}.",
+                config.contract_address
+            );
+            None
+        }
+        UpdateResult::Misbehaviour => {
+            error!(
+                "[{name}] The update from block {trusted_height} to block {target_height} was a misbehaviour, the client at address {} is frozen.",
+                config.contract_address
+            );
+            Some(format!(
+                "the update from block {trusted_height} to block {target_height} was a misbehaviour"
+            ))
+        }
+    }
+}
+
+/// Returns the light client verifier options matching the client state of the contract.
+#[must_use]
+pub fn verifier_options(client_state: &ClientState) -> Options {
+    Options {
+        trust_threshold: client_state.trustLevel.clone().into(),
+        trusting_period: Duration::from_secs(client_state.trustingPeriod.into()),
+        clock_drift: Duration::from_secs(client_state.maxClockDrift.into()),
+    }
+}
+
+/// Simulates the given updates in order and logs their results.
+/// A simulation that reverts is a fatal error, since retrying would not change the outcome.
+async fn simulate_updates<T, P, N>(
+    contract: &sp1_ics07_tendermint::sp1_ics07_tendermintInstance<T, P, N>,
+    name: &str,
+    update_msgs: &[MsgUpdateClient],
+) -> anyhow::Result<()>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    match simulation::simulate_update_client(contract, update_msgs).await? {
+        Simulation::Success(results) => {
+            if results.contains(&UpdateResult::Misbehaviour) {
+                error!("[{name}] Dry run: the updates would freeze the client with {results:?}.");
+            } else {
+                info!("[{name}] Dry run: the updates would succeed with {results:?}.");
+            }
+            Ok(())
+        }
+        Simulation::Revert(revert) => Err(OperatorError::fatal(format!(
+            "dry run: the updates would revert with {revert:?}"
+        ))
+        .into()),
+    }
+}
+
+/// Returns the time to prove a header at, in unix seconds.
+///
+/// The programs only accept headers that are less than the max clock drift of the client ahead of
+/// the time of the proof, and the contract rejects proofs whose time is ahead of the block time.
+/// So the time is the current time, after waiting for a header that is too far ahead to be within
+/// the max clock drift.
+#[allow(clippy::missing_errors_doc)]
+pub async fn proof_time(client_state: &ClientState, header_time: Time) -> anyhow::Result<u64> {
+    let header_time = u64::try_from(header_time.unix_timestamp())?;
+    let earliest = (header_time + 1).saturating_sub(client_state.maxClockDrift.into());
+    let now = unix_now()?;
+    if now < earliest {
+        debug!(
+            "The header at {header_time} is ahead of the max clock drift, waiting {} seconds",
+            earliest - now
+        );
+        tokio::time::sleep(Duration::from_secs(earliest - now)).await;
+        return unix_now();
+    }
+    Ok(now)
+}
+
+/// Returns the current unix time in seconds.
+#[allow(clippy::missing_errors_doc)]
+pub fn unix_now() -> anyhow::Result<u64> {
+    Ok(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs())
+}
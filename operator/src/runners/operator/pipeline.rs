@@ -0,0 +1,127 @@
+//! Contains the pipelining of proving and submission.
+//!
+//! In pipelined mode, the next update of a client, from the height of the in-flight update to
+//! the latest block, is proven while the transaction of the in-flight update confirms. The next
+//! proofs are stored once the in-flight update is included, and submitted by the next iteration.
+//! If the in-flight update fails or is a misbehaviour, the next proofs are discarded, since they
+//! are built on a height that the client never reached.
+
+use std::{future::Future, sync::Arc};
+
+use log::{info, warn};
+use sp1_ics07_tendermint_prover::{
+    programs::UpdateClientProgram, prover::SP1ICS07TendermintProver,
+};
+use sp1_ics07_tendermint_solidity::{
+    IICS02ClientMsgs::Height, IICS07TendermintMsgs::ClientState, UpdateResult,
+};
+use sp1_ics07_tendermint_utils::rpc::TendermintRpcExt;
+use tendermint::Time;
+use tendermint_rpc::HttpClient;
+
+use super::{bisection, prove_update, state::StoredProof, unix_now, verifier_options};
+
+/// The inputs needed to prove the next update of a client while an update is in flight.
+pub struct Pipeline<'a> {
+    /// The name of the client.
+    pub name: &'a str,
+    /// The Tendermint RPC client of the counterparty chain.
+    pub tendermint_rpc_client: &'a HttpClient,
+    /// The prover of the update client program.
+    pub prover: &'a Arc<SP1ICS07TendermintProver<UpdateClientProgram>>,
+    /// The client state of the contract.
+    pub client_state: &'a ClientState,
+    /// The verification key of the update client program.
+    pub vkey: &'a str,
+}
+
+impl Pipeline<'_> {
+    /// Runs the submission of an update to the target height, while proving the updates from
+    /// the target height to the latest block if a pipeline is given.
+    /// Returns the result of the submission, and the proofs of the next updates unless it was a
+    /// misbehaviour.
+    ///
+    /// # Errors
+    /// Returns the error of the submission, in which case the next proofs are discarded.
+    /// Proving errors are only logged, so that they never interrupt the submission.
+    pub async fn submit(
+        pipeline: Option<&Self>,
+        submission: impl Future<Output = anyhow::Result<UpdateResult>>,
+        target_height: &Height,
+    ) -> anyhow::Result<(UpdateResult, Vec<StoredProof>)> {
+        let Some(pipeline) = pipeline else {
+            return Ok((submission.await?, Vec::new()));
+        };
+
+        let next = async { anyhow::Ok(pipeline.prove_next(target_height).await) };
+        let (result, proofs) = tokio::try_join!(submission, next).inspect_err(|_| {
+            // A proof that is still being generated keeps running on its blocking thread, but
+            // its result is dropped.
+            warn!(
+                "[{}] Discarding the pipelined update from height {target_height}, since the update to it failed",
+                pipeline.name
+            );
+        })?;
+
+        if result == UpdateResult::Misbehaviour {
+            return Ok((result, Vec::new()));
+        }
+        Ok((result, proofs))
+    }
+
+    /// Proves the updates from the trusted height to the latest block.
+    /// Returns no proofs if the chain has not moved or if proving fails.
+    async fn prove_next(&self, trusted_height: &Height) -> Vec<StoredProof> {
+        match self.try_prove_next(trusted_height).await {
+            Ok(proofs) => proofs,
+            Err(err) => {
+                warn!(
+                    "[{}] Failed to prove the pipelined update from height {trusted_height}: {err:?}",
+                    self.name
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    async fn try_prove_next(&self, trusted_height: &Height) -> anyhow::Result<Vec<StoredProof>> {
+        let trusted_light_block = self
+            .tendermint_rpc_client
+            .get_light_block(Some(trusted_height.revisionHeight.into()))
+            .await?;
+        let target_light_block = self.tendermint_rpc_client.get_light_block(None).await?;
+        if target_light_block.height() <= trusted_light_block.height() {
+            return Ok(Vec::new());
+        }
+
+        info!(
+            "[{}] Proving the pipelined update from height {trusted_height} to block {}",
+            self.name,
+            target_light_block.height()
+        );
+        let light_blocks = bisection::bisect(
+            self.tendermint_rpc_client,
+            &trusted_light_block,
+            target_light_block,
+            &verifier_options(self.client_state),
+            Time::from_unix_timestamp(unix_now()?.try_into()?, 0)?,
+        )
+        .await?;
+
+        let mut proofs = Vec::with_capacity(light_blocks.len());
+        let mut trusted_light_block = trusted_light_block;
+        for target_light_block in light_blocks {
+            let update_msg = prove_update(
+                self.prover.clone(),
+                self.client_state,
+                &trusted_light_block,
+                &target_light_block,
+            )
+            .await?;
+            proofs.push(StoredProof::new(self.vkey.to_string(), &update_msg)?);
+            trusted_light_block = target_light_block;
+        }
+
+        Ok(proofs)
+    }
+}
@@ -0,0 +1,279 @@
+//! Contains the update policy of the operator.
+//!
+//! The policy decides when a client should be updated, based on how far the client lags behind
+//! the chain, how close its latest consensus state is to the end of the trusting period, and an
+//! optional cron schedule. A minimum spacing between updates bounds the proving cost.
+
+use std::{fmt, str::FromStr, time::Duration};
+
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+
+use super::config::UpdatePolicyConfig;
+
+/// The update policy of a client.
+#[derive(Debug, Clone)]
+pub struct UpdatePolicy {
+    /// Update when the chain is ahead of the client by at least this many blocks.
+    max_block_lag: Option<u64>,
+    /// Update when the time since the latest consensus state exceeds this fraction of the
+    /// trusting period.
+    trusting_period_fraction: Option<f64>,
+    /// Update at the times given by this schedule.
+    schedule: Option<Schedule>,
+    /// The minimum time between two updates.
+    min_spacing: Duration,
+    /// The time between two evaluations of the policy.
+    pub poll_interval: Duration,
+}
+
+/// The observed state of a client and its chain, used to evaluate the [`UpdatePolicy`].
+#[derive(Debug, Clone, Copy)]
+pub struct PolicyInput {
+    /// The latest height of the client.
+    pub client_height: u64,
+    /// The latest height of the chain.
+    pub chain_height: u64,
+    /// The timestamp of the latest consensus state of the client in seconds.
+    pub consensus_timestamp: u64,
+    /// The trusting period of the client in seconds, as read from the contract.
+    pub trusting_period: u32,
+    /// The current time in seconds.
+    pub now: u64,
+}
+
+/// The reason why the [`UpdatePolicy`] decided to update a client.
+#[derive(Debug, Clone, Copy)]
+pub enum UpdateReason {
+    /// The client lags behind the chain by the given number of blocks.
+    BlockLag(u64),
+    /// The given number of seconds have passed since the latest consensus state.
+    TrustingPeriod {
+        /// The seconds since the latest consensus state.
+        elapsed: u64,
+        /// The trusting period in seconds.
+        trusting_period: u32,
+    },
+    /// An update is scheduled.
+    Schedule,
+}
+
+impl UpdatePolicy {
+    /// Returns the reason to update the client, or `None` if the client should not be updated.
+    ///
+    /// `last_update` is the time of the last update made by this operator, if any, and `since`
+    /// is the time from which scheduled updates are considered due.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        input: &PolicyInput,
+        last_update: Option<u64>,
+        since: u64,
+    ) -> Option<UpdateReason> {
+        if input.chain_height <= input.client_height {
+            return None;
+        }
+
+        if let Some(last_update) = last_update {
+            if input.now.saturating_sub(last_update) < self.min_spacing.as_secs() {
+                return None;
+            }
+        }
+
+        let lag = input.chain_height - input.client_height;
+        if self.max_block_lag.is_some_and(|max_lag| lag >= max_lag) {
+            return Some(UpdateReason::BlockLag(lag));
+        }
+
+        let elapsed = input.now.saturating_sub(input.consensus_timestamp);
+        if let Some(fraction) = self.trusting_period_fraction {
+            #[allow(clippy::cast_precision_loss)]
+            let elapsed_secs = elapsed as f64;
+            if elapsed_secs >= fraction * f64::from(input.trusting_period) {
+                return Some(UpdateReason::TrustingPeriod {
+                    elapsed,
+                    trusting_period: input.trusting_period,
+                });
+            }
+        }
+
+        if let Some(schedule) = &self.schedule {
+            let since = i64::try_from(since)
+                .ok()
+                .and_then(|s| DateTime::<Utc>::from_timestamp(s, 0));
+            let now = i64::try_from(input.now)
+                .ok()
+                .and_then(|s| DateTime::<Utc>::from_timestamp(s, 0));
+            if let (Some(since), Some(now)) = (since, now) {
+                if schedule
+                    .after(&since)
+                    .next()
+                    .is_some_and(|next| next <= now)
+                {
+                    return Some(UpdateReason::Schedule);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl TryFrom<&UpdatePolicyConfig> for UpdatePolicy {
+    type Error = anyhow::Error;
+
+    fn try_from(config: &UpdatePolicyConfig) -> Result<Self, Self::Error> {
+        if let Some(fraction) = config.trusting_period_fraction {
+            if !(fraction > 0.0 && fraction <= 1.0) {
+                anyhow::bail!("trusting period fraction must be in (0, 1], got {fraction}");
+            }
+        }
+
+        let schedule = config
+            .schedule
+            .as_deref()
+            .map(Schedule::from_str)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("invalid update schedule: {e}"))?;
+
+        Ok(Self {
+            max_block_lag: config.max_block_lag,
+            trusting_period_fraction: config.trusting_period_fraction,
+            schedule,
+            min_spacing: Duration::from_secs(config.min_spacing),
+            poll_interval: Duration::from_secs(config.poll_interval),
+        })
+    }
+}
+
+impl fmt::Display for UpdateReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockLag(lag) => write!(f, "the client lags {lag} blocks behind the chain"),
+            Self::TrustingPeriod {
+                elapsed,
+                trusting_period,
+            } => write!(
+                f,
+                "{elapsed}s have passed since the latest consensus state (trusting period: {trusting_period}s)"
+            ),
+            Self::Schedule => write!(f, "an update is scheduled"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PolicyInput, UpdatePolicy, UpdatePolicyConfig, UpdateReason};
+
+    fn policy(
+        max_block_lag: Option<u64>,
+        trusting_period_fraction: Option<f64>,
+        schedule: Option<&str>,
+    ) -> UpdatePolicy {
+        UpdatePolicy::try_from(&UpdatePolicyConfig {
+            max_block_lag,
+            trusting_period_fraction,
+            schedule: schedule.map(String::from),
+            min_spacing: 60,
+            poll_interval: 30,
+        })
+        .unwrap()
+    }
+
+    const fn input(client_height: u64, chain_height: u64, now: u64) -> PolicyInput {
+        PolicyInput {
+            client_height,
+            chain_height,
+            consensus_timestamp: 1000,
+            trusting_period: 300,
+            now,
+        }
+    }
+
+    #[test]
+    fn test_block_lag() {
+        let policy = policy(Some(10), None, None);
+
+        assert!(policy.evaluate(&input(100, 109, 1000), None, 0).is_none());
+        assert!(matches!(
+            policy.evaluate(&input(100, 110, 1000), None, 0),
+            Some(UpdateReason::BlockLag(10))
+        ));
+        // the client is not behind the chain
+        assert!(policy.evaluate(&input(100, 100, 1000), None, 0).is_none());
+        assert!(policy.evaluate(&input(100, 90, 1000), None, 0).is_none());
+    }
+
+    #[test]
+    fn test_trusting_period_fraction() {
+        // 150s of the trusting period of 300s
+        let policy = policy(None, Some(0.5), None);
+
+        assert!(policy.evaluate(&input(100, 101, 1149), None, 0).is_none());
+        assert!(matches!(
+            policy.evaluate(&input(100, 101, 1150), None, 0),
+            Some(UpdateReason::TrustingPeriod {
+                elapsed: 150,
+                trusting_period: 300
+            })
+        ));
+        // a consensus state from the future has no elapsed time
+        assert!(policy.evaluate(&input(100, 101, 900), None, 0).is_none());
+    }
+
+    #[test]
+    fn test_schedule() {
+        let policy = policy(None, None, Some("0 */30 * * * *"));
+
+        assert!(policy.evaluate(&input(100, 101, 1799), None, 0).is_none());
+        assert!(matches!(
+            policy.evaluate(&input(100, 101, 1800), None, 0),
+            Some(UpdateReason::Schedule)
+        ));
+        // the scheduled time is before the start
+        assert!(policy
+            .evaluate(&input(100, 101, 1900), None, 1800)
+            .is_none());
+    }
+
+    #[test]
+    fn test_min_spacing() {
+        let policy = policy(Some(1), None, None);
+
+        assert!(policy
+            .evaluate(&input(100, 101, 1000), Some(941), 0)
+            .is_none());
+        assert!(policy
+            .evaluate(&input(100, 101, 1000), Some(940), 0)
+            .is_some());
+        assert!(policy.evaluate(&input(100, 101, 1000), None, 0).is_some());
+        // a last update in the future is within the spacing
+        assert!(policy
+            .evaluate(&input(100, 101, 1000), Some(1010), 0)
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        for fraction in [0.0, -0.5, 1.5, f64::NAN] {
+            let config = UpdatePolicyConfig {
+                trusting_period_fraction: Some(fraction),
+                ..UpdatePolicyConfig::default()
+            };
+            assert!(UpdatePolicy::try_from(&config).is_err(), "{fraction}");
+        }
+
+        let config = UpdatePolicyConfig {
+            trusting_period_fraction: Some(1.0),
+            ..UpdatePolicyConfig::default()
+        };
+        assert!(UpdatePolicy::try_from(&config).is_ok());
+
+        let config = UpdatePolicyConfig {
+            schedule: Some("every 30 minutes".to_string()),
+            ..UpdatePolicyConfig::default()
+        };
+        assert!(UpdatePolicy::try_from(&config).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Contains the provers that are shared between the clients of the operator.
+
+use std::sync::Arc;
+
+use sp1_ics07_tendermint_prover::{
+    programs::SP1Program,
+    prover::{ProofObserver, SP1ICS07TendermintProver, SupportedProofType},
+};
+use tokio::sync::OnceCell;
+
+/// A lazily initialized set of provers for a program, one per proof type.
+/// The prover setup is expensive, so it is only done once per program and proof type and then
+/// shared between all the clients that need it.
+pub struct SharedProvers<T: SP1Program> {
+    groth16: OnceCell<Arc<SP1ICS07TendermintProver<T>>>,
+    plonk: OnceCell<Arc<SP1ICS07TendermintProver<T>>>,
+    observer: Option<ProofObserver>,
+}
+
+impl<T: SP1Program + Send + Sync + 'static> SharedProvers<T> {
+    /// Creates a new empty set of provers.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            groth16: OnceCell::const_new(),
+            plonk: OnceCell::const_new(),
+            observer: None,
+        }
+    }
+
+    /// Sets a callback that is called after every proof generated by the provers.
+    #[must_use]
+    pub fn with_observer(mut self, observer: ProofObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Returns the prover for the given proof type, initializing it if needed.
+    ///
+    /// # Errors
+    /// Returns an error if the prover setup task fails.
+    pub async fn get(
+        &self,
+        proof_type: SupportedProofType,
+    ) -> anyhow::Result<Arc<SP1ICS07TendermintProver<T>>> {
+        let cell = match proof_type {
+            SupportedProofType::Groth16 => &self.groth16,
+            SupportedProofType::Plonk => &self.plonk,
+        };
+
+        let observer = self.observer.clone();
+        cell.get_or_try_init(|| async move {
+            let mut prover =
+                tokio::task::spawn_blocking(move || SP1ICS07TendermintProver::<T>::new(proof_type))
+                    .await?;
+            if let Some(observer) = observer {
+                prover = prover.with_observer(observer);
+            }
+            anyhow::Ok(Arc::new(prover))
+        })
+        .await
+        .cloned()
+    }
+}
+
+impl<T: SP1Program + Send + Sync + 'static> Default for SharedProvers<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,156 @@
+//! Contains the retries with exponential backoff of the operator.
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    errors::ErrorClass,
+    events::{EventSender, OperatorEvent},
+};
+
+/// The configuration of the retries of a client.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    /// The delay before the first retry in seconds.
+    pub initial_backoff: u64,
+    /// The maximum delay between two retries in seconds.
+    pub max_backoff: u64,
+}
+
+/// An exponential backoff, shared between a task and its retries so that the task can reset it
+/// once it makes progress.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    attempts: Arc<AtomicU32>,
+}
+
+impl Backoff {
+    /// Creates a new backoff from the retry configuration.
+    #[must_use]
+    pub fn new(config: &RetryConfig) -> Self {
+        Self {
+            initial: Duration::from_secs(config.initial_backoff),
+            max: Duration::from_secs(config.max_backoff),
+            attempts: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    /// Returns the delay before the next retry, doubling it for every consecutive failure.
+    #[must_use]
+    pub fn next_delay(&self) -> Duration {
+        let attempts = self.attempts.fetch_add(1, Ordering::Relaxed);
+        self.initial
+            .saturating_mul(2_u32.saturating_pow(attempts))
+            .min(self.max)
+    }
+
+    /// Resets the backoff after a success.
+    pub fn reset(&self) {
+        self.attempts.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Runs a task until it succeeds or fails with a fatal error, retrying transient and proof errors
+/// with exponential backoff.
+///
+/// # Errors
+/// Returns the first fatal error of the task.
+pub async fn with_retries<F, Fut>(
+    name: &str,
+    events: &EventSender,
+    backoff: &Backoff,
+    mut task: F,
+) -> anyhow::Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<()>>,
+{
+    loop {
+        let Err(err) = task().await else {
+            return Ok(());
+        };
+
+        let class = ErrorClass::of(&err);
+        events.emit(OperatorEvent::Failure {
+            client: name.to_string(),
+            class: class.as_str(),
+        });
+        if class == ErrorClass::Fatal {
+            return Err(err);
+        }
+
+        let delay = backoff.next_delay();
+        log::warn!("[{name}] Retrying in {delay:?} after a {class} error: {err:?}");
+        tokio::time::sleep(delay).await;
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: 5,
+            max_backoff: 600,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Backoff, RetryConfig};
+
+    fn backoff(initial_backoff: u64, max_backoff: u64) -> Backoff {
+        Backoff::new(&RetryConfig {
+            initial_backoff,
+            max_backoff,
+        })
+    }
+
+    fn next_delays(backoff: &Backoff, n: usize) -> Vec<u64> {
+        (0..n).map(|_| backoff.next_delay().as_secs()).collect()
+    }
+
+    #[test]
+    fn test_growth_and_cap() {
+        let backoff = backoff(5, 60);
+        assert_eq!(next_delays(&backoff, 6), vec![5, 10, 20, 40, 60, 60]);
+
+        // the delay stays at the cap once the doubling overflows
+        let backoff = Backoff::new(&RetryConfig::default());
+        for _ in 0..64 {
+            assert!(backoff.next_delay() <= Duration::from_secs(600));
+        }
+        assert_eq!(backoff.next_delay(), Duration::from_secs(600));
+    }
+
+    #[test]
+    fn test_reset() {
+        let backoff = backoff(5, 60);
+        assert_eq!(next_delays(&backoff, 3), vec![5, 10, 20]);
+        backoff.reset();
+        assert_eq!(next_delays(&backoff, 2), vec![5, 10]);
+    }
+
+    #[test]
+    fn test_shared_between_clones() {
+        // a task resets the backoff of its retries through a clone
+        let backoff = backoff(5, 60);
+        let task_backoff = backoff.clone();
+        assert_eq!(next_delays(&backoff, 2), vec![5, 10]);
+        assert_eq!(next_delays(&task_backoff, 1), vec![20]);
+        task_backoff.reset();
+        assert_eq!(next_delays(&backoff, 1), vec![5]);
+    }
+}
@@ -0,0 +1,448 @@
+//! Contains the on-disk state store of the operator.
+//!
+//! The store keeps track of the last submitted update, the transactions that were broadcast but
+//! not yet confirmed, and the proofs that were generated but not yet submitted. This allows the
+//! operator to resume after a restart without generating the same proof twice, and to replace a
+//! transaction that is stuck in the mempool by reusing its nonce.
+
+use std::path::{Path, PathBuf};
+
+use alloy::{
+    network::{Network, ReceiptResponse},
+    primitives::TxHash,
+    providers::Provider,
+    transports::Transport,
+};
+use alloy_sol_types::SolValue;
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_solidity::{
+    sp1_ics07_tendermint::{
+        sp1_ics07_tendermintErrors as ContractError, sp1_ics07_tendermintInstance,
+    },
+    IICS02ClientMsgs::Height,
+    IUpdateClientMsgs::{MsgUpdateClient, UpdateClientOutput},
+};
+
+use crate::runners::simulation::contract_revert;
+
+/// The key of a proof in the state store.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofKey {
+    /// The trusted height of the update.
+    pub trusted_height: Height,
+    /// The target height of the update.
+    pub target_height: Height,
+    /// The verification key of the program that generated the proof.
+    pub vkey: String,
+}
+
+/// A proof that was generated but has not been confirmed on chain yet.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredProof {
+    /// The key of the proof.
+    pub key: ProofKey,
+    /// The time used to generate the proof, in seconds.
+    pub time: u64,
+    /// The encoded update client message.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub update_msg: Vec<u8>,
+    /// The last transaction that was broadcast with this proof, if it may still be pending.
+    /// Its nonce is reused when the proof is resubmitted, so that the transaction is replaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx: Option<TxParams>,
+}
+
+/// The parameters of a broadcast transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxParams {
+    /// The hash of the transaction.
+    pub tx_hash: TxHash,
+    /// The nonce of the transaction.
+    pub nonce: u64,
+    /// The maximum fee per gas of the transaction in wei.
+    pub max_fee_per_gas: u128,
+    /// The maximum priority fee per gas of the transaction in wei.
+    pub max_priority_fee_per_gas: u128,
+}
+
+/// A transaction that was broadcast but not yet confirmed.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTx {
+    /// The hash of the transaction.
+    pub tx_hash: TxHash,
+    /// The hashes of the transactions with the same nonce that were replaced by this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replaced: Vec<TxHash>,
+    /// The proof that was submitted in the transaction.
+    pub proof: StoredProof,
+}
+
+/// The last update that was confirmed on chain.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmittedUpdate {
+    /// The hash of the transaction, if known.
+    pub tx_hash: Option<TxHash>,
+    /// The key of the submitted proof.
+    pub key: ProofKey,
+}
+
+/// The persisted state of the operator.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorState {
+    /// The last update that was confirmed on chain.
+    pub last_update: Option<SubmittedUpdate>,
+    /// The transactions that were broadcast but not yet confirmed.
+    pub pending_txs: Vec<PendingTx>,
+    /// The proofs that were generated but not yet submitted.
+    pub proofs: Vec<StoredProof>,
+}
+
+/// A file backed store for the [`OperatorState`].
+#[allow(clippy::module_name_repetitions)]
+pub struct StateStore {
+    path: PathBuf,
+    state: OperatorState,
+}
+
+impl StoredProof {
+    /// Creates a new stored proof from an update client message.
+    ///
+    /// # Errors
+    /// Returns an error if the public values of the message cannot be decoded.
+    pub fn new(vkey: String, update_msg: &MsgUpdateClient) -> anyhow::Result<Self> {
+        let output = UpdateClientOutput::abi_decode(&update_msg.sp1Proof.publicValues, false)?;
+        Ok(Self {
+            key: ProofKey {
+                trusted_height: output.trustedHeight,
+                target_height: output.newHeight,
+                vkey,
+            },
+            time: output.time,
+            update_msg: update_msg.abi_encode(),
+            tx: None,
+        })
+    }
+
+    /// Decodes the stored update client message.
+    ///
+    /// # Errors
+    /// Returns an error if the stored message cannot be decoded.
+    pub fn msg(&self) -> anyhow::Result<MsgUpdateClient> {
+        Ok(MsgUpdateClient::abi_decode(&self.update_msg, false)?)
+    }
+}
+
+impl StateStore {
+    /// Loads the state store from the given path.
+    /// If the file does not exist, an empty state is used.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let state = if path.exists() {
+            serde_json::from_slice(&std::fs::read(&path)?)?
+        } else {
+            OperatorState::default()
+        };
+
+        Ok(Self { path, state })
+    }
+
+    /// Returns the current state.
+    #[must_use]
+    pub const fn state(&self) -> &OperatorState {
+        &self.state
+    }
+
+    /// Writes the state to disk.
+    /// The state is first written to a temporary file which is then renamed, so that a crash
+    /// during the write does not corrupt the store.
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be written.
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(&self.state)?)?;
+        std::fs::rename(tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    /// Stores a proof that was generated but not yet submitted.
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be written.
+    pub fn insert_proof(&mut self, proof: StoredProof) -> anyhow::Result<()> {
+        self.state.proofs.retain(|p| p.key != proof.key);
+        self.state.proofs.push(proof);
+        self.save()
+    }
+
+    /// Finds a stored proof for the given trusted height and verification key.
+    /// If there are multiple, the one with the highest target height is returned.
+    #[must_use]
+    pub fn find_proof(&self, trusted_height: &Height, vkey: &str) -> Option<&StoredProof> {
+        self.state
+            .proofs
+            .iter()
+            .filter(|p| &p.key.trusted_height == trusted_height && p.key.vkey == vkey)
+            .max_by_key(|p| &p.key.target_height)
+    }
+
+    /// Moves a stored proof to the pending transactions.
+    /// If the proof is already pending, its transaction is recorded as replaced by the new one.
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be written.
+    pub fn mark_pending(&mut self, tx: TxParams, key: &ProofKey) -> anyhow::Result<()> {
+        if let Some(pending) = self
+            .state
+            .pending_txs
+            .iter_mut()
+            .find(|pending| &pending.proof.key == key)
+        {
+            if pending.tx_hash != tx.tx_hash {
+                pending.replaced.push(pending.tx_hash);
+                pending.tx_hash = tx.tx_hash;
+            }
+            pending.proof.tx = Some(tx);
+            return self.save();
+        }
+
+        let Some(idx) = self.state.proofs.iter().position(|p| &p.key == key) else {
+            anyhow::bail!("no stored proof found for {key:?}");
+        };
+        let mut proof = self.state.proofs.remove(idx);
+        proof.tx = Some(tx);
+        self.state.pending_txs.push(PendingTx {
+            tx_hash: tx.tx_hash,
+            replaced: Vec::new(),
+            proof,
+        });
+        self.save()
+    }
+
+    /// Records a confirmed update and removes it from the pending transactions.
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be written.
+    pub fn mark_submitted(&mut self, tx_hash: Option<TxHash>, key: ProofKey) -> anyhow::Result<()> {
+        self.state.pending_txs.retain(|tx| tx.proof.key != key);
+        self.state.proofs.retain(|p| p.key != key);
+        self.state.last_update = Some(SubmittedUpdate { tx_hash, key });
+        self.save()
+    }
+
+    /// Moves a pending transaction back to the stored proofs, so that it can be resubmitted.
+    /// If the transaction was included, e.g. reverted, its nonce is not reused.
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be written.
+    pub fn mark_failed(&mut self, tx_hash: TxHash, included: bool) -> anyhow::Result<()> {
+        if let Some(idx) = self
+            .state
+            .pending_txs
+            .iter()
+            .position(|tx| tx.tx_hash == tx_hash)
+        {
+            let mut proof = self.state.pending_txs.remove(idx).proof;
+            if included {
+                proof.tx = None;
+            }
+            self.state.proofs.push(proof);
+        }
+        self.save()
+    }
+
+    /// Reconciles the stored state with the contract.
+    ///
+    /// - Pending transactions with a successful receipt, or whose target consensus state is
+    ///   already on the contract, are recorded as the last update. The transactions they
+    ///   replaced are checked as well, since any of them may have been included.
+    /// - Pending transactions with a failed receipt are moved back to the stored proofs.
+    /// - Pending transactions without a receipt are moved back to the stored proofs, keeping
+    ///   their nonce so that they are replaced when the proof is resubmitted.
+    /// - Stored proofs whose target consensus state is already on the contract, which were
+    ///   generated by a different program, or whose time is older than the allowed clock drift
+    ///   of the contract are dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the contract or the provider cannot be queried, or if the state
+    /// cannot be written.
+    pub async fn reconcile<T, P, N>(
+        &mut self,
+        contract: &sp1_ics07_tendermintInstance<T, P, N>,
+        vkey: &str,
+    ) -> anyhow::Result<()>
+    where
+        T: Transport + Clone,
+        P: Provider<T, N>,
+        N: Network,
+    {
+        let pending_txs = std::mem::take(&mut self.state.pending_txs);
+        for pending in pending_txs {
+            let mut receipt = None;
+            for tx_hash in std::iter::once(pending.tx_hash).chain(pending.replaced.iter().copied())
+            {
+                if let Some(r) = contract.provider().get_transaction_receipt(tx_hash).await? {
+                    receipt = Some((tx_hash, r));
+                    break;
+                }
+            }
+
+            let key = pending.proof.key.clone();
+            match receipt {
+                Some((tx_hash, receipt)) if receipt.status() => {
+                    log::info!(
+                        "Pending transaction {tx_hash} was confirmed while the operator was down"
+                    );
+                    self.state.last_update = Some(SubmittedUpdate {
+                        tx_hash: Some(tx_hash),
+                        key,
+                    });
+                }
+                Some((tx_hash, _)) => {
+                    log::warn!(
+                        "Pending transaction {tx_hash} has failed, keeping its proof for resubmission"
+                    );
+                    let mut proof = pending.proof;
+                    proof.tx = None;
+                    self.state.proofs.push(proof);
+                }
+                None if has_consensus_state(contract, key.target_height.clone()).await? => {
+                    self.state.last_update = Some(SubmittedUpdate { tx_hash: None, key });
+                }
+                None => {
+                    log::warn!(
+                        "Pending transaction {} was not found, keeping its proof for resubmission",
+                        pending.tx_hash
+                    );
+                    self.state.proofs.push(pending.proof);
+                }
+            }
+        }
+
+        let allowed_drift = u64::from(contract.ALLOWED_SP1_CLOCK_DRIFT().call().await?._0);
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs();
+
+        let proofs = std::mem::take(&mut self.state.proofs);
+        for proof in proofs {
+            if proof.key.vkey != vkey {
+                log::info!("Dropping stored proof {:?}: vkey mismatch", proof.key);
+            } else if now.saturating_sub(proof.time) > allowed_drift {
+                log::info!("Dropping stored proof {:?}: proof is too old", proof.key);
+            } else if has_consensus_state(contract, proof.key.target_height.clone()).await? {
+                log::info!("Dropping stored proof {:?}: already on chain", proof.key);
+            } else {
+                log::info!("Resuming with stored proof {:?}", proof.key);
+                self.state.proofs.push(proof);
+            }
+        }
+
+        self.save()
+    }
+}
+
+/// Returns whether the contract has a consensus state at the given height.
+///
+/// # Errors
+/// Returns an error if the contract cannot be queried, or reverts with another error than
+/// `ConsensusStateNotFound`.
+pub async fn has_consensus_state<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    height: Height,
+) -> anyhow::Result<bool>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    // `getConsensusStateHash` reverts if the consensus state is not found. Any other error, e.g.
+    // of the connection, is returned to be classified by the caller.
+    match contract.getConsensusStateHash(height).call().await {
+        Ok(_) => Ok(true),
+        Err(alloy::contract::Error::TransportError(err))
+            if matches!(
+                contract_revert(&err),
+                Some(ContractError::ConsensusStateNotFound(_))
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_ics07_tendermint_solidity::IICS02ClientMsgs::Height;
+
+    use super::{ProofKey, StateStore, StoredProof};
+
+    const fn height(revision_number: u32, revision_height: u32) -> Height {
+        Height {
+            revisionNumber: revision_number,
+            revisionHeight: revision_height,
+        }
+    }
+
+    fn proof(trusted_height: Height, target_height: Height) -> StoredProof {
+        StoredProof {
+            key: ProofKey {
+                trusted_height,
+                target_height,
+                vkey: "vkey".to_string(),
+            },
+            time: 0,
+            update_msg: Vec::new(),
+            tx: None,
+        }
+    }
+
+    #[test]
+    fn test_find_proof() {
+        let path = std::env::temp_dir().join(format!(
+            "operator-state-test-{}/state.json",
+            std::process::id()
+        ));
+        let mut store = StateStore::load(&path).unwrap();
+        store
+            .insert_proof(proof(height(4, 100), height(4, 110)))
+            .unwrap();
+        store
+            .insert_proof(proof(height(4, 100), height(4, 120)))
+            .unwrap();
+        store
+            .insert_proof(proof(height(5, 100), height(5, 105)))
+            .unwrap();
+
+        // the proof with the highest target height is used
+        let found = store.find_proof(&height(4, 100), "vkey").unwrap();
+        assert_eq!(found.key.target_height, height(4, 120));
+        // a proof from the same revision height of another revision is not used
+        let found = store.find_proof(&height(5, 100), "vkey").unwrap();
+        assert_eq!(found.key.target_height, height(5, 105));
+        assert!(store.find_proof(&height(6, 100), "vkey").is_none());
+        assert!(store.find_proof(&height(4, 100), "other vkey").is_none());
+
+        // the heights are persisted with their revision
+        let store = StateStore::load(&path).unwrap();
+        assert!(store.find_proof(&height(5, 100), "vkey").is_some());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
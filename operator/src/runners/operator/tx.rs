@@ -0,0 +1,558 @@
+//! Contains the submission of update and misbehaviour transactions with fee management.
+//!
+//! Transactions use EIP-1559 fees within the configured caps. A transaction that is not included
+//! after a timeout is replaced by a transaction with the same nonce and bumped fees, so that the
+//! proof it carries is not lost to a stuck mempool entry. The nonce and fees of every broadcast
+//! update transaction are persisted in the state store, so that the replacement also works
+//! across restarts.
+//!
+//! The update loop and the misbehaviour monitor of a client send their transactions from the
+//! same signer through a shared [`TxSender`], which assigns the nonces of new transactions one at
+//! a time.
+//!
+//! Before a new transaction is broadcast, the update is simulated to decode its
+//! [`UpdateResult`], so that updates that would be no-ops or revert are not paid for. The result
+//! of an included transaction is decoded from the state of the client at its block.
+
+use std::{sync::Arc, time::Duration};
+
+use alloy::{
+    eips::BlockId,
+    network::{Network, ReceiptResponse},
+    primitives::{Address, Bytes, TxHash},
+    providers::Provider,
+    transports::Transport,
+};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_solidity::{
+    sp1_ics07_tendermint::sp1_ics07_tendermintInstance, UpdateResult,
+};
+use tokio::sync::Mutex;
+
+use crate::runners::simulation::{self, Simulation};
+
+use super::{
+    errors::{ErrorClass, OperatorError},
+    events::{EventSender, OperatorEvent},
+    state::{StateStore, StoredProof, TxParams},
+};
+
+/// The interval at which receipts are polled.
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(4);
+
+/// The fee configuration of a client.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FeeConfig {
+    /// The maximum `maxFeePerGas` of a transaction in wei.
+    pub max_fee_per_gas: Option<u64>,
+    /// The maximum `maxPriorityFeePerGas` of a transaction in wei.
+    pub max_priority_fee_per_gas: Option<u64>,
+    /// Updates that are not urgent are delayed while the gas price is above this value in wei.
+    pub max_gas_price: Option<u64>,
+    /// The time after which a transaction that is not included is replaced, in seconds.
+    pub stuck_timeout: u64,
+    /// The percentage by which the fees are bumped when a transaction is replaced.
+    /// Most nodes require at least 10%.
+    pub fee_bump_percent: u64,
+}
+
+/// The sender of the transactions of a client, shared by its update loop and its misbehaviour
+/// monitor.
+/// The nonce of a new transaction is the pending nonce of the signer, which is only read while
+/// no other new transaction of the signer is being broadcast, so that two transactions never get
+/// the same nonce.
+#[derive(Debug, Clone)]
+pub struct TxSender {
+    from: Address,
+    fees: FeeConfig,
+    nonce_lock: Arc<Mutex<()>>,
+}
+
+impl TxSender {
+    /// Creates a new sender of the transactions of the signer `from`.
+    #[must_use]
+    pub fn new(from: Address, fees: FeeConfig) -> Self {
+        Self {
+            from,
+            fees,
+            nonce_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Submits a stored proof to the contract and waits for the transaction to be included,
+    /// replacing it with bumped fees whenever it is stuck.
+    /// The state store is updated as the transaction progresses.
+    /// Returns the result of the update, which is [`UpdateResult::NoOp`] without a transaction
+    /// if the contract already has the consensus state.
+    ///
+    /// # Errors
+    /// Returns an error if a request fails, if the update reverts, or if another transaction
+    /// with the same nonce was included.
+    pub async fn submit_update<T, P, N>(
+        &self,
+        contract: &sp1_ics07_tendermintInstance<T, P, N>,
+        store: &mut StateStore,
+        events: &EventSender,
+        client: &str,
+        mut proof: StoredProof,
+    ) -> anyhow::Result<UpdateResult>
+    where
+        T: Transport + Clone,
+        P: Provider<T, N>,
+        N: Network,
+    {
+        let provider = contract.provider();
+        let stuck_timeout = Duration::from_secs(self.fees.stuck_timeout);
+
+        // Reuse the nonce of a previous transaction of this proof if it was not consumed, so that
+        // the previous transaction is replaced instead of blocking the new one.
+        let confirmed_nonce = provider.get_transaction_count(self.from).await?;
+        let (mut tx, mut stuck) = match proof.tx.take() {
+            Some(prev) if confirmed_nonce <= prev.nonce => {
+                info!(
+                    "[{client}] Resubmitting the proof of transaction {} with nonce {}",
+                    prev.tx_hash, prev.nonce
+                );
+                store.mark_pending(prev, &proof.key)?;
+                (prev, true)
+            }
+            _ => {
+                match simulation::simulate_update_client(contract, &[proof.msg()?]).await? {
+                    Simulation::Success(results) if results == [UpdateResult::NoOp] => {
+                        info!(
+                            "[{client}] The contract already has the consensus state at height {}, skipping the transaction",
+                            proof.key.target_height
+                        );
+                        store.mark_submitted(None, proof.key)?;
+                        return Ok(UpdateResult::NoOp);
+                    }
+                    Simulation::Success(results) if results == [UpdateResult::Misbehaviour] => {
+                        error!(
+                            "[{client}] The update from height {} to height {} is a misbehaviour and will freeze the client",
+                            proof.key.trusted_height, proof.key.target_height
+                        );
+                    }
+                    Simulation::Success(_) => {}
+                    Simulation::Revert(revert) => {
+                        return Err(OperatorError::new(
+                            ErrorClass::of_revert(&revert),
+                            format!("the update would revert with {revert:?}"),
+                        )
+                        .into());
+                    }
+                }
+
+                let _nonce_guard = self.nonce_lock.lock().await;
+                let estimate = provider.estimate_eip1559_fees(None).await?;
+                let tx = self.fees.cap(TxParams {
+                    tx_hash: TxHash::ZERO,
+                    nonce: provider.get_transaction_count(self.from).pending().await?,
+                    max_fee_per_gas: estimate.max_fee_per_gas,
+                    max_priority_fee_per_gas: estimate.max_priority_fee_per_gas,
+                });
+                (send_update(contract, store, &proof, tx).await?, false)
+            }
+        };
+
+        loop {
+            if stuck {
+                if provider.get_transaction_count(self.from).await? > tx.nonce {
+                    // A replaced transaction, or another transaction of the signer, was included.
+                    // The state store is reconciled when the client restarts.
+                    return Err(OperatorError::transient(format!(
+                        "nonce {} was consumed by another transaction",
+                        tx.nonce
+                    ))
+                    .into());
+                }
+
+                let estimate = provider.estimate_eip1559_fees(None).await?;
+                if let Some(bumped) = self.fees.bump(
+                    &tx,
+                    estimate.max_fee_per_gas,
+                    estimate.max_priority_fee_per_gas,
+                ) {
+                    warn!(
+                        "[{client}] Transaction {} is stuck, replacing it with max fee {} and priority fee {}",
+                        tx.tx_hash, bumped.max_fee_per_gas, bumped.max_priority_fee_per_gas
+                    );
+                    tx = send_update(contract, store, &proof, bumped).await?;
+                } else if provider
+                    .get_transaction_by_hash(tx.tx_hash)
+                    .await?
+                    .is_none()
+                {
+                    warn!(
+                        "[{client}] Transaction {} was dropped, resubmitting it",
+                        tx.tx_hash
+                    );
+                    tx = send_update(contract, store, &proof, tx).await?;
+                } else {
+                    warn!(
+                        "[{client}] Transaction {} is stuck, but its fees cannot be bumped above the configured caps",
+                        tx.tx_hash
+                    );
+                }
+            }
+
+            let Some(receipt) = wait_for_receipt(provider, tx.tx_hash, stuck_timeout).await? else {
+                stuck = true;
+                continue;
+            };
+
+            events.emit(OperatorEvent::TxIncluded {
+                client: client.to_string(),
+                gas_used: receipt.gas_used().into(),
+            });
+            if !receipt.status() {
+                store.mark_failed(tx.tx_hash, true)?;
+                return Err(
+                    OperatorError::proof(format!("transaction {} reverted", tx.tx_hash)).into(),
+                );
+            }
+            store.mark_submitted(Some(tx.tx_hash), proof.key)?;
+
+            return update_result(contract, &receipt).await;
+        }
+    }
+
+    /// Submits a misbehaviour to the contract and waits for the transaction to be included,
+    /// replacing it with bumped fees whenever it is stuck.
+    /// Returns the hash of the included transaction.
+    ///
+    /// # Errors
+    /// Returns an error if a request fails, if the transaction reverts, or if another
+    /// transaction with the same nonce was included.
+    pub async fn submit_misbehaviour<T, P, N>(
+        &self,
+        contract: &sp1_ics07_tendermintInstance<T, P, N>,
+        client: &str,
+        submit_msg: Bytes,
+    ) -> anyhow::Result<TxHash>
+    where
+        T: Transport + Clone,
+        P: Provider<T, N>,
+        N: Network,
+    {
+        let provider = contract.provider();
+        let stuck_timeout = Duration::from_secs(self.fees.stuck_timeout);
+
+        let mut tx = {
+            let _nonce_guard = self.nonce_lock.lock().await;
+            let estimate = provider.estimate_eip1559_fees(None).await?;
+            let tx = self.fees.cap(TxParams {
+                tx_hash: TxHash::ZERO,
+                nonce: provider.get_transaction_count(self.from).pending().await?,
+                max_fee_per_gas: estimate.max_fee_per_gas,
+                max_priority_fee_per_gas: estimate.max_priority_fee_per_gas,
+            });
+            send_misbehaviour(contract, &submit_msg, tx).await?
+        };
+
+        loop {
+            if let Some(receipt) = wait_for_receipt(provider, tx.tx_hash, stuck_timeout).await? {
+                if !receipt.status() {
+                    return Err(OperatorError::proof(format!(
+                        "misbehaviour transaction {} reverted",
+                        tx.tx_hash
+                    ))
+                    .into());
+                }
+                return Ok(tx.tx_hash);
+            }
+
+            if provider.get_transaction_count(self.from).await? > tx.nonce {
+                // A replaced transaction, or another transaction of the signer, was included.
+                return Err(OperatorError::transient(format!(
+                    "nonce {} was consumed by another transaction",
+                    tx.nonce
+                ))
+                .into());
+            }
+
+            let estimate = provider.estimate_eip1559_fees(None).await?;
+            if let Some(bumped) = self.fees.bump(
+                &tx,
+                estimate.max_fee_per_gas,
+                estimate.max_priority_fee_per_gas,
+            ) {
+                warn!(
+                    "[{client}] Misbehaviour transaction {} is stuck, replacing it with max fee {} and priority fee {}",
+                    tx.tx_hash, bumped.max_fee_per_gas, bumped.max_priority_fee_per_gas
+                );
+                tx = send_misbehaviour(contract, &submit_msg, bumped).await?;
+            } else if provider
+                .get_transaction_by_hash(tx.tx_hash)
+                .await?
+                .is_none()
+            {
+                warn!(
+                    "[{client}] Misbehaviour transaction {} was dropped, resubmitting it",
+                    tx.tx_hash
+                );
+                tx = send_misbehaviour(contract, &submit_msg, tx).await?;
+            } else {
+                warn!(
+                    "[{client}] Misbehaviour transaction {} is stuck, but its fees cannot be bumped above the configured caps",
+                    tx.tx_hash
+                );
+            }
+        }
+    }
+}
+
+/// Decodes the result of an included update transaction.
+/// Receipts do not contain return values, so a transaction that froze the client is a
+/// misbehaviour, and an update otherwise.
+async fn update_result<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    receipt: &N::ReceiptResponse,
+) -> anyhow::Result<UpdateResult>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let block = receipt
+        .block_number()
+        .map_or(BlockId::latest(), BlockId::number);
+    let client_state = contract.getClientState().block(block).call().await?._0;
+    Ok(if client_state.isFrozen {
+        UpdateResult::Misbehaviour
+    } else {
+        UpdateResult::Update
+    })
+}
+
+/// Returns the current gas price if it is above the configured maximum for updates that are not
+/// urgent.
+///
+/// # Errors
+/// Returns an error if the gas price cannot be queried.
+pub async fn gas_price_above_max<T, P, N>(
+    provider: &P,
+    fees: &FeeConfig,
+) -> anyhow::Result<Option<u128>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let Some(max_gas_price) = fees.max_gas_price else {
+        return Ok(None);
+    };
+    let gas_price = provider.get_gas_price().await?;
+    Ok((gas_price > u128::from(max_gas_price)).then_some(gas_price))
+}
+
+/// Broadcasts an update transaction with the given nonce and fees, and records it as pending.
+/// Returns the parameters of the broadcast transaction.
+async fn send_update<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    store: &mut StateStore,
+    proof: &StoredProof,
+    tx: TxParams,
+) -> anyhow::Result<TxParams>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let pending_tx = contract
+        .updateClient(proof.update_msg.clone().into())
+        .nonce(tx.nonce)
+        .max_fee_per_gas(tx.max_fee_per_gas)
+        .max_priority_fee_per_gas(tx.max_priority_fee_per_gas)
+        .send()
+        .await?;
+
+    let tx = TxParams {
+        tx_hash: *pending_tx.tx_hash(),
+        ..tx
+    };
+    store.mark_pending(tx, &proof.key)?;
+    Ok(tx)
+}
+
+/// Broadcasts a misbehaviour transaction with the given nonce and fees.
+/// Returns the parameters of the broadcast transaction.
+async fn send_misbehaviour<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    submit_msg: &Bytes,
+    tx: TxParams,
+) -> anyhow::Result<TxParams>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let pending_tx = contract
+        .misbehaviour(submit_msg.clone())
+        .nonce(tx.nonce)
+        .max_fee_per_gas(tx.max_fee_per_gas)
+        .max_priority_fee_per_gas(tx.max_priority_fee_per_gas)
+        .send()
+        .await?;
+
+    Ok(TxParams {
+        tx_hash: *pending_tx.tx_hash(),
+        ..tx
+    })
+}
+
+/// Polls the receipt of a transaction until it is available or the timeout elapses.
+async fn wait_for_receipt<T, P, N>(
+    provider: &P,
+    tx_hash: TxHash,
+    timeout: Duration,
+) -> anyhow::Result<Option<N::ReceiptResponse>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        if let Some(receipt) = provider.get_transaction_receipt(tx_hash).await? {
+            return Ok(Some(receipt));
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Ok(None);
+        }
+        tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+    }
+}
+
+impl FeeConfig {
+    /// Caps the fees of a transaction to the configured maximums.
+    #[must_use]
+    pub fn cap(&self, tx: TxParams) -> TxParams {
+        let max_fee_per_gas = self
+            .max_fee_per_gas
+            .map_or(tx.max_fee_per_gas, |cap| tx.max_fee_per_gas.min(cap.into()));
+        let max_priority_fee_per_gas = self
+            .max_priority_fee_per_gas
+            .map_or(tx.max_priority_fee_per_gas, |cap| {
+                tx.max_priority_fee_per_gas.min(cap.into())
+            })
+            .min(max_fee_per_gas);
+
+        TxParams {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            ..tx
+        }
+    }
+
+    /// Returns the parameters of a replacement for a stuck transaction, with fees bumped by
+    /// the configured percentage or raised to the current estimate, whichever is higher.
+    /// Returns `None` if the caps do not allow a sufficient bump.
+    #[must_use]
+    pub fn bump(
+        &self,
+        tx: &TxParams,
+        estimated_max_fee_per_gas: u128,
+        estimated_max_priority_fee_per_gas: u128,
+    ) -> Option<TxParams> {
+        let bump = |fee: u128| fee + fee * u128::from(self.fee_bump_percent) / 100;
+        let min_max_fee_per_gas = bump(tx.max_fee_per_gas);
+        let min_max_priority_fee_per_gas = bump(tx.max_priority_fee_per_gas);
+
+        let bumped = self.cap(TxParams {
+            max_fee_per_gas: min_max_fee_per_gas.max(estimated_max_fee_per_gas),
+            max_priority_fee_per_gas: min_max_priority_fee_per_gas
+                .max(estimated_max_priority_fee_per_gas),
+            ..*tx
+        });
+
+        (bumped.max_fee_per_gas >= min_max_fee_per_gas
+            && bumped.max_priority_fee_per_gas >= min_max_priority_fee_per_gas)
+            .then_some(bumped)
+    }
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+            max_gas_price: None,
+            stuck_timeout: 180,
+            fee_bump_percent: 15,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::TxHash;
+
+    use super::{FeeConfig, TxParams};
+
+    const fn tx(max_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> TxParams {
+        TxParams {
+            tx_hash: TxHash::ZERO,
+            nonce: 7,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+        }
+    }
+
+    fn fees(max_fee_per_gas: Option<u64>, max_priority_fee_per_gas: Option<u64>) -> FeeConfig {
+        FeeConfig {
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            ..FeeConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_cap() {
+        assert_eq!(fees(None, None).cap(tx(100, 10)), tx(100, 10));
+        assert_eq!(fees(Some(100), Some(10)).cap(tx(100, 10)), tx(100, 10));
+        assert_eq!(fees(Some(80), None).cap(tx(100, 10)), tx(80, 10));
+        assert_eq!(fees(None, Some(5)).cap(tx(100, 10)), tx(100, 5));
+        // the priority fee is never above the max fee
+        assert_eq!(fees(Some(8), None).cap(tx(100, 10)), tx(8, 8));
+        assert_eq!(fees(None, None).cap(tx(8, 10)), tx(8, 8));
+    }
+
+    #[test]
+    fn test_bump() {
+        // 15% by default, rounded down
+        let fees = fees(None, None);
+        assert_eq!(fees.bump(&tx(100, 10), 0, 0), Some(tx(115, 11)));
+        // the current estimate is used if it is higher
+        assert_eq!(fees.bump(&tx(100, 10), 200, 20), Some(tx(200, 20)));
+        assert_eq!(fees.bump(&tx(100, 10), 110, 20), Some(tx(115, 20)));
+
+        let fees = FeeConfig {
+            fee_bump_percent: 100,
+            ..fees
+        };
+        assert_eq!(fees.bump(&tx(100, 10), 0, 0), Some(tx(200, 20)));
+    }
+
+    #[test]
+    fn test_bump_within_caps() {
+        assert_eq!(
+            fees(Some(115), Some(11)).bump(&tx(100, 10), 0, 0),
+            Some(tx(115, 11))
+        );
+        // the estimate is capped, but the bump is still sufficient
+        assert_eq!(
+            fees(Some(150), Some(15)).bump(&tx(100, 10), 200, 20),
+            Some(tx(150, 15))
+        );
+    }
+
+    #[test]
+    fn test_bump_above_caps() {
+        assert_eq!(fees(Some(114), None).bump(&tx(100, 10), 0, 0), None);
+        assert_eq!(fees(None, Some(10)).bump(&tx(100, 10), 0, 0), None);
+        // a transaction already at the caps cannot be replaced
+        assert_eq!(fees(Some(100), Some(10)).bump(&tx(100, 10), 200, 20), None);
+    }
+}
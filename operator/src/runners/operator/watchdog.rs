@@ -0,0 +1,258 @@
+//! Contains the trusting period expiry watchdog of the operator.
+//!
+//! The watchdog compares the timestamp of the latest consensus state of a client with its
+//! trusting period and the current EVM block time. As the client gets closer to expiry, the log
+//! severity is escalated, alerts are sent and the operator is asked to update the client
+//! immediately, regardless of its update policy.
+//! The webhook of the watchdog also receives an alert when the client is frozen, and when the
+//! misbehaviour monitor finds a consensus state of the client that no Tendermint RPC endpoint
+//! serves.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sp1_ics07_tendermint_solidity::IICS02ClientMsgs::Height;
+
+/// The configuration of the expiry watchdog.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchdogConfig {
+    /// The fraction of the trusting period after which the client is in the warning level.
+    pub warning_threshold: f64,
+    /// The fraction of the trusting period after which the client is in the critical level.
+    pub critical_threshold: f64,
+    /// A URL to which alerts are posted as JSON when the expiry level changes, when the client is
+    /// frozen, when a consensus state of the client matches no Tendermint RPC endpoint, or when a
+    /// detected misbehaviour cannot be submitted.
+    pub alert_webhook: Option<String>,
+}
+
+/// How close a client is to the end of its trusting period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiryLevel {
+    /// The client is far from expiry.
+    Ok,
+    /// The client passed the warning threshold.
+    Warning,
+    /// The client passed the critical threshold.
+    Critical,
+    /// The trusting period of the client has passed.
+    Expired,
+}
+
+/// An alert sent by the watchdog when the expiry level of a client changes.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Alert<'a> {
+    client: &'a str,
+    level: ExpiryLevel,
+    previous_level: ExpiryLevel,
+    elapsed: u64,
+    trusting_period: u32,
+    remaining: u64,
+}
+
+/// An alert sent when a client is frozen.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FrozenAlert<'a> {
+    client: &'a str,
+    frozen: bool,
+    reason: &'a str,
+}
+
+/// An alert sent when a consensus state of a client does not match the header of any Tendermint
+/// RPC endpoint at its height.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UnknownConsensusStateAlert<'a> {
+    client: &'a str,
+    revision_number: u32,
+    revision_height: u32,
+}
+
+/// An alert sent when a misbehaviour was detected at a height of a client, but could not be
+/// proven or submitted.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MisbehaviourFailedAlert<'a> {
+    client: &'a str,
+    revision_number: u32,
+    revision_height: u32,
+    reason: &'a str,
+}
+
+/// The expiry watchdog of a single client.
+pub struct Watchdog {
+    client_name: String,
+    config: WatchdogConfig,
+    level: ExpiryLevel,
+    http_client: reqwest::Client,
+}
+
+impl Watchdog {
+    /// Creates a new watchdog for the given client.
+    ///
+    /// # Errors
+    /// Returns an error if the thresholds are not increasing fractions of the trusting period.
+    pub fn new(client_name: String, config: WatchdogConfig) -> anyhow::Result<Self> {
+        config.validate()?;
+        Ok(Self {
+            client_name,
+            config,
+            level: ExpiryLevel::Ok,
+            http_client: reqwest::Client::new(),
+        })
+    }
+
+    /// Checks the expiry of the client and returns its current expiry level.
+    /// Logs with a severity matching the level, and sends an alert if the level changed.
+    pub async fn check(
+        &mut self,
+        consensus_timestamp: u64,
+        trusting_period: u32,
+        evm_time: u64,
+    ) -> ExpiryLevel {
+        let elapsed = evm_time.saturating_sub(consensus_timestamp);
+        let remaining = u64::from(trusting_period).saturating_sub(elapsed);
+
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = elapsed as f64 / f64::from(trusting_period);
+        let level = if remaining == 0 {
+            ExpiryLevel::Expired
+        } else if fraction >= self.config.critical_threshold {
+            ExpiryLevel::Critical
+        } else if fraction >= self.config.warning_threshold {
+            ExpiryLevel::Warning
+        } else {
+            ExpiryLevel::Ok
+        };
+
+        let name = &self.client_name;
+        match level {
+            ExpiryLevel::Ok => log::debug!(
+                "[{name}] The client expires in {remaining}s (trusting period: {trusting_period}s)"
+            ),
+            ExpiryLevel::Warning => log::warn!(
+                "[{name}] The client expires in {remaining}s (trusting period: {trusting_period}s)"
+            ),
+            ExpiryLevel::Critical => log::error!(
+                "[{name}] The client expires in {remaining}s (trusting period: {trusting_period}s)"
+            ),
+            ExpiryLevel::Expired => log::error!(
+                "[{name}] The client has expired {}s ago and needs to be recovered",
+                elapsed - u64::from(trusting_period)
+            ),
+        }
+
+        if level != self.level {
+            let alert = Alert {
+                client: name,
+                level,
+                previous_level: self.level,
+                elapsed,
+                trusting_period,
+                remaining,
+            };
+            self.send_alert(&alert).await;
+            self.level = level;
+        }
+
+        level
+    }
+
+    /// Sends an alert that the client is frozen, with the reason of the freeze.
+    pub async fn alert_frozen(&self, reason: &str) {
+        let alert = FrozenAlert {
+            client: &self.client_name,
+            frozen: true,
+            reason,
+        };
+        self.send_alert(&alert).await;
+    }
+
+    /// Sends an alert that the consensus state of the client at the given height does not match
+    /// the header of any Tendermint RPC endpoint, i.e. that the client may have been updated to
+    /// a fork of the chain.
+    pub async fn alert_unknown_consensus_state(&self, height: &Height) {
+        let alert = UnknownConsensusStateAlert {
+            client: &self.client_name,
+            revision_number: height.revisionNumber,
+            revision_height: height.revisionHeight,
+        };
+        self.send_alert(&alert).await;
+    }
+
+    /// Sends an alert that a misbehaviour detected at the given height could not be proven or
+    /// submitted, so that it can be submitted by hand before the client is used.
+    pub async fn alert_misbehaviour_failed(&self, height: &Height, reason: &str) {
+        let alert = MisbehaviourFailedAlert {
+            client: &self.client_name,
+            revision_number: height.revisionNumber,
+            revision_height: height.revisionHeight,
+            reason,
+        };
+        self.send_alert(&alert).await;
+    }
+
+    /// Posts an alert to the configured webhook, if any.
+    /// Failures are logged, since the alert must not stop the operator.
+    async fn send_alert(&self, alert: &impl Serialize) {
+        let Some(url) = &self.config.alert_webhook else {
+            return;
+        };
+
+        let res = self
+            .http_client
+            .post(url)
+            .json(alert)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(err) = res {
+            log::warn!("[{}] Failed to send alert: {err}", self.client_name);
+        }
+    }
+}
+
+impl WatchdogConfig {
+    /// Validates the thresholds.
+    ///
+    /// # Errors
+    /// Returns an error if the thresholds are not increasing fractions in (0, 1).
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if !(0.0 < self.warning_threshold
+            && self.warning_threshold < self.critical_threshold
+            && self.critical_threshold < 1.0)
+        {
+            anyhow::bail!(
+                "watchdog thresholds must satisfy 0 < warning ({}) < critical ({}) < 1",
+                self.warning_threshold,
+                self.critical_threshold
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            warning_threshold: 0.6,
+            critical_threshold: 0.8,
+            alert_webhook: None,
+        }
+    }
+}
+
+impl fmt::Display for ExpiryLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Warning => write!(f, "warning"),
+            Self::Critical => write!(f, "critical"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Contains the runner for the `recover` command.
+//!
+//! An expired or frozen client cannot be updated anymore, so the applications using it have to
+//! migrate to a substitute client. The substitute genesis keeps the chain identifier and the trust
+//! parameters of the old client at a fresh trusted height, and the report lists every parameter
+//! that differs between the two clients, so that the migration can be checked before deploying.
+
+use std::{env, path::PathBuf};
+
+use crate::{
+    cli::command::{recover::Args, OutputPath},
+    runners::genesis::SP1ICS07TendermintGenesis,
+};
+use alloy::{
+    eips::BlockNumberOrTag,
+    primitives::{Address, B256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::BlockTransactionsKind,
+};
+use alloy_sol_types::SolValue;
+use anyhow::anyhow;
+use log::{info, warn};
+use sp1_ics07_tendermint_prover::prover::SupportedProofType;
+use sp1_ics07_tendermint_solidity::{
+    revision_chain_id, sp1_ics07_tendermint, IICS07TendermintMsgs::ClientState,
+};
+use sp1_ics07_tendermint_utils::rpc::TendermintRpcExt;
+use tendermint_rpc::{Client, HttpClient};
+
+/// The status of the old client at the time of the recovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ClientStatus {
+    /// The client can still be updated.
+    Active,
+    /// The trusting period of the latest consensus state has passed.
+    Expired,
+    /// The client was frozen, e.g. by a misbehaviour.
+    Frozen,
+    /// The status could not be determined, e.g. because the chain pruned the latest block of the
+    /// client.
+    Unknown,
+}
+
+/// The parameters of a client that are compared by the [`RecoveryReport`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientParams {
+    /// The chain identifier.
+    pub chain_id: String,
+    /// The trust level, as a fraction.
+    pub trust_level: String,
+    /// The trusting period in seconds.
+    pub trusting_period: u32,
+    /// The unbonding period in seconds.
+    pub unbonding_period: u32,
+    /// The max clock drift in seconds.
+    pub max_clock_drift: u32,
+    /// The hash of the proof specs.
+    pub proof_specs_hash: B256,
+    /// The latest height, as `{revision number}-{revision height}`.
+    pub latest_height: String,
+    /// The zk algorithm of the proofs.
+    pub zk_algorithm: u8,
+}
+
+/// The comparison of an old client with its substitute.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryReport {
+    /// The address of the old client contract.
+    pub old_contract_address: Address,
+    /// The status of the old client.
+    pub old_client_status: ClientStatus,
+    /// The parameters of the old client.
+    pub old_client: ClientParams,
+    /// The parameters of the substitute client.
+    pub new_client: ClientParams,
+    /// The parameters that differ between the clients, other than the latest height.
+    pub changes: Vec<String>,
+    /// The points to check before migrating the applications to the substitute client.
+    pub warnings: Vec<String>,
+}
+
+/// Creates a substitute `genesis.json` file for an expired or frozen client, and a report
+/// comparing the old and new client parameters.
+#[allow(clippy::missing_errors_doc)]
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    let tm_rpc_client = HttpClient::from_env();
+    let rpc_url = env::var("RPC_URL").map_err(|_| anyhow!("RPC_URL not set"))?;
+    let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+    let contract = sp1_ics07_tendermint::new(args.contract_address, provider);
+
+    let old_client_state = contract.getClientState().call().await?._0;
+    let status = client_status(&contract, &tm_rpc_client, &old_client_state).await?;
+    if status == ClientStatus::Active && !args.force {
+        return Err(anyhow!(
+            "the client at address {} is neither frozen nor expired, use '--force' to recover it anyway",
+            args.contract_address
+        ));
+    }
+
+    let trusted_light_block = tm_rpc_client
+        .get_light_block(args.trusted_block.map(Into::into))
+        .await?;
+    let chain_id = trusted_light_block.signed_header.header.chain_id.as_str();
+    if chain_id != old_client_state.chainId
+        && revision_chain_id(&old_client_state.chainId, chain_id) != chain_id
+    {
+        return Err(anyhow!(
+            "the chain at TENDERMINT_RPC_URL ({chain_id}) is not the chain of the client ({})",
+            old_client_state.chainId
+        ));
+    }
+
+    let genesis = SP1ICS07TendermintGenesis::from_env(
+        &trusted_light_block,
+        Some(
+            args.trusting_period
+                .unwrap_or(old_client_state.trustingPeriod),
+        ),
+        old_client_state.trustLevel.clone().into(),
+        old_client_state.maxClockDrift,
+        args.proof_specs,
+        SupportedProofType::try_from(old_client_state.zkAlgorithm).map_err(|e| anyhow!(e))?,
+    )
+    .await?;
+    let new_client_state = ClientState::abi_decode(&genesis.trusted_client_state, false)?;
+
+    let report = RecoveryReport::new(
+        args.contract_address,
+        status,
+        &old_client_state,
+        &new_client_state,
+    );
+
+    match args.output_path {
+        OutputPath::File(path) => {
+            std::fs::write(PathBuf::from(path), serde_json::to_string_pretty(&genesis)?)?;
+        }
+        OutputPath::Stdout => {
+            println!("{}", serde_json::to_string_pretty(&genesis)?);
+        }
+    }
+
+    for warning in &report.warnings {
+        warn!("{warning}");
+    }
+    match args.report_path {
+        Some(path) => std::fs::write(PathBuf::from(path), serde_json::to_string_pretty(&report)?)?,
+        None => info!(
+            "Recovery report: {}",
+            serde_json::to_string_pretty(&report)?
+        ),
+    }
+
+    Ok(())
+}
+
+/// Returns the status of the client, checking the expiry against the latest EVM block time as the
+/// contract does.
+async fn client_status<T, P, N>(
+    contract: &sp1_ics07_tendermint::sp1_ics07_tendermintInstance<T, P, N>,
+    tm_rpc_client: &HttpClient,
+    client_state: &ClientState,
+) -> anyhow::Result<ClientStatus>
+where
+    T: alloy::transports::Transport + Clone,
+    P: Provider<T, N>,
+    N: alloy::network::Network,
+{
+    if client_state.isFrozen {
+        return Ok(ClientStatus::Frozen);
+    }
+
+    let Ok(commit) = tm_rpc_client
+        .commit(client_state.latestHeight.revisionHeight)
+        .await
+    else {
+        return Ok(ClientStatus::Unknown);
+    };
+    let consensus_timestamp = commit.signed_header.header.time.unix_timestamp();
+    let evm_time = contract
+        .provider()
+        .get_block_by_number(BlockNumberOrTag::Latest, BlockTransactionsKind::Hashes)
+        .await?
+        .ok_or_else(|| anyhow!("latest EVM block not found"))?
+        .header
+        .timestamp;
+
+    if consensus_timestamp + i64::from(client_state.trustingPeriod) <= i64::try_from(evm_time)? {
+        Ok(ClientStatus::Expired)
+    } else {
+        Ok(ClientStatus::Active)
+    }
+}
+
+impl RecoveryReport {
+    /// Compares the old client with its substitute.
+    #[must_use]
+    pub fn new(
+        old_contract_address: Address,
+        old_client_status: ClientStatus,
+        old_client_state: &ClientState,
+        new_client_state: &ClientState,
+    ) -> Self {
+        let old_client = ClientParams::from(old_client_state);
+        let new_client = ClientParams::from(new_client_state);
+
+        let mut changes = Vec::new();
+        let mut compare = |name: &str, old: String, new: String| {
+            if old != new {
+                changes.push(format!("{name}: {old} -> {new}"));
+            }
+        };
+        compare(
+            "chain identifier",
+            old_client.chain_id.clone(),
+            new_client.chain_id.clone(),
+        );
+        compare(
+            "trust level",
+            old_client.trust_level.clone(),
+            new_client.trust_level.clone(),
+        );
+        compare(
+            "trusting period",
+            old_client.trusting_period.to_string(),
+            new_client.trusting_period.to_string(),
+        );
+        compare(
+            "unbonding period",
+            old_client.unbonding_period.to_string(),
+            new_client.unbonding_period.to_string(),
+        );
+        compare(
+            "max clock drift",
+            old_client.max_clock_drift.to_string(),
+            new_client.max_clock_drift.to_string(),
+        );
+        compare(
+            "proof specs hash",
+            old_client.proof_specs_hash.to_string(),
+            new_client.proof_specs_hash.to_string(),
+        );
+        compare(
+            "zk algorithm",
+            old_client.zk_algorithm.to_string(),
+            new_client.zk_algorithm.to_string(),
+        );
+
+        let mut warnings = Vec::new();
+        match old_client_status {
+            ClientStatus::Active => warnings.push(
+                "the old client is neither frozen nor expired, applications can keep using it"
+                    .to_string(),
+            ),
+            ClientStatus::Unknown => warnings.push(
+                "the expiry of the old client could not be checked, its latest block is not available"
+                    .to_string(),
+            ),
+            ClientStatus::Expired | ClientStatus::Frozen => {}
+        }
+        if old_client.chain_id != new_client.chain_id {
+            warnings.push(format!(
+                "the chain was upgraded from {} to {}, the counterparty must be upgraded too",
+                old_client.chain_id, new_client.chain_id
+            ));
+        }
+        if old_client.unbonding_period != new_client.unbonding_period {
+            warnings.push(format!(
+                "the unbonding period of the chain changed from {} to {} seconds, check the trusting period",
+                old_client.unbonding_period, new_client.unbonding_period
+            ));
+        }
+        if old_client.proof_specs_hash != new_client.proof_specs_hash {
+            warnings.push(
+                "the proof specs differ from the proof specs of the old client, check that '--proof-specs' matches the store of the chain"
+                    .to_string(),
+            );
+        }
+        if old_client_status == ClientStatus::Frozen {
+            warnings.push(
+                "the old client was frozen, check that the misbehaviour was resolved by the chain before trusting it again"
+                    .to_string(),
+            );
+        }
+
+        Self {
+            old_contract_address,
+            old_client_status,
+            old_client,
+            new_client,
+            changes,
+            warnings,
+        }
+    }
+}
+
+impl From<&ClientState> for ClientParams {
+    fn from(client_state: &ClientState) -> Self {
+        Self {
+            chain_id: client_state.chainId.clone(),
+            trust_level: format!(
+                "{}/{}",
+                client_state.trustLevel.numerator, client_state.trustLevel.denominator
+            ),
+            trusting_period: client_state.trustingPeriod,
+            unbonding_period: client_state.unbondingPeriod,
+            max_clock_drift: client_state.maxClockDrift,
+            proof_specs_hash: client_state.proofSpecsHash,
+            latest_height: format!(
+                "{}-{}",
+                client_state.latestHeight.revisionNumber, client_state.latestHeight.revisionHeight
+            ),
+            zk_algorithm: client_state.zkAlgorithm,
+        }
+    }
+}
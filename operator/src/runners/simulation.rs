@@ -0,0 +1,261 @@
+//! Contains the simulation of contract calls with `eth_call`, used by the `--dry-run` flags.
+//!
+//! Calls are simulated through the `multicall` function of the contract, so that a sequence of
+//! calls is simulated on top of the state changes of the previous calls, e.g. the intermediate
+//! updates of a bisection. Nothing is broadcast.
+
+use std::env;
+
+use alloy::{
+    network::Network,
+    primitives::{Address, Bytes, U256},
+    providers::{Provider, ProviderBuilder, RootProvider},
+    transports::{
+        http::{Client, Http},
+        RpcError, Transport, TransportError,
+    },
+};
+use alloy_sol_types::{SolCall, SolInterface, SolValue};
+use anyhow::anyhow;
+use sp1_ics07_tendermint_solidity::{
+    sp1_ics07_tendermint::{
+        self, membershipCall, misbehaviourCall, sp1_ics07_tendermintErrors as ContractError,
+        sp1_ics07_tendermintInstance, updateClientCall, upgradeClientCall,
+    },
+    IICS02ClientMsgs::Height,
+    ILightClientMsgs::MsgMembership,
+    IMembershipMsgs::{KVPair, MembershipProof},
+    IMisbehaviourMsgs::MsgSubmitMisbehaviour,
+    IUpdateClientMsgs::MsgUpdateClient,
+    IUpgradeClientMsgs::MsgUpgradeClient,
+    UpdateResult,
+};
+
+/// A contract connected over HTTP without a signer, which is all a simulation needs.
+pub type HttpContract = sp1_ics07_tendermintInstance<Http<Client>, RootProvider<Http<Client>>>;
+
+/// The outcome of a simulated call.
+#[derive(Debug)]
+pub enum Simulation<T> {
+    /// The call would succeed and return the given value.
+    Success(T),
+    /// The call would revert with the given error of the contract.
+    Revert(ContractError),
+}
+
+/// Connects to the contract at `CONTRACT_ADDRESS` on the chain at `RPC_URL`.
+#[allow(clippy::missing_errors_doc)]
+pub fn contract_from_env() -> anyhow::Result<HttpContract> {
+    let rpc_url = env::var("RPC_URL").map_err(|_| anyhow!("RPC_URL not set"))?;
+    let contract_address: Address = env::var("CONTRACT_ADDRESS")
+        .map_err(|_| anyhow!("CONTRACT_ADDRESS not set"))?
+        .parse()?;
+
+    let provider = ProviderBuilder::new().on_http(rpc_url.parse()?);
+    Ok(sp1_ics07_tendermint::new(contract_address, provider))
+}
+
+/// Simulates the given calls in order with a single `eth_call`, and returns their decoded return
+/// values or the error of the first call that reverts.
+///
+/// # Errors
+/// Returns an error if the request fails, or if a call reverts with an error that is not an
+/// error of the contract.
+pub async fn simulate<C, T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    calls: &[C],
+) -> anyhow::Result<Simulation<Vec<C::Return>>>
+where
+    C: SolCall,
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let data = calls
+        .iter()
+        .map(|call| Bytes::from(call.abi_encode()))
+        .collect();
+
+    match multicall(contract, data).await? {
+        Simulation::Success(results) => Ok(Simulation::Success(
+            results
+                .iter()
+                .map(|result| C::abi_decode_returns(result, true))
+                .collect::<Result<_, _>>()?,
+        )),
+        Simulation::Revert(revert) => Ok(Simulation::Revert(revert)),
+    }
+}
+
+/// Simulates the given encoded calls in order with a single `eth_call`, and returns their
+/// encoded return values or the error of the first call that reverts.
+async fn multicall<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    data: Vec<Bytes>,
+) -> anyhow::Result<Simulation<Vec<Bytes>>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    match contract.multicall(data).call().await {
+        Ok(ret) => Ok(Simulation::Success(ret.results)),
+        Err(alloy::contract::Error::TransportError(err)) => match contract_revert(&err) {
+            Some(revert) => Ok(Simulation::Revert(revert)),
+            None => Err(err.into()),
+        },
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Simulates `updateClient` with the given messages in order, and returns their results.
+///
+/// # Errors
+/// Returns an error if the simulation fails, see [`simulate`].
+pub async fn simulate_update_client<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    update_msgs: &[MsgUpdateClient],
+) -> anyhow::Result<Simulation<Vec<UpdateResult>>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let calls: Vec<_> = update_msgs
+        .iter()
+        .map(|msg| updateClientCall {
+            updateMsg: msg.abi_encode().into(),
+        })
+        .collect();
+
+    match simulate(contract, &calls).await? {
+        Simulation::Success(returns) => Ok(Simulation::Success(
+            returns
+                .into_iter()
+                .map(|ret| UpdateResult::try_from(ret._0).map_err(|e| anyhow!(e)))
+                .collect::<anyhow::Result<_>>()?,
+        )),
+        Simulation::Revert(revert) => Ok(Simulation::Revert(revert)),
+    }
+}
+
+/// Simulates `membership` for every key-value pair of a membership proof, and returns the
+/// timestamps of the proof height.
+///
+/// # Errors
+/// Returns an error if the simulation fails, see [`simulate`].
+pub async fn simulate_membership<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    proof: &MembershipProof,
+    proof_height: &Height,
+    kv_pairs: &[KVPair],
+) -> anyhow::Result<Simulation<Vec<U256>>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let proof: Bytes = proof.abi_encode().into();
+    let calls: Vec<_> = kv_pairs
+        .iter()
+        .map(|kv_pair| membershipCall {
+            msgMembership: MsgMembership {
+                proof: proof.clone(),
+                proofHeight: proof_height.clone(),
+                path: kv_pair.path.clone(),
+                value: kv_pair.value.clone(),
+            },
+        })
+        .collect();
+
+    Ok(simulate(contract, &calls)
+        .await?
+        .map(|returns| returns.into_iter().map(|ret| ret.timestamp).collect()))
+}
+
+/// Simulates `misbehaviour` with the given message.
+///
+/// # Errors
+/// Returns an error if the simulation fails, see [`simulate`].
+pub async fn simulate_misbehaviour<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    submit_msg: &MsgSubmitMisbehaviour,
+) -> anyhow::Result<Simulation<()>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let call = misbehaviourCall {
+        misbehaviourMsg: submit_msg.abi_encode().into(),
+    };
+
+    Ok(simulate(contract, &[call]).await?.map(|_| ()))
+}
+
+/// Simulates `upgradeClient` with the given message, after `updateClient` with the given
+/// messages in order, e.g. the updates to the upgrade height.
+///
+/// # Errors
+/// Returns an error if the simulation fails, see [`simulate`].
+pub async fn simulate_upgrade_client<T, P, N>(
+    contract: &sp1_ics07_tendermintInstance<T, P, N>,
+    update_msgs: &[MsgUpdateClient],
+    upgrade_msg: &MsgUpgradeClient,
+) -> anyhow::Result<Simulation<()>>
+where
+    T: Transport + Clone,
+    P: Provider<T, N>,
+    N: Network,
+{
+    let data = update_msgs
+        .iter()
+        .map(|msg| {
+            updateClientCall {
+                updateMsg: msg.abi_encode().into(),
+            }
+            .abi_encode()
+        })
+        .chain(std::iter::once(
+            upgradeClientCall {
+                upgradeMsg: upgrade_msg.abi_encode().into(),
+            }
+            .abi_encode(),
+        ))
+        .map(Bytes::from)
+        .collect();
+
+    Ok(multicall(contract, data).await?.map(|_| ()))
+}
+
+/// Decodes the error of the contract from a reverted call, if any.
+#[must_use]
+pub fn contract_revert(err: &TransportError) -> Option<ContractError> {
+    let RpcError::ErrorResp(payload) = err else {
+        return None;
+    };
+    payload
+        .as_revert_data()
+        .and_then(|data| ContractError::abi_decode(&data, true).ok())
+}
+
+impl<T> Simulation<T> {
+    /// Maps the value of a successful simulation.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Simulation<U> {
+        match self {
+            Self::Success(value) => Simulation::Success(f(value)),
+            Self::Revert(revert) => Simulation::Revert(revert),
+        }
+    }
+
+    /// Returns the value of a successful simulation, or an error describing the revert.
+    ///
+    /// # Errors
+    /// Returns an error if the simulated call reverts.
+    pub fn into_result(self) -> anyhow::Result<T> {
+        match self {
+            Self::Success(value) => Ok(value),
+            Self::Revert(revert) => Err(anyhow!("the call would revert with {revert:?}")),
+        }
+    }
+}
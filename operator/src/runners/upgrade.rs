@@ -0,0 +1,241 @@
+//! Contains the runner for the `upgrade` command.
+//!
+//! A planned upgrade of the chain, e.g. one that changes the chain identifier or the unbonding
+//! period, commits the upgraded client and consensus states under the ICS-02 upgrade path before
+//! the chain halts at the plan height. The client is first updated to the plan height, and then
+//! upgraded from these states with a proof of the upgrade client program.
+
+use std::{env, sync::Arc};
+
+use crate::{
+    cli::command::upgrade::Args,
+    runners::{
+        fixtures::membership::fetch_kv_proof,
+        operator::{bisection, config::SignerConfig, prove_update, unix_now, verifier_options},
+        simulation::{self, Simulation},
+    },
+};
+use alloy::{primitives::B256, providers::ProviderBuilder};
+use alloy_sol_types::SolValue;
+use anyhow::anyhow;
+use ibc_core_commitment_types::merkle::MerkleProof;
+use log::info;
+use reqwest::Url;
+use sp1_ics07_tendermint_prover::{
+    programs::{UpdateClientProgram, UpgradeClientProgram},
+    prover::{SP1ICS07TendermintProver, SupportedProofType},
+};
+use sp1_ics07_tendermint_solidity::{
+    proof_specs_hash, sp1_ics07_tendermint, upgraded_client_state_path,
+    upgraded_consensus_state_path,
+    IICS07TendermintMsgs::ConsensusState as SolConsensusState,
+    ISP1Msgs::SP1Proof,
+    IUpgradeClientMsgs::{MsgUpgradeClient, UpgradeClientOutput},
+};
+use sp1_ics07_tendermint_utils::{light_block::LightBlockExt, rpc::TendermintRpcExt};
+use sp1_sdk::HashableKey;
+use tendermint::Time;
+use tendermint_rpc::HttpClient;
+
+/// Upgrades the client to the upgraded client and consensus states committed by the chain for an
+/// upgrade plan, updating the client to the plan height first if needed.
+/// If the `dry_run` flag is set, the updates and the upgrade are simulated instead of submitted.
+#[allow(clippy::missing_errors_doc, clippy::too_many_lines)]
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    let tm_rpc_client = HttpClient::from_env();
+    let rpc_url = env::var("RPC_URL").map_err(|_| anyhow!("RPC_URL not set"))?;
+    let contract_address = env::var("CONTRACT_ADDRESS")
+        .map_err(|_| anyhow!("CONTRACT_ADDRESS not set"))?
+        .parse()?;
+
+    let provider = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(SignerConfig::from(args.signer).wallet()?)
+        .on_http(Url::parse(&rpc_url)?);
+    let contract = sp1_ics07_tendermint::new(contract_address, provider);
+
+    let mut client_state = contract.getClientState().call().await?._0;
+    anyhow::ensure!(!client_state.isFrozen, "the client is frozen");
+    anyhow::ensure!(
+        proof_specs_hash(&args.proof_specs) == client_state.proofSpecsHash,
+        "the proof specs do not match the proof specs of the client"
+    );
+
+    let upgrade_height = match &args.plan_name {
+        Some(name) => tm_rpc_client
+            .sdk_applied_upgrade_height(name)
+            .await?
+            .ok_or_else(|| anyhow!("the upgrade plan {name} was not applied by the chain"))?,
+        None => tm_rpc_client
+            .sdk_current_upgrade_plan()
+            .await?
+            .ok_or_else(|| anyhow!("the chain has no upgrade plan"))?
+            .height
+            .try_into()?,
+    };
+    let upgrade_block = u32::try_from(upgrade_height)?;
+    let latest_block = client_state.latestHeight.revisionHeight;
+    anyhow::ensure!(
+        latest_block <= upgrade_block,
+        "the client is at block {latest_block}, past the upgrade height {upgrade_height}"
+    );
+
+    let proof_type =
+        SupportedProofType::try_from(client_state.zkAlgorithm).map_err(|e| anyhow!(e))?;
+    let upgrade_light_block = tm_rpc_client.get_light_block(Some(upgrade_height)).await?;
+
+    // The upgraded states are committed at the plan height, so the client must trust it.
+    let mut update_msgs = Vec::new();
+    if latest_block < upgrade_block {
+        let trusted_light_block = tm_rpc_client
+            .get_light_block(Some(latest_block.into()))
+            .await?;
+        let light_blocks = bisection::bisect(
+            &tm_rpc_client,
+            &trusted_light_block,
+            upgrade_light_block.clone(),
+            &verifier_options(&client_state),
+            Time::from_unix_timestamp(unix_now()?.try_into()?, 0)?,
+        )
+        .await?;
+
+        let prover = Arc::new(SP1ICS07TendermintProver::<UpdateClientProgram>::new(
+            proof_type,
+        ));
+        let mut trusted_light_block = trusted_light_block;
+        for target_light_block in light_blocks {
+            info!(
+                "Updating the client from block {} to block {}",
+                trusted_light_block.height(),
+                target_light_block.height()
+            );
+            let update_msg = prove_update(
+                prover.clone(),
+                &client_state,
+                &trusted_light_block,
+                &target_light_block,
+            )
+            .await?;
+
+            if args.dry_run {
+                update_msgs.push(update_msg);
+            } else {
+                contract
+                    .updateClient(update_msg.abi_encode().into())
+                    .send()
+                    .await?
+                    .watch()
+                    .await?;
+            }
+            trusted_light_block = target_light_block;
+        }
+
+        if args.dry_run {
+            // The contract is not updated in a dry run, so the client state is updated as the
+            // updates to the upgrade height would.
+            client_state.latestHeight.revisionHeight = upgrade_block;
+        } else {
+            client_state = contract.getClientState().call().await?._0;
+            anyhow::ensure!(
+                !client_state.isFrozen,
+                "the client was frozen while updating to the upgrade height"
+            );
+            anyhow::ensure!(
+                client_state.latestHeight.revisionHeight == upgrade_block,
+                "the client was not updated to the upgrade height {upgrade_height}"
+            );
+        }
+    }
+
+    let (upgraded_client_state, upgraded_consensus_state) =
+        fetch_upgraded_states(&tm_rpc_client, upgrade_block).await?;
+    let trusted_consensus_state = SolConsensusState::from(upgrade_light_block.to_consensus_state());
+
+    let prover = SP1ICS07TendermintProver::<UpgradeClientProgram>::new(proof_type);
+    let vkey = prover.vkey.bytes32();
+    let contract_vkey = contract.UPGRADE_CLIENT_PROGRAM_VKEY().call().await?._0;
+    anyhow::ensure!(
+        contract_vkey == vkey.parse::<B256>()?,
+        "the upgrade client program vkey of the contract ({contract_vkey}) does not match the prover ({vkey})"
+    );
+
+    info!("Generating the upgrade proof at height {upgrade_height}");
+    let now = unix_now()?;
+    let proof_specs = args.proof_specs;
+    let proof_data = tokio::task::spawn_blocking(move || {
+        prover.generate_proof(
+            &client_state,
+            &trusted_consensus_state,
+            &proof_specs,
+            upgraded_client_state,
+            upgraded_consensus_state,
+            now,
+        )
+    })
+    .await?;
+
+    let output = UpgradeClientOutput::abi_decode(proof_data.public_values.as_slice(), false)?;
+    let upgrade_msg = MsgUpgradeClient {
+        sp1Proof: SP1Proof::new(&vkey, proof_data.bytes(), proof_data.public_values.to_vec()),
+    };
+    let new_chain_id = &output.newClientState.chainId;
+    let new_height = output.newClientState.latestHeight.revisionHeight;
+
+    if args.dry_run {
+        return match simulation::simulate_upgrade_client(&contract, &update_msgs, &upgrade_msg)
+            .await?
+        {
+            Simulation::Success(()) => {
+                info!(
+                    "Dry run: the upgrade to chain {new_chain_id} at block {new_height} would succeed."
+                );
+                Ok(())
+            }
+            Simulation::Revert(revert) => {
+                Err(anyhow!("dry run: the upgrade would revert with {revert:?}"))
+            }
+        };
+    }
+
+    let tx_hash = contract
+        .upgradeClient(upgrade_msg.abi_encode().into())
+        .send()
+        .await?
+        .watch()
+        .await?;
+    info!(
+        "Upgraded the ICS-07 Tendermint light client at address {} to chain {new_chain_id} at block {new_height} in transaction {tx_hash}.",
+        contract.address()
+    );
+    Ok(())
+}
+
+/// Fetches the upgraded client and consensus states committed by the chain for the upgrade at the
+/// given height, with their merkle proofs against the app hash of the block at that height.
+async fn fetch_upgraded_states(
+    tm_rpc_client: &HttpClient,
+    upgrade_block: u32,
+) -> anyhow::Result<((Vec<u8>, MerkleProof), (Vec<u8>, MerkleProof))> {
+    let upgrade_height = upgrade_block.into();
+    let (_, client_state, client_state_proof) = fetch_kv_proof(
+        tm_rpc_client,
+        upgraded_client_state_path(upgrade_height),
+        upgrade_block,
+    )
+    .await?;
+    let (_, consensus_state, consensus_state_proof) = fetch_kv_proof(
+        tm_rpc_client,
+        upgraded_consensus_state_path(upgrade_height),
+        upgrade_block,
+    )
+    .await?;
+    anyhow::ensure!(
+        !client_state.is_empty() && !consensus_state.is_empty(),
+        "the chain did not commit the upgraded states at height {upgrade_height}"
+    );
+
+    Ok((
+        (client_state, client_state_proof),
+        (consensus_state, consensus_state_proof),
+    ))
+}
@@ -34,5 +34,29 @@ fn main() {
             elf_name: "misbehaviour-riscv32im-succinct-zkvm-elf".to_string(),
             ..Default::default()
         },
+    );
+    // Build the upgrade-client program.
+    build_program_with_args(
+        "../../programs/upgrade-client",
+        BuildArgs {
+            elf_name: "upgrade-client-riscv32im-succinct-zkvm-elf".to_string(),
+            ..Default::default()
+        },
+    );
+    // Build the multi-update-client program.
+    build_program_with_args(
+        "../../programs/multi-update-client",
+        BuildArgs {
+            elf_name: "multi-update-client-riscv32im-succinct-zkvm-elf".to_string(),
+            ..Default::default()
+        },
+    );
+    // Build the multi-membership program.
+    build_program_with_args(
+        "../../programs/multi-membership",
+        BuildArgs {
+            elf_name: "multi-membership-riscv32im-succinct-zkvm-elf".to_string(),
+            ..Default::default()
+        },
     )
 }
@@ -0,0 +1,273 @@
+//! Contains an on-disk cache of proofs, keyed by the inputs of the programs.
+//!
+//! A proof is stored under the hash of the program verifying key, the proof type and the
+//! program stdin, so that proving the same inputs twice, e.g. when a transaction failed after
+//! proving, returns the stored proof instead of proving again.
+
+use std::{
+    env, fs,
+    io::{BufReader, BufWriter},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use sha2::{Digest, Sha256};
+use sp1_sdk::{HashableKey, SP1ProofWithPublicValues, SP1Stdin, SP1VerifyingKey};
+
+use crate::prover::SupportedProofType;
+
+/// The extension of the cached proof files.
+const EXTENSION: &str = "proof";
+
+/// An on-disk cache of proofs, with size and age eviction.
+#[derive(Clone, Debug)]
+pub struct ProofCache {
+    dir: PathBuf,
+    max_size: Option<u64>,
+    max_age: Option<Duration>,
+}
+
+impl ProofCache {
+    /// Creates a cache in the given directory, creating the directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if the directory cannot be created.
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            max_size: None,
+            max_age: None,
+        })
+    }
+
+    /// Creates a cache from the `SP1_PROOF_CACHE_DIR`, `SP1_PROOF_CACHE_MAX_SIZE` (in bytes) and
+    /// `SP1_PROOF_CACHE_MAX_AGE` (in seconds) environment variables.
+    /// Returns `None` if `SP1_PROOF_CACHE_DIR` is not set or the cache cannot be created.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let dir = env::var("SP1_PROOF_CACHE_DIR").ok()?;
+        let cache = Self::new(&dir)
+            .inspect_err(|err| log::warn!("Proof cache disabled, cannot create {dir}: {err}"))
+            .ok()?;
+
+        let parse = |var: &str| {
+            env::var(var).ok().and_then(|value| {
+                value
+                    .parse::<u64>()
+                    .inspect_err(|err| log::warn!("Ignoring invalid {var}: {err}"))
+                    .ok()
+            })
+        };
+        let cache = match parse("SP1_PROOF_CACHE_MAX_SIZE") {
+            Some(max_size) => cache.with_max_size(max_size),
+            None => cache,
+        };
+        Some(match parse("SP1_PROOF_CACHE_MAX_AGE") {
+            Some(max_age) => cache.with_max_age(Duration::from_secs(max_age)),
+            None => cache,
+        })
+    }
+
+    /// Sets the maximum total size of the cached proofs in bytes.
+    /// The oldest proofs are evicted first.
+    #[must_use]
+    pub const fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Sets the maximum age of the cached proofs.
+    #[must_use]
+    pub const fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns the cache key of a proof of the given inputs.
+    ///
+    /// # Panics
+    /// Panics if the stdin cannot be serialized.
+    #[must_use]
+    pub fn key(vkey: &SP1VerifyingKey, proof_type: SupportedProofType, stdin: &SP1Stdin) -> String {
+        Self::key_of_vkey_hash(&vkey.bytes32(), proof_type, stdin)
+    }
+
+    /// Returns the cache key of a proof of the given inputs, from the `bytes32` hash of the
+    /// verifying key.
+    fn key_of_vkey_hash(
+        vkey_hash: &str,
+        proof_type: SupportedProofType,
+        stdin: &SP1Stdin,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(vkey_hash.as_bytes());
+        hasher.update([proof_type as u8]);
+        hasher.update(bincode::serialize(stdin).expect("failed to serialize stdin"));
+        hex::encode(hasher.finalize())
+    }
+
+    /// Returns the cached proof with the given key, if any.
+    /// Unreadable and expired proofs are treated as missing.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<SP1ProofWithPublicValues> {
+        let path = self.path(key);
+        if self.is_expired(&path) {
+            return None;
+        }
+
+        let file = fs::File::open(&path).ok()?;
+        bincode::deserialize_from(BufReader::new(file))
+            .inspect_err(|err| log::warn!("Ignoring unreadable cached proof {key}: {err}"))
+            .ok()
+    }
+
+    /// Stores a proof under the given key, then evicts the proofs exceeding the configured limits.
+    ///
+    /// # Errors
+    /// Returns an error if the proof cannot be written.
+    pub fn insert(&self, key: &str, proof: &SP1ProofWithPublicValues) -> bincode::Result<()> {
+        // Write to a temporary file first, so that readers never see a partial proof.
+        let tmp_path = self.dir.join(format!("{key}.tmp"));
+        bincode::serialize_into(BufWriter::new(fs::File::create(&tmp_path)?), proof)?;
+        fs::rename(&tmp_path, self.path(key))?;
+
+        self.evict();
+        Ok(())
+    }
+
+    /// Removes the expired proofs, then the oldest proofs until the total size is within the
+    /// limit.
+    fn evict(&self) {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut proofs: Vec<(PathBuf, SystemTime, u64)> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == EXTENSION))
+            .filter_map(|path| {
+                let metadata = fs::metadata(&path).ok()?;
+                Some((path, metadata.modified().ok()?, metadata.len()))
+            })
+            .collect();
+
+        proofs.retain(|(path, _, _)| {
+            let expired = self.is_expired(path);
+            if expired {
+                let _ = fs::remove_file(path);
+            }
+            !expired
+        });
+
+        if let Some(max_size) = self.max_size {
+            proofs.sort_by_key(|(_, modified, _)| *modified);
+            let mut total_size: u64 = proofs.iter().map(|(_, _, size)| size).sum();
+            for (path, _, size) in proofs {
+                if total_size <= max_size {
+                    break;
+                }
+                if fs::remove_file(&path).is_ok() {
+                    total_size -= size;
+                }
+            }
+        }
+    }
+
+    /// Returns whether the proof at the given path is older than the maximum age.
+    fn is_expired(&self, path: &Path) -> bool {
+        let Some(max_age) = self.max_age else {
+            return false;
+        };
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > max_age)
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{EXTENSION}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_sdk::SP1Stdin;
+
+    use super::ProofCache;
+    use crate::prover::SupportedProofType;
+
+    const VKEY_HASH: &str = "0x00a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f";
+
+    fn key(vkey_hash: &str, proof_type: SupportedProofType, inputs: &[&str]) -> String {
+        let mut stdin = SP1Stdin::new();
+        for input in inputs {
+            stdin.write_vec(input.as_bytes().to_vec());
+        }
+        ProofCache::key_of_vkey_hash(vkey_hash, proof_type, &stdin)
+    }
+
+    #[test]
+    fn test_key() {
+        let key_1 = key(
+            VKEY_HASH,
+            SupportedProofType::Plonk,
+            &["input 1", "input 2"],
+        );
+        assert_eq!(key_1.len(), 64);
+        assert!(key_1.chars().all(|c| c.is_ascii_hexdigit()));
+
+        // the key only depends on the inputs
+        let key_2 = key(
+            VKEY_HASH,
+            SupportedProofType::Plonk,
+            &["input 1", "input 2"],
+        );
+        assert_eq!(key_1, key_2);
+    }
+
+    #[test]
+    fn test_key_inputs() {
+        let base = key(
+            VKEY_HASH,
+            SupportedProofType::Plonk,
+            &["input 1", "input 2"],
+        );
+
+        let other_vkey_hash = VKEY_HASH.replace("0x00", "0x01");
+        let other_keys = [
+            key(
+                &other_vkey_hash,
+                SupportedProofType::Plonk,
+                &["input 1", "input 2"],
+            ),
+            key(
+                VKEY_HASH,
+                SupportedProofType::Groth16,
+                &["input 1", "input 2"],
+            ),
+            key(
+                VKEY_HASH,
+                SupportedProofType::Plonk,
+                &["input 1", "input 3"],
+            ),
+            key(
+                VKEY_HASH,
+                SupportedProofType::Plonk,
+                &["input 2", "input 1"],
+            ),
+            // the boundaries of the inputs are part of the key
+            key(VKEY_HASH, SupportedProofType::Plonk, &["input 1input 2"]),
+            key(
+                VKEY_HASH,
+                SupportedProofType::Plonk,
+                &["input 1", "input 2", ""],
+            ),
+        ];
+        for (i, other_key) in other_keys.iter().enumerate() {
+            assert_ne!(&base, other_key, "case {i}");
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![deny(clippy::nursery, clippy::pedantic, warnings, missing_docs)]
 
+pub mod cache;
 pub mod programs;
 pub mod prover;
@@ -7,6 +7,9 @@ pub trait SP1Program {
     /// The ELF file for the program.
     const ELF: &'static [u8];
 
+    /// The name of the program, used in logs and metrics.
+    const NAME: &'static str;
+
     /// Get the verifying key for the program using [`MockProver`].
     #[must_use]
     fn get_vkey() -> SP1VerifyingKey {
@@ -28,22 +31,60 @@ pub struct UpdateClientAndMembershipProgram;
 /// SP1 ICS07 Tendermint misbehaviour program.
 pub struct MisbehaviourProgram;
 
+/// SP1 ICS07 Tendermint upgrade client program.
+pub struct UpgradeClientProgram;
+
+/// SP1 ICS07 Tendermint multi-header update client program.
+pub struct MultiUpdateClientProgram;
+
+/// SP1 ICS07 Tendermint verify (non)membership at multiple heights program.
+pub struct MultiMembershipProgram;
+
 impl SP1Program for UpdateClientProgram {
     const ELF: &'static [u8] =
         include_bytes!("../../../elf/update-client-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "update-client";
 }
 
 impl SP1Program for MembershipProgram {
     const ELF: &'static [u8] =
         include_bytes!("../../../elf/membership-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "membership";
 }
 
 impl SP1Program for UpdateClientAndMembershipProgram {
     const ELF: &'static [u8] =
         include_bytes!("../../../elf/uc-and-membership-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "uc-and-membership";
 }
 
 impl SP1Program for MisbehaviourProgram {
     const ELF: &'static [u8] =
         include_bytes!("../../../elf/misbehaviour-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "misbehaviour";
+}
+
+impl SP1Program for UpgradeClientProgram {
+    const ELF: &'static [u8] =
+        include_bytes!("../../../elf/upgrade-client-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "upgrade-client";
+}
+
+impl SP1Program for MultiUpdateClientProgram {
+    const ELF: &'static [u8] =
+        include_bytes!("../../../elf/multi-update-client-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "multi-update-client";
+}
+
+impl SP1Program for MultiMembershipProgram {
+    const ELF: &'static [u8] =
+        include_bytes!("../../../elf/multi-membership-riscv32im-succinct-zkvm-elf");
+
+    const NAME: &'static str = "multi-membership";
 }
@@ -1,17 +1,26 @@
 //! Prover for SP1 ICS07 Tendermint programs.
 
+use crate::cache::ProofCache;
 use crate::programs::{
-    MembershipProgram, MisbehaviourProgram, SP1Program, UpdateClientAndMembershipProgram,
-    UpdateClientProgram,
+    MembershipProgram, MisbehaviourProgram, MultiMembershipProgram, MultiUpdateClientProgram,
+    SP1Program, UpdateClientAndMembershipProgram, UpdateClientProgram, UpgradeClientProgram,
 };
 use ibc_client_tendermint_types::{Header, Misbehaviour};
-use ibc_core_commitment_types::merkle::MerkleProof;
+use ibc_core_commitment_types::{merkle::MerkleProof, specs::ProofSpecs};
 use ibc_proto::Protobuf;
 use sp1_ics07_tendermint_solidity::{
+    encode_proof_specs,
+    IICS02ClientMsgs::Height as SolHeight,
     IICS07TendermintMsgs::{ClientState as SolClientState, ConsensusState as SolConsensusState},
     ISP1Msgs::SupportedZkAlgorithm,
 };
-use sp1_sdk::{ProverClient, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey};
+use sp1_sdk::{
+    HashableKey, ProverClient, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey,
+};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 /// A prover for for [`SP1Program`] programs.
 #[allow(clippy::module_name_repetitions)]
@@ -24,9 +33,35 @@ pub struct SP1ICS07TendermintProver<T: SP1Program> {
     pub vkey: SP1VerifyingKey,
     /// The proof type.
     pub proof_type: SupportedProofType,
+    /// Called after every generated proof.
+    observer: Option<ProofObserver>,
+    /// The cache of generated proofs.
+    cache: Option<ProofCache>,
     _phantom: std::marker::PhantomData<T>,
 }
 
+/// An event emitted by the prover after a proof is generated.
+#[derive(Clone, Debug)]
+pub struct ProofEvent {
+    /// The name of the program.
+    pub program: &'static str,
+    /// The proof type.
+    pub proof_type: SupportedProofType,
+    /// The time it took to generate and verify the proof.
+    pub duration: Duration,
+}
+
+/// A callback that is called with every [`ProofEvent`].
+pub type ProofObserver = Arc<dyn Fn(&ProofEvent) + Send + Sync>;
+
+/// A group of key-value pairs and their proofs against the root of the trusted consensus state at
+/// the height, as proven by [`MultiMembershipProgram`].
+pub type MembershipRequest = (
+    SolHeight,
+    SolConsensusState,
+    Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
+);
+
 /// The supported proof types.
 #[derive(Clone, Debug, Copy)]
 pub enum SupportedProofType {
@@ -38,6 +73,8 @@ pub enum SupportedProofType {
 
 impl<T: SP1Program> SP1ICS07TendermintProver<T> {
     /// Create a new prover.
+    /// The proof cache is enabled if the `SP1_PROOF_CACHE_DIR` environment variable is set, see
+    /// [`ProofCache::from_env`].
     #[must_use]
     pub fn new(proof_type: SupportedProofType) -> Self {
         log::info!("Initializing SP1 ProverClient...");
@@ -49,15 +86,41 @@ impl<T: SP1Program> SP1ICS07TendermintProver<T> {
             pkey,
             vkey,
             proof_type,
+            observer: None,
+            cache: ProofCache::from_env(),
             _phantom: std::marker::PhantomData,
         }
     }
 
-    /// Prove the given input.
+    /// Sets a callback that is called after every generated proof.
+    #[must_use]
+    pub fn with_observer(mut self, observer: ProofObserver) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Sets the cache of generated proofs, replacing the one configured from the environment.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Option<ProofCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Prove the given input, or return the cached proof of the same input if there is one.
     /// # Panics
     /// If the proof cannot be generated or validated.
     #[must_use]
     pub fn prove(&self, stdin: SP1Stdin) -> SP1ProofWithPublicValues {
+        let cache_key = self
+            .cache
+            .as_ref()
+            .map(|_| ProofCache::key(&self.vkey, self.proof_type, &stdin));
+        if let Some(proof) = self.cached_proof(cache_key.as_deref()) {
+            return proof;
+        }
+
+        let start = Instant::now();
+
         // Generate the proof. Depending on SP1_PROVER env variable, this may be a mock, local or
         // network proof.
         let proof = match self.proof_type {
@@ -79,8 +142,38 @@ impl<T: SP1Program> SP1ICS07TendermintProver<T> {
             .verify(&proof, &self.vkey)
             .expect("verification failed");
 
+        if let Some(observer) = &self.observer {
+            observer(&ProofEvent {
+                program: T::NAME,
+                proof_type: self.proof_type,
+                duration: start.elapsed(),
+            });
+        }
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Err(err) = cache.insert(key, &proof) {
+                log::warn!("Failed to cache the {} proof: {err}", T::NAME);
+            }
+        }
+
         proof
     }
+
+    /// Returns the cached proof with the given key if it is valid for this prover.
+    fn cached_proof(&self, key: Option<&str>) -> Option<SP1ProofWithPublicValues> {
+        let proof = self.cache.as_ref()?.get(key?)?;
+        if let Err(err) = self.prover_client.verify(&proof, &self.vkey) {
+            log::warn!("Ignoring an invalid cached {} proof: {err}", T::NAME);
+            return None;
+        }
+
+        log::info!(
+            "Reusing a cached {} proof for vkey {}",
+            T::NAME,
+            self.vkey.bytes32()
+        );
+        Some(proof)
+    }
 }
 
 impl SP1ICS07TendermintProver<UpdateClientProgram> {
@@ -116,7 +209,9 @@ impl SP1ICS07TendermintProver<UpdateClientProgram> {
 }
 
 impl SP1ICS07TendermintProver<MembershipProgram> {
-    /// Generate a proof of verify (non)membership for multiple key-value pairs.
+    /// Generate a proof of verify (non)membership for multiple key-value pairs, verified with the
+    /// given ICS-23 proof specs. If `hashed_values` is set, the proof commits the keccak256 hashes
+    /// of the values instead of the values themselves.
     ///
     /// # Panics
     /// Panics if the proof cannot be generated or the proof is invalid.
@@ -124,6 +219,8 @@ impl SP1ICS07TendermintProver<MembershipProgram> {
     pub fn generate_proof(
         &self,
         commitment_root: &[u8],
+        proof_specs: &ProofSpecs,
+        hashed_values: bool,
         kv_proofs: Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
     ) -> SP1ProofWithPublicValues {
         assert!(!kv_proofs.is_empty(), "No key-value pairs to prove");
@@ -131,6 +228,8 @@ impl SP1ICS07TendermintProver<MembershipProgram> {
 
         let mut stdin = SP1Stdin::new();
         stdin.write_slice(commitment_root);
+        stdin.write_vec(encode_proof_specs(proof_specs));
+        stdin.write_vec(vec![u8::from(hashed_values)]);
         stdin.write_vec(vec![len]);
         for (path, value, proof) in kv_proofs {
             stdin.write_vec(bincode::serialize(&path).unwrap());
@@ -142,10 +241,56 @@ impl SP1ICS07TendermintProver<MembershipProgram> {
     }
 }
 
+impl SP1ICS07TendermintProver<MultiMembershipProgram> {
+    /// Generate a proof of verify (non)membership for groups of key-value pairs, each against the
+    /// root of the trusted consensus state at its height, verified with the given ICS-23 proof
+    /// specs. The groups must be ordered by strictly increasing height. If `hashed_values` is set,
+    /// the proof commits the keccak256 hashes of the values instead of the values themselves.
+    ///
+    /// # Panics
+    /// Panics if there are no groups or too many of them, if a group has no or too many key-value
+    /// pairs, if the inputs cannot be encoded, the proof cannot be generated or the proof is
+    /// invalid.
+    #[must_use]
+    pub fn generate_proof(
+        &self,
+        proof_specs: &ProofSpecs,
+        hashed_values: bool,
+        groups: Vec<MembershipRequest>,
+    ) -> SP1ProofWithPublicValues {
+        assert!(!groups.is_empty(), "No key-value pairs to prove");
+        let groups_len = u8::try_from(groups.len()).expect("too many heights");
+
+        let mut stdin = SP1Stdin::new();
+        stdin.write_vec(encode_proof_specs(proof_specs));
+        stdin.write_vec(vec![u8::from(hashed_values)]);
+        stdin.write_vec(vec![groups_len]);
+        for (height, trusted_consensus_state, kv_proofs) in groups {
+            assert!(
+                !kv_proofs.is_empty(),
+                "No key-value pairs to prove at {height:?}"
+            );
+            let len = u8::try_from(kv_proofs.len()).expect("too many key-value pairs");
+
+            stdin.write_vec(bincode::serialize(&height).unwrap());
+            stdin.write_vec(bincode::serialize(&trusted_consensus_state).unwrap());
+            stdin.write_vec(vec![len]);
+            for (path, value, proof) in kv_proofs {
+                stdin.write_vec(bincode::serialize(&path).unwrap());
+                stdin.write_vec(value);
+                stdin.write_vec(proof.encode_vec());
+            }
+        }
+
+        self.prove(stdin)
+    }
+}
+
 impl SP1ICS07TendermintProver<UpdateClientAndMembershipProgram> {
     /// Generate a proof of an update from `trusted_consensus_state` to a proposed header and
     /// verify (non)membership for multiple key-value pairs on the commitment root of
-    /// `proposed_header`.
+    /// `proposed_header`, verified with the ICS-23 proof specs of the client. If `hashed_values` is
+    /// set, the proof commits the keccak256 hashes of the values instead of the values themselves.
     ///
     /// # Panics
     /// Panics if the inputs cannot be encoded, the proof cannot be generated or the proof is
@@ -157,6 +302,8 @@ impl SP1ICS07TendermintProver<UpdateClientAndMembershipProgram> {
         trusted_consensus_state: &SolConsensusState,
         proposed_header: &Header,
         time: u64,
+        proof_specs: &ProofSpecs,
+        hashed_values: bool,
         kv_proofs: Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
     ) -> SP1ProofWithPublicValues {
         assert!(!kv_proofs.is_empty(), "No key-value pairs to prove");
@@ -175,6 +322,8 @@ impl SP1ICS07TendermintProver<UpdateClientAndMembershipProgram> {
         stdin.write_vec(encoded_2);
         stdin.write_vec(encoded_3);
         stdin.write_vec(encoded_4);
+        stdin.write_vec(encode_proof_specs(proof_specs));
+        stdin.write_vec(vec![u8::from(hashed_values)]);
         stdin.write_vec(vec![len]);
         for (path, value, proof) in kv_proofs {
             stdin.write_vec(bincode::serialize(&path).unwrap());
@@ -217,6 +366,78 @@ impl SP1ICS07TendermintProver<MisbehaviourProgram> {
     }
 }
 
+impl SP1ICS07TendermintProver<UpgradeClientProgram> {
+    /// Generate a proof of an upgrade of the client, from the upgraded client and consensus
+    /// states committed by the chain and their merkle proofs against the root of
+    /// `trusted_consensus_state`, the consensus state at the upgrade height, verified with
+    /// `proof_specs`.
+    ///
+    /// # Panics
+    /// Panics if the inputs cannot be encoded, the proof cannot be generated or the proof is
+    /// invalid.
+    #[must_use]
+    pub fn generate_proof(
+        &self,
+        client_state: &SolClientState,
+        trusted_consensus_state: &SolConsensusState,
+        proof_specs: &ProofSpecs,
+        (upgraded_client_state, proof_upgrade_client): (Vec<u8>, MerkleProof),
+        (upgraded_consensus_state, proof_upgrade_consensus_state): (Vec<u8>, MerkleProof),
+        time: u64,
+    ) -> SP1ProofWithPublicValues {
+        let encoded_1 = bincode::serialize(client_state).unwrap();
+        let encoded_2 = bincode::serialize(trusted_consensus_state).unwrap();
+        let encoded_8 = time.to_le_bytes().into();
+
+        let mut stdin = SP1Stdin::new();
+        stdin.write_vec(encoded_1);
+        stdin.write_vec(encoded_2);
+        stdin.write_vec(encode_proof_specs(proof_specs));
+        stdin.write_vec(upgraded_client_state);
+        stdin.write_vec(upgraded_consensus_state);
+        stdin.write_vec(proof_upgrade_client.encode_vec());
+        stdin.write_vec(proof_upgrade_consensus_state.encode_vec());
+        stdin.write_vec(encoded_8);
+
+        self.prove(stdin)
+    }
+}
+
+impl SP1ICS07TendermintProver<MultiUpdateClientProgram> {
+    /// Generate a proof of an update from `trusted_consensus_state` through the proposed headers,
+    /// in order, where each header is trusted from the height of the previous one.
+    ///
+    /// # Panics
+    /// Panics if there are no proposed headers or too many of them, if the inputs cannot be
+    /// encoded, the proof cannot be generated or the proof is invalid.
+    #[must_use]
+    pub fn generate_proof(
+        &self,
+        client_state: &SolClientState,
+        trusted_consensus_state: &SolConsensusState,
+        proposed_headers: &[Header],
+        time: u64,
+    ) -> SP1ProofWithPublicValues {
+        assert!(!proposed_headers.is_empty(), "No headers to prove");
+        let len = u8::try_from(proposed_headers.len()).expect("too many headers");
+        let encoded_1 = bincode::serialize(client_state).unwrap();
+        let encoded_2 = bincode::serialize(trusted_consensus_state).unwrap();
+        let encoded_3 = time.to_le_bytes().into();
+
+        let mut stdin = SP1Stdin::new();
+        stdin.write_vec(encoded_1);
+        stdin.write_vec(encoded_2);
+        stdin.write_vec(encoded_3);
+        stdin.write_vec(vec![len]);
+        for proposed_header in proposed_headers {
+            // NOTE: The Header struct is not deserializable by bincode, so we use CBOR instead.
+            stdin.write_vec(serde_cbor::to_vec(proposed_header).unwrap());
+        }
+
+        self.prove(stdin)
+    }
+}
+
 impl From<SupportedProofType> for SupportedZkAlgorithm {
     fn from(proof_type: SupportedProofType) -> Self {
         match proof_type {
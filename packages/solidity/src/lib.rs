@@ -1,9 +1,15 @@
 #![doc = include_str!("../README.md")]
 #![deny(clippy::nursery, clippy::pedantic, warnings)]
 
+use core::cmp::Ordering;
+
 use alloy_sol_types::SolValue;
 use ibc_client_tendermint_types::ConsensusState as ICS07TendermintConsensusState;
-use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_commitment_types::{
+    commitment::CommitmentRoot, proto::ics23::ProofSpec as RawProofSpec, specs::ProofSpecs,
+};
+use prost::Message;
+use sha2::{Digest, Sha256};
 use tendermint::{hash::Algorithm, Time};
 use tendermint_light_client_verifier::types::{Hash, TrustThreshold as TendermintTrustThreshold};
 use time::OffsetDateTime;
@@ -129,6 +135,15 @@ impl From<IMembershipMsgs::SP1MembershipAndUpdateClientProof> for IMembershipMsg
     }
 }
 
+impl From<IMembershipMsgs::SP1MultiMembershipProof> for IMembershipMsgs::MembershipProof {
+    fn from(proof: IMembershipMsgs::SP1MultiMembershipProof) -> Self {
+        Self {
+            proofType: 2,
+            proof: proof.abi_encode().into(),
+        }
+    }
+}
+
 impl TryFrom<ibc_core_client_types::Height> for IICS02ClientMsgs::Height {
     type Error = <u64 as TryInto<u32>>::Error;
 
@@ -139,3 +154,198 @@ impl TryFrom<ibc_core_client_types::Height> for IICS02ClientMsgs::Height {
         })
     }
 }
+
+impl PartialOrd for IICS02ClientMsgs::Height {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Heights are ordered by revision number and then by revision height, as in ICS-02.
+impl Ord for IICS02ClientMsgs::Height {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.revisionNumber, self.revisionHeight)
+            .cmp(&(other.revisionNumber, other.revisionHeight))
+    }
+}
+
+/// Heights are displayed as `{revision_number}-{revision_height}`, as in ICS-02.
+impl core::fmt::Display for IICS02ClientMsgs::Height {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}-{}", self.revisionNumber, self.revisionHeight)
+    }
+}
+
+/// The result of an update client call, as returned by `updateClient`.
+/// Mirrors the `ILightClientMsgs.UpdateResult` enum of the contracts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateResult {
+    /// The client was updated.
+    Update,
+    /// Misbehaviour was detected and the client was frozen.
+    Misbehaviour,
+    /// The consensus state already existed, nothing was changed.
+    NoOp,
+}
+
+impl TryFrom<u8> for UpdateResult {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Update),
+            1 => Ok(Self::Misbehaviour),
+            2 => Ok(Self::NoOp),
+            n => Err(format!("Unknown update result: {n}")),
+        }
+    }
+}
+
+impl core::fmt::Display for UpdateResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Update => write!(f, "Update"),
+            Self::Misbehaviour => write!(f, "Misbehaviour"),
+            Self::NoOp => write!(f, "NoOp"),
+        }
+    }
+}
+
+/// Splits a chain identifier into its name and revision number, e.g. `("foo", 1)` for `foo-1`.
+/// A chain identifier without a revision, e.g. `foo`, is revision 0 of the chain `foo`.
+///
+/// The revision is the digits after the last dash, without a leading zero, e.g. `foo-01` is
+/// revision 0 of the chain `foo-01`. The contract parses chain identifiers the same way in
+/// `Heights.withRevision`.
+#[must_use]
+pub fn split_chain_id(chain_id: &str) -> (&str, u64) {
+    chain_id
+        .rsplit_once('-')
+        .filter(|(_, revision)| {
+            !revision.is_empty()
+                && revision.bytes().all(|b| b.is_ascii_digit())
+                && (revision.len() == 1 || !revision.starts_with('0'))
+        })
+        .and_then(|(name, revision)| Some((name, revision.parse().ok()?)))
+        .unwrap_or((chain_id, 0))
+}
+
+/// Returns the chain identifier that a header of `header_chain_id` is verified against by a
+/// client of `client_chain_id`.
+///
+/// This is the chain identifier of the header if it is a later revision of the chain of the
+/// client, e.g. `foo-2` for a client of `foo-1`, in which case the contract follows the chain to
+/// the new revision. Otherwise, it is the chain identifier of the client.
+#[must_use]
+pub fn revision_chain_id<'a>(client_chain_id: &'a str, header_chain_id: &'a str) -> &'a str {
+    let (client_name, client_revision) = split_chain_id(client_chain_id);
+    let (header_name, header_revision) = split_chain_id(header_chain_id);
+    if header_name == client_name && header_revision > client_revision {
+        header_chain_id
+    } else {
+        client_chain_id
+    }
+}
+
+/// The key of the upgrade store under which the upgraded IBC states are committed, as in the
+/// default upgrade path of ICS-07 Tendermint clients.
+pub const UPGRADED_IBC_STATE_KEY: &str = "upgradedIBCState";
+
+/// Returns the merkle path of the upgraded client state committed by the chain for an upgrade at
+/// `upgrade_height`, i.e. `upgradedIBCState/{upgrade_height}/upgradedClient` in the `upgrade`
+/// store.
+#[must_use]
+pub fn upgraded_client_state_path(upgrade_height: u64) -> Vec<Vec<u8>> {
+    upgrade_path(upgrade_height, "upgradedClient")
+}
+
+/// Returns the merkle path of the upgraded consensus state committed by the chain for an upgrade
+/// at `upgrade_height`, i.e. `upgradedIBCState/{upgrade_height}/upgradedConsState` in the
+/// `upgrade` store.
+#[must_use]
+pub fn upgraded_consensus_state_path(upgrade_height: u64) -> Vec<Vec<u8>> {
+    upgrade_path(upgrade_height, "upgradedConsState")
+}
+
+fn upgrade_path(upgrade_height: u64, key: &str) -> Vec<Vec<u8>> {
+    vec![
+        b"upgrade".to_vec(),
+        format!("{UPGRADED_IBC_STATE_KEY}/{upgrade_height}/{key}").into_bytes(),
+    ]
+}
+
+/// Encodes ICS-23 proof specs as the concatenation of their length-delimited protobuf
+/// encodings, which is how the membership programs take them as input.
+#[must_use]
+pub fn encode_proof_specs(proof_specs: &ProofSpecs) -> Vec<u8> {
+    Vec::<RawProofSpec>::from(proof_specs.clone())
+        .iter()
+        .flat_map(Message::encode_length_delimited_to_vec)
+        .collect()
+}
+
+/// Decodes ICS-23 proof specs encoded with [`encode_proof_specs`].
+///
+/// # Errors
+/// Returns an error if a proof spec cannot be decoded or the proof specs are invalid.
+pub fn decode_proof_specs(mut encoded: &[u8]) -> Result<ProofSpecs, String> {
+    let mut proof_specs = Vec::new();
+    while !encoded.is_empty() {
+        proof_specs
+            .push(RawProofSpec::decode_length_delimited(&mut encoded).map_err(|e| e.to_string())?);
+    }
+    ProofSpecs::try_from(proof_specs).map_err(|e| e.to_string())
+}
+
+/// Returns the hash of ICS-23 proof specs committed in the client state, i.e. the sha256 hash of
+/// their encoding with [`encode_proof_specs`].
+#[must_use]
+pub fn proof_specs_hash(proof_specs: &ProofSpecs) -> [u8; 32] {
+    Sha256::digest(encode_proof_specs(proof_specs)).into()
+}
+
+/// Returns the value of a key-value pair as committed by the membership programs, i.e. the
+/// keccak256 hash of the value if the values are hashed. The value of a non-membership proof is
+/// empty, so it is never hashed.
+#[must_use]
+pub fn committed_kv_value(value: Vec<u8>, hashed_values: bool) -> Vec<u8> {
+    if hashed_values && !value.is_empty() {
+        alloy_primitives::keccak256(value).to_vec()
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{revision_chain_id, split_chain_id};
+
+    #[test]
+    fn test_split_chain_id() {
+        assert_eq!(split_chain_id("mocha-4"), ("mocha", 4));
+        assert_eq!(split_chain_id("mocha"), ("mocha", 0));
+        assert_eq!(split_chain_id("mocha-0"), ("mocha", 0));
+        assert_eq!(split_chain_id("mocha-04"), ("mocha-04", 0));
+        assert_eq!(split_chain_id("mocha-"), ("mocha-", 0));
+        assert_eq!(split_chain_id("mocha-4a"), ("mocha-4a", 0));
+        assert_eq!(split_chain_id("test-chain-12"), ("test-chain", 12));
+        assert_eq!(
+            split_chain_id("mocha-18446744073709551615"),
+            ("mocha", u64::MAX)
+        );
+        assert_eq!(
+            split_chain_id("mocha-18446744073709551616"),
+            ("mocha-18446744073709551616", 0)
+        );
+    }
+
+    #[test]
+    fn test_revision_chain_id() {
+        assert_eq!(revision_chain_id("mocha-4", "mocha-5"), "mocha-5");
+        assert_eq!(revision_chain_id("mocha-4", "mocha-4"), "mocha-4");
+        assert_eq!(revision_chain_id("mocha-4", "mocha-3"), "mocha-4");
+        assert_eq!(revision_chain_id("mocha-4", "other-5"), "mocha-4");
+        assert_eq!(revision_chain_id("mocha-04", "mocha-5"), "mocha-04");
+        assert_eq!(revision_chain_id("mocha-04", "mocha-04-1"), "mocha-04-1");
+    }
+}
@@ -1,21 +1,244 @@
 //! Helpers for interacting with EVM.
 
-use std::env;
+use std::{env, fmt, path::Path};
 
-use alloy::{network::EthereumWallet, signers::local::PrivateKeySigner};
+use alloy::{
+    consensus::{SignableTransaction, TxEnvelope},
+    eips::eip2718::Decodable2718,
+    network::{EthereumWallet, TxSigner},
+    primitives::{Address, Bytes, PrimitiveSignature},
+    rpc::{
+        client::RpcClient,
+        types::{TransactionInput, TransactionRequest},
+    },
+    signers::local::{coins_bip39::English, LocalSignerError, MnemonicBuilder, PrivateKeySigner},
+    transports::http::{Client, Http},
+};
+use serde::Deserialize;
+
+/// The default derivation path of mnemonics, i.e. the first account of the Ethereum path.
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// An error raised while loading a signer.
+#[derive(Debug)]
+pub enum SignerError {
+    /// An environment variable is not set.
+    MissingEnvVar(String),
+    /// A file could not be read.
+    Io {
+        /// The path of the file.
+        path: String,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// A private key is invalid.
+    InvalidPrivateKey(LocalSignerError),
+    /// A keystore could not be decrypted, e.g. because of a wrong password.
+    Keystore(LocalSignerError),
+    /// A mnemonic or its derivation path is invalid.
+    Mnemonic(LocalSignerError),
+    /// The URL of a remote signer is invalid.
+    InvalidUrl(String),
+}
+
+/// A signer that delegates signing to a remote JSON-RPC service through `eth_signTransaction`,
+/// e.g. Web3Signer, or Anvil with an unlocked account as a local stand-in.
+/// The private key never leaves the remote service.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: RpcClient<Http<Client>>,
+    address: Address,
+}
+
+/// The response of `eth_signTransaction`, which is either the raw signed transaction or an object
+/// containing it, depending on the signer.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SignTransactionResponse {
+    Raw(Bytes),
+    Object { raw: Bytes },
+}
 
 /// Create an Ethereum wallet from the `PRIVATE_KEY` environment variable.
 ///
-/// # Panics
-/// Panics if the `PRIVATE_KEY` environment variable is not set.
-/// Panics if the `PRIVATE_KEY` environment variable is not a valid private key.
-#[must_use]
-pub fn wallet_from_env() -> EthereumWallet {
-    let mut private_key = env::var("PRIVATE_KEY").expect("PRIVATE_KEY not set");
-    if let Some(stripped) = private_key.strip_prefix("0x") {
-        private_key = stripped.to_string();
+/// # Errors
+/// Returns an error if the `PRIVATE_KEY` environment variable is not set or is not a valid
+/// private key.
+pub fn wallet_from_env() -> Result<EthereumWallet, SignerError> {
+    wallet_from_env_var("PRIVATE_KEY")
+}
+
+/// Create an Ethereum wallet from the hex encoded private key in the given environment variable.
+///
+/// # Errors
+/// Returns an error if the environment variable is not set or is not a valid private key.
+pub fn wallet_from_env_var(var: &str) -> Result<EthereumWallet, SignerError> {
+    let private_key = read_env_var(var)?;
+    let signer: PrivateKeySigner = private_key
+        .strip_prefix("0x")
+        .unwrap_or(&private_key)
+        .parse()
+        .map_err(SignerError::InvalidPrivateKey)?;
+    Ok(EthereumWallet::from(signer))
+}
+
+/// Create an Ethereum wallet from an encrypted JSON keystore, with the password read from a file.
+/// Trailing newlines of the password file are ignored.
+///
+/// # Errors
+/// Returns an error if a file cannot be read or if the keystore cannot be decrypted.
+pub fn wallet_from_keystore(
+    keystore_path: impl AsRef<Path>,
+    password_file: impl AsRef<Path>,
+) -> Result<EthereumWallet, SignerError> {
+    let password = read_file(password_file.as_ref())?;
+    let signer = PrivateKeySigner::decrypt_keystore(
+        keystore_path.as_ref(),
+        password.trim_end_matches(['\r', '\n']),
+    )
+    .map_err(SignerError::Keystore)?;
+    Ok(EthereumWallet::from(signer))
+}
+
+/// Create an Ethereum wallet from the BIP-39 mnemonic in the given environment variable, using
+/// the given derivation path, e.g. [`DEFAULT_DERIVATION_PATH`].
+///
+/// # Errors
+/// Returns an error if the environment variable is not set, or if the mnemonic or the derivation
+/// path is invalid.
+pub fn wallet_from_mnemonic_env_var(
+    var: &str,
+    derivation_path: &str,
+) -> Result<EthereumWallet, SignerError> {
+    let phrase = read_env_var(var)?;
+    let signer = MnemonicBuilder::<English>::default()
+        .phrase(phrase.trim())
+        .derivation_path(derivation_path)
+        .and_then(|builder| builder.build())
+        .map_err(SignerError::Mnemonic)?;
+    Ok(EthereumWallet::from(signer))
+}
+
+/// Create an Ethereum wallet that signs with the given account of a remote signer.
+///
+/// # Errors
+/// Returns an error if the URL is invalid.
+pub fn wallet_from_remote_signer(
+    url: &str,
+    address: Address,
+) -> Result<EthereumWallet, SignerError> {
+    Ok(EthereumWallet::from(RemoteSigner::new(url, address)?))
+}
+
+impl RemoteSigner {
+    /// Creates a remote signer for the given account of the JSON-RPC service at the given URL.
+    ///
+    /// # Errors
+    /// Returns an error if the URL is invalid.
+    pub fn new(url: &str, address: Address) -> Result<Self, SignerError> {
+        let url = url
+            .parse()
+            .map_err(|_| SignerError::InvalidUrl(url.to_string()))?;
+        Ok(Self {
+            client: RpcClient::new_http(url),
+            address,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TxSigner<PrimitiveSignature> for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<PrimitiveSignature>,
+    ) -> alloy::signers::Result<PrimitiveSignature> {
+        let request = TransactionRequest {
+            from: Some(self.address),
+            to: Some(tx.kind()),
+            gas: Some(tx.gas_limit()),
+            gas_price: tx.gas_price(),
+            max_fee_per_gas: tx.gas_price().is_none().then(|| tx.max_fee_per_gas()),
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas(),
+            value: Some(tx.value()),
+            input: TransactionInput::new(tx.input().clone()),
+            nonce: Some(tx.nonce()),
+            chain_id: tx.chain_id(),
+            access_list: tx.access_list().cloned(),
+            transaction_type: Some(tx.ty()),
+            ..Default::default()
+        };
+
+        let (SignTransactionResponse::Raw(raw) | SignTransactionResponse::Object { raw }) = self
+            .client
+            .request::<_, SignTransactionResponse>("eth_signTransaction", (request,))
+            .await
+            .map_err(alloy::signers::Error::other)?;
+
+        let signature = match TxEnvelope::decode_2718(&mut raw.as_ref())
+            .map_err(alloy::signers::Error::other)?
+        {
+            TxEnvelope::Legacy(signed) => *signed.signature(),
+            TxEnvelope::Eip2930(signed) => *signed.signature(),
+            TxEnvelope::Eip1559(signed) => *signed.signature(),
+            TxEnvelope::Eip4844(signed) => *signed.signature(),
+            TxEnvelope::Eip7702(signed) => *signed.signature(),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(alloy::signers::Error::other(
+                    "unsupported transaction type returned by the remote signer",
+                ))
+            }
+        };
+
+        // The remote signer may fill or change fields of the transaction, so the signature is
+        // only accepted if it signs exactly the transaction that was requested.
+        let signer = signature
+            .recover_address_from_prehash(&tx.signature_hash())
+            .map_err(alloy::signers::Error::other)?;
+        if signer != self.address {
+            return Err(alloy::signers::Error::other(
+                "the remote signer did not sign the requested transaction",
+            ));
+        }
+
+        Ok(signature)
     }
+}
+
+impl fmt::Display for SignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEnvVar(var) => write!(f, "{var} not set"),
+            Self::Io { path, source } => write!(f, "failed to read {path}: {source}"),
+            Self::InvalidPrivateKey(err) => write!(f, "invalid private key: {err}"),
+            Self::Keystore(err) => write!(f, "failed to decrypt the keystore: {err}"),
+            Self::Mnemonic(err) => write!(f, "invalid mnemonic: {err}"),
+            Self::InvalidUrl(url) => write!(f, "invalid remote signer URL: {url}"),
+        }
+    }
+}
+
+impl std::error::Error for SignerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::InvalidPrivateKey(err) | Self::Keystore(err) | Self::Mnemonic(err) => Some(err),
+            Self::MissingEnvVar(_) | Self::InvalidUrl(_) => None,
+        }
+    }
+}
+
+fn read_env_var(var: &str) -> Result<String, SignerError> {
+    env::var(var).map_err(|_| SignerError::MissingEnvVar(var.to_string()))
+}
 
-    let signer: PrivateKeySigner = private_key.parse().unwrap();
-    EthereumWallet::from(signer)
+fn read_file(path: &Path) -> Result<String, SignerError> {
+    std::fs::read_to_string(path).map_err(|source| SignerError::Io {
+        path: path.display().to_string(),
+        source,
+    })
 }
@@ -0,0 +1,173 @@
+//! This module defines how the key paths of (non)membership proofs are parsed, built under a
+//! commitment prefix, and queried from the chain.
+
+use std::{convert::Infallible, str, str::FromStr};
+
+/// The commitment prefix of the `ibc` store of the Cosmos SDK, in the format of
+/// [`CommitmentPrefix::from_str`].
+pub const DEFAULT_COMMITMENT_PREFIX: &str = "ibc\\";
+
+/// The separator of the segments of a key path or commitment prefix.
+const SEGMENT_SEPARATOR: char = '\\';
+
+/// A commitment prefix, i.e. the merkle path segments that the keys of a chain are committed
+/// under. As in IBC v2, a key is appended to the last segment of the prefix, so the prefix of the
+/// `ibc` store of the Cosmos SDK is `["ibc", ""]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentPrefix(Vec<Vec<u8>>);
+
+impl CommitmentPrefix {
+    /// Returns the merkle path of the key under the prefix.
+    #[must_use]
+    pub fn apply(&self, key: &[u8]) -> Vec<Vec<u8>> {
+        let mut path = self.0.clone();
+        if let Some(last) = path.last_mut() {
+            last.extend_from_slice(key);
+        }
+        path
+    }
+}
+
+impl FromStr for CommitmentPrefix {
+    type Err = Infallible;
+
+    /// Parses a commitment prefix from its segments separated by a backslash, e.g. `ibc\` for
+    /// `["ibc", ""]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split(SEGMENT_SEPARATOR)
+                .map(|segment| segment.as_bytes().to_vec())
+                .collect(),
+        ))
+    }
+}
+
+impl Default for CommitmentPrefix {
+    fn default() -> Self {
+        Self(vec![b"ibc".to_vec(), Vec::new()])
+    }
+}
+
+/// Parses a key path into a merkle path.
+///
+/// If `is_base64` is set, the key path is the full merkle path, i.e. base64 encoded segments
+/// separated by a backslash, e.g. `aWJj\a2V5` for `["ibc", "key"]`. Otherwise, it is a key under
+/// the commitment prefix.
+///
+/// # Errors
+/// Returns an error if a segment is not valid base64, or if the merkle path has less than two
+/// segments.
+pub fn parse_key_path(
+    key_path: &str,
+    is_base64: bool,
+    prefix: &CommitmentPrefix,
+) -> anyhow::Result<Vec<Vec<u8>>> {
+    let path = if is_base64 {
+        key_path
+            .split(SEGMENT_SEPARATOR)
+            .map(subtle_encoding::base64::decode)
+            .collect::<Result<_, _>>()?
+    } else {
+        prefix.apply(key_path.as_bytes())
+    };
+    anyhow::ensure!(
+        path.len() >= 2,
+        "the merkle path of '{key_path}' must have at least two segments"
+    );
+    Ok(path)
+}
+
+/// Returns the ABCI query path and data of the value of a merkle path, i.e. `store/{store}/key`
+/// and the key.
+///
+/// The multistore of the Cosmos SDK only routes queries to its top-level stores, so only merkle
+/// paths of a store and a key, e.g. under the `ibc` store, can be queried.
+///
+/// # Errors
+/// Returns an error if the merkle path does not have exactly two segments, or if the store
+/// segment is not valid UTF-8.
+pub fn abci_query_path(path: &[Vec<u8>]) -> anyhow::Result<(String, Vec<u8>)> {
+    let [store, key] = path else {
+        anyhow::bail!(
+            "the merkle path must have exactly two segments, a store and a key, but it has {}",
+            path.len()
+        );
+    };
+    let store = str::from_utf8(store)?;
+
+    Ok((format!("store/{store}/key"), key.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{abci_query_path, parse_key_path, CommitmentPrefix, DEFAULT_COMMITMENT_PREFIX};
+
+    fn prefix(s: &str) -> CommitmentPrefix {
+        s.parse().unwrap()
+    }
+
+    fn segments(segments: &[&str]) -> Vec<Vec<u8>> {
+        segments.iter().map(|s| s.as_bytes().to_vec()).collect()
+    }
+
+    #[test]
+    fn test_commitment_prefix() {
+        assert_eq!(
+            prefix(DEFAULT_COMMITMENT_PREFIX),
+            CommitmentPrefix::default()
+        );
+        assert_eq!(
+            CommitmentPrefix::default().apply(b"key"),
+            segments(&["ibc", "key"])
+        );
+
+        // the key is appended to the last segment
+        assert_eq!(
+            prefix("ibc\\prefix/").apply(b"key"),
+            segments(&["ibc", "prefix/key"])
+        );
+        assert_eq!(prefix("ibc").apply(b"key"), segments(&["ibckey"]));
+        assert_eq!(
+            prefix("store\\substore\\").apply(b"key"),
+            segments(&["store", "substore", "key"])
+        );
+    }
+
+    #[test]
+    fn test_parse_key_path() {
+        let default_prefix = CommitmentPrefix::default();
+        assert_eq!(
+            parse_key_path(
+                "clients/07-tendermint-0/clientState",
+                false,
+                &default_prefix
+            )
+            .unwrap(),
+            segments(&["ibc", "clients/07-tendermint-0/clientState"])
+        );
+        // a key path under a prefix of a single segment has a single segment
+        assert!(parse_key_path("key", false, &prefix("ibc")).is_err());
+
+        // base64 key paths are full merkle paths, regardless of the prefix
+        assert_eq!(
+            parse_key_path("aWJj\\a2V5", true, &prefix("other\\")).unwrap(),
+            segments(&["ibc", "key"])
+        );
+        assert!(parse_key_path("aWJj", true, &default_prefix).is_err());
+        assert!(parse_key_path("aWJj\\!!!", true, &default_prefix).is_err());
+    }
+
+    #[test]
+    fn test_abci_query_path() {
+        assert_eq!(
+            abci_query_path(&segments(&["ibc", "key"])).unwrap(),
+            ("store/ibc/key".to_string(), b"key".to_vec())
+        );
+        // nested stores are not routed by the multistore
+        assert!(abci_query_path(&segments(&["store", "substore", "nested/key"])).is_err());
+
+        assert!(abci_query_path(&segments(&["key"])).is_err());
+        assert!(abci_query_path(&[]).is_err());
+        assert!(abci_query_path(&[vec![0xff], b"key".to_vec()]).is_err());
+    }
+}
@@ -2,6 +2,8 @@
 #![deny(clippy::nursery, clippy::pedantic, warnings, missing_docs)]
 
 pub mod eth;
+pub mod key_path;
 pub mod light_block;
 pub mod merkle;
+pub mod proof_specs;
 pub mod rpc;
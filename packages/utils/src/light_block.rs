@@ -2,9 +2,10 @@
 
 use ibc_client_tendermint_types::{ConsensusState, Header};
 use ibc_core_client_types::Height as IbcHeight;
-use ibc_core_commitment_types::commitment::CommitmentRoot;
+use ibc_core_commitment_types::{commitment::CommitmentRoot, specs::ProofSpecs};
 use ibc_core_host_types::{error::IdentifierError, identifiers::ChainId};
 use sp1_ics07_tendermint_solidity::{
+    proof_specs_hash,
     IICS02ClientMsgs::Height,
     IICS07TendermintMsgs::{ClientState, TrustThreshold},
     ISP1Msgs::SupportedZkAlgorithm,
@@ -25,8 +26,15 @@ pub trait LightBlockExt {
         trust_level: TrustThreshold,
         unbonding_period: u32,
         trusting_period: u32,
+        max_clock_drift: u32,
+        proof_specs: &ProofSpecs,
         zk_algorithm: SupportedZkAlgorithm,
     ) -> anyhow::Result<ClientState>;
+    /// Get the height of the [`LightBlock`], in the revision of its chain identifier.
+    ///
+    /// # Errors
+    /// Returns an error if the chain identifier or height cannot be parsed.
+    fn to_sol_height(&self) -> anyhow::Result<Height>;
     /// Convert the [`LightBlock`] to a new [`ConsensusState`].
     #[must_use]
     fn to_consensus_state(&self) -> ConsensusState;
@@ -49,20 +57,28 @@ impl LightBlockExt for LightBlock {
         trust_level: TrustThreshold,
         unbonding_period: u32,
         trusting_period: u32,
+        max_clock_drift: u32,
+        proof_specs: &ProofSpecs,
         zk_algorithm: SupportedZkAlgorithm,
     ) -> anyhow::Result<ClientState> {
         let chain_id = ChainId::from_str(self.signed_header.header.chain_id.as_str())?;
         Ok(ClientState {
             chainId: chain_id.to_string(),
             trustLevel: trust_level,
-            latestHeight: Height {
-                revisionNumber: chain_id.revision_number().try_into()?,
-                revisionHeight: self.height().value().try_into()?,
-            },
+            latestHeight: self.to_sol_height()?,
             isFrozen: false,
             zkAlgorithm: zk_algorithm.into(),
             unbondingPeriod: unbonding_period,
             trustingPeriod: trusting_period,
+            maxClockDrift: max_clock_drift,
+            proofSpecsHash: proof_specs_hash(proof_specs).into(),
+        })
+    }
+
+    fn to_sol_height(&self) -> anyhow::Result<Height> {
+        Ok(Height {
+            revisionNumber: self.chain_id()?.revision_number().try_into()?,
+            revisionHeight: self.height().value().try_into()?,
         })
     }
 
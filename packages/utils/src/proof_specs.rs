@@ -0,0 +1,192 @@
+//! This module defines the ICS-23 proof specs presets of the light client, and how to load them
+//! from the command line or the configuration.
+
+use std::{fmt, str::FromStr};
+
+use ibc_core_commitment_types::{
+    proto::ics23::{self, HashOp, InnerSpec, LeafOp, LengthOp, ProofSpec},
+    specs::ProofSpecs,
+};
+
+/// A preset of ICS-23 proof specs for a store layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofSpecsPreset {
+    /// An IAVL store in a tendermint multistore, i.e. the layout of the Cosmos SDK.
+    Iavl,
+    /// A sparse merkle tree store in a tendermint multistore.
+    Smt,
+    /// A Jellyfish merkle tree store in a tendermint multistore, i.e. the same layout as
+    /// [`Self::Smt`] with a Jellyfish merkle tree store.
+    /// Chains that commit their stores differently, e.g. Penumbra whose substores are committed
+    /// in another Jellyfish merkle tree, must provide their proof specs as a JSON file.
+    Jmt,
+}
+
+impl ProofSpecsPreset {
+    /// Returns the proof specs of the preset.
+    ///
+    /// # Panics
+    /// Panics if the proof specs of the preset are invalid, which never happens.
+    #[must_use]
+    pub fn proof_specs(self) -> ProofSpecs {
+        match self {
+            Self::Iavl => ProofSpecs::cosmos(),
+            Self::Smt => vec![ics23::smt_spec(), ics23::tendermint_spec()]
+                .try_into()
+                .expect("the smt proof specs are valid"),
+            Self::Jmt => vec![jmt_spec(), ics23::tendermint_spec()]
+                .try_into()
+                .expect("the jmt proof specs are valid"),
+        }
+    }
+}
+
+impl FromStr for ProofSpecsPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "iavl" => Ok(Self::Iavl),
+            "smt" => Ok(Self::Smt),
+            "jmt" => Ok(Self::Jmt),
+            _ => Err(format!("unknown proof specs preset: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for ProofSpecsPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Iavl => write!(f, "iavl"),
+            Self::Smt => write!(f, "smt"),
+            Self::Jmt => write!(f, "jmt"),
+        }
+    }
+}
+
+/// Loads proof specs from either the name of a preset, i.e. `iavl`, `smt` or `jmt`, or the path
+/// to a JSON file of the proof specs, e.g. as written in the `genesis.json` file.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or does not contain valid proof specs.
+pub fn load_proof_specs(preset_or_path: &str) -> anyhow::Result<ProofSpecs> {
+    if let Ok(preset) = preset_or_path.parse::<ProofSpecsPreset>() {
+        return Ok(preset.proof_specs());
+    }
+
+    let contents = std::fs::read_to_string(preset_or_path).map_err(|e| {
+        anyhow::anyhow!(
+            "'{preset_or_path}' is neither a proof specs preset nor a readable file: {e}"
+        )
+    })?;
+    let proof_specs: ProofSpecs = serde_json::from_str(&contents)?;
+    anyhow::ensure!(
+        !Vec::<ProofSpec>::from(proof_specs.clone()).is_empty(),
+        "no proof specs in {preset_or_path}"
+    );
+    Ok(proof_specs)
+}
+
+/// The proof spec of a Jellyfish merkle tree.
+fn jmt_spec() -> ProofSpec {
+    ProofSpec {
+        leaf_spec: Some(LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::Sha256.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::NoPrefix.into(),
+            prefix: b"JMT::LeafNode".to_vec(),
+        }),
+        inner_spec: Some(InnerSpec {
+            hash: HashOp::Sha256.into(),
+            child_order: vec![0, 1],
+            min_prefix_length: 16,
+            max_prefix_length: 16,
+            child_size: 32,
+            empty_child: b"SPARSE_MERKLE_PLACEHOLDER_HASH__".to_vec(),
+        }),
+        min_depth: 0,
+        max_depth: 64,
+        prehash_key_before_comparison: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_commitment_types::{
+        proto::ics23::{self, ProofSpec},
+        specs::ProofSpecs,
+    };
+
+    use super::{jmt_spec, load_proof_specs, ProofSpecsPreset};
+
+    fn specs(proof_specs: ProofSpecs) -> Vec<ProofSpec> {
+        proof_specs.into()
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(
+            specs(ProofSpecsPreset::Iavl.proof_specs()),
+            specs(ProofSpecs::cosmos())
+        );
+        assert_eq!(
+            specs(ProofSpecsPreset::Smt.proof_specs()),
+            vec![ics23::smt_spec(), ics23::tendermint_spec()]
+        );
+        assert_eq!(
+            specs(ProofSpecsPreset::Jmt.proof_specs()),
+            vec![jmt_spec(), ics23::tendermint_spec()]
+        );
+    }
+
+    #[test]
+    fn test_parse_preset() {
+        for preset in [
+            ProofSpecsPreset::Iavl,
+            ProofSpecsPreset::Smt,
+            ProofSpecsPreset::Jmt,
+        ] {
+            assert_eq!(preset.to_string().parse::<ProofSpecsPreset>(), Ok(preset));
+        }
+        assert_eq!(
+            "IAVL".parse::<ProofSpecsPreset>(),
+            Ok(ProofSpecsPreset::Iavl)
+        );
+        assert!("cosmos".parse::<ProofSpecsPreset>().is_err());
+    }
+
+    #[test]
+    fn test_load_preset() {
+        assert_eq!(
+            specs(load_proof_specs("smt").unwrap()),
+            specs(ProofSpecsPreset::Smt.proof_specs())
+        );
+    }
+
+    #[test]
+    fn test_load_json_file() {
+        let dir = std::env::temp_dir().join(format!("proof-specs-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("jmt.json");
+        let proof_specs = ProofSpecsPreset::Jmt.proof_specs();
+        std::fs::write(&path, serde_json::to_string(&proof_specs).unwrap()).unwrap();
+        assert_eq!(
+            specs(load_proof_specs(path.to_str().unwrap()).unwrap()),
+            specs(proof_specs)
+        );
+
+        let path = dir.join("empty.json");
+        std::fs::write(&path, "[]").unwrap();
+        assert!(load_proof_specs(path.to_str().unwrap()).is_err());
+
+        let path = dir.join("invalid.json");
+        std::fs::write(&path, "{").unwrap();
+        assert!(load_proof_specs(path.to_str().unwrap()).is_err());
+
+        assert!(load_proof_specs(dir.join("missing.json").to_str().unwrap()).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
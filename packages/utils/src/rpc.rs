@@ -6,11 +6,20 @@ use std::{collections::HashMap, env};
 use anyhow::Result;
 
 use cosmos_sdk_proto::{
-    cosmos::staking::v1beta1::{Params, QueryParamsRequest, QueryParamsResponse},
+    cosmos::{
+        staking::v1beta1::{Params, QueryParamsRequest, QueryParamsResponse},
+        upgrade::v1beta1::{
+            Plan, QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryCurrentPlanRequest,
+            QueryCurrentPlanResponse,
+        },
+    },
     prost::Message,
     traits::MessageExt,
 };
-use tendermint::{block::signed_header::SignedHeader, validator::Set};
+use tendermint::{
+    block::{signed_header::SignedHeader, Height},
+    validator::Set,
+};
 use tendermint_light_client_verifier::types::{LightBlock, ValidatorSet};
 use tendermint_rpc::{Client, HttpClient, Paging, Url};
 
@@ -25,14 +34,20 @@ pub trait TendermintRpcExt {
     /// invalid.
     #[must_use]
     fn from_env() -> Self;
-    /// Gets a light block for a specific block height.
+    /// Gets a light block for a specific block height, i.e. the revision height of the current
+    /// revision of the chain.
     /// If `block_height` is `None`, the latest block is fetched.
     ///
     /// # Errors
     /// Returns an error if the RPC request fails or if the response cannot be parsed.
-    async fn get_light_block(&self, block_height: Option<u32>) -> Result<LightBlock>;
+    async fn get_light_block(&self, block_height: Option<u64>) -> Result<LightBlock>;
     /// Queries the Cosmos SDK for staking parameters.
     async fn sdk_staking_params(&self) -> Result<Params>;
+    /// Queries the Cosmos SDK for the currently scheduled upgrade plan, if any.
+    async fn sdk_current_upgrade_plan(&self) -> Result<Option<Plan>>;
+    /// Queries the Cosmos SDK for the height at which the upgrade plan with the given name was
+    /// applied, if it was.
+    async fn sdk_applied_upgrade_height(&self, name: &str) -> Result<Option<u64>>;
 }
 
 #[async_trait::async_trait]
@@ -45,28 +60,23 @@ impl TendermintRpcExt for HttpClient {
         .expect("Failed to create HTTP client")
     }
 
-    async fn get_light_block(&self, block_height: Option<u32>) -> Result<LightBlock> {
+    async fn get_light_block(&self, block_height: Option<u64>) -> Result<LightBlock> {
         let peer_id = self.status().await?.node_info.id;
         let commit_response;
         let height;
         if let Some(block_height) = block_height {
-            commit_response = self.commit(block_height).await?;
-            height = block_height;
+            height = Height::try_from(block_height)?;
+            commit_response = self.commit(height).await?;
         } else {
             commit_response = self.latest_commit().await?;
-            height = commit_response
-                .signed_header
-                .header
-                .height
-                .value()
-                .try_into()?;
+            height = commit_response.signed_header.header.height;
         }
         let mut signed_header = commit_response.signed_header;
 
         let validator_response = self.validators(height, Paging::All).await?;
         let validators = Set::new(validator_response.validators, None);
 
-        let next_validator_response = self.validators(height + 1, Paging::All).await?;
+        let next_validator_response = self.validators(height.increment(), Paging::All).await?;
         let next_validators = Set::new(next_validator_response.validators, None);
 
         sort_signatures_by_validators_power_desc(&mut signed_header, &validators);
@@ -91,6 +101,35 @@ impl TendermintRpcExt for HttpClient {
             .params
             .ok_or_else(|| anyhow::anyhow!("No staking params found"))
     }
+
+    async fn sdk_current_upgrade_plan(&self) -> Result<Option<Plan>> {
+        let abci_resp = self
+            .abci_query(
+                Some("/cosmos.upgrade.v1beta1.Query/CurrentPlan".to_string()),
+                QueryCurrentPlanRequest::default().to_bytes()?,
+                None,
+                false,
+            )
+            .await?;
+        Ok(QueryCurrentPlanResponse::decode(abci_resp.value.as_slice())?.plan)
+    }
+
+    async fn sdk_applied_upgrade_height(&self, name: &str) -> Result<Option<u64>> {
+        let abci_resp = self
+            .abci_query(
+                Some("/cosmos.upgrade.v1beta1.Query/AppliedPlan".to_string()),
+                QueryAppliedPlanRequest {
+                    name: name.to_string(),
+                }
+                .to_bytes()?,
+                None,
+                false,
+            )
+            .await?;
+        let height = QueryAppliedPlanResponse::decode(abci_resp.value.as_slice())?.height;
+        // A plan that was never applied has a height of 0.
+        Ok((height > 0).then(|| height.try_into()).transpose()?)
+    }
 }
 
 /// Sorts the signatures in the signed header based on the descending order of validators' power.
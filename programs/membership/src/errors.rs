@@ -0,0 +1,43 @@
+//! Contains the errors of the membership program.
+
+use std::fmt;
+
+/// An error of the verification of key-value pairs by [`crate::membership`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MembershipError {
+    /// The membership proof of the key-value pair at the given index is invalid.
+    InvalidMembershipProof {
+        /// The index of the key-value pair in the request.
+        index: usize,
+        /// The description of the verification error.
+        description: String,
+    },
+    /// The non-membership proof of the key at the given index is invalid.
+    InvalidNonMembershipProof {
+        /// The index of the key in the request.
+        index: usize,
+        /// The description of the verification error.
+        description: String,
+    },
+}
+
+impl fmt::Display for MembershipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMembershipProof { index, description } => {
+                write!(
+                    f,
+                    "invalid membership proof at index {index}: {description}"
+                )
+            }
+            Self::InvalidNonMembershipProof { index, description } => {
+                write!(
+                    f,
+                    "invalid non-membership proof at index {index}: {description}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for MembershipError {}
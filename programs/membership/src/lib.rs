@@ -1,7 +1,13 @@
 //! The crate that contains the types and utilities for `sp1-ics07-tendermint-membership` program.
 #![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
 
-use sp1_ics07_tendermint_solidity::IMembershipMsgs::{KVPair, MembershipOutput};
+pub mod errors;
+
+use errors::MembershipError;
+use sp1_ics07_tendermint_solidity::{
+    committed_kv_value, proof_specs_hash,
+    IMembershipMsgs::{KVPair, MembershipOutput},
+};
 
 use ibc_core_commitment_types::{
     commitment::CommitmentRoot,
@@ -11,16 +17,26 @@ use ibc_core_commitment_types::{
 };
 
 /// The main function of the program without the zkVM wrapper.
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
+///
+/// The proofs are verified with the given ICS-23 proof specs, whose hash is committed in the
+/// output so that the contract can check them against the proof specs of the client.
+///
+/// If `hashed_values` is set, the output commits the keccak256 hash of each value instead of the
+/// value itself, which keeps the output small for large values.
+///
+/// # Errors
+/// Returns an error if the proof of a key-value pair cannot be verified against the app hash.
 pub fn membership(
     app_hash: [u8; 32],
+    proof_specs: &ProofSpecs,
+    hashed_values: bool,
     request_iter: impl Iterator<Item = (Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
-) -> MembershipOutput {
+) -> Result<MembershipOutput, MembershipError> {
     let commitment_root = CommitmentRoot::from_bytes(&app_hash);
 
     let kv_pairs = request_iter
-        .map(|(path, value, merkle_proof)| {
+        .enumerate()
+        .map(|(index, (path, value, merkle_proof))| {
             let merkle_path = MerklePath {
                 key_path: path.into_iter().map(Into::into).collect(),
             };
@@ -28,36 +44,44 @@ pub fn membership(
             if value.is_empty() {
                 merkle_proof
                     .verify_non_membership::<HostFunctionsManager>(
-                        &ProofSpecs::cosmos(),
+                        proof_specs,
                         commitment_root.clone().into(),
                         merkle_path.clone(),
                     )
-                    .unwrap();
+                    .map_err(|e| MembershipError::InvalidNonMembershipProof {
+                        index,
+                        description: e.to_string(),
+                    })?;
             } else {
                 merkle_proof
                     .verify_membership::<HostFunctionsManager>(
-                        &ProofSpecs::cosmos(),
+                        proof_specs,
                         commitment_root.clone().into(),
                         merkle_path.clone(),
                         value.clone(),
                         0,
                     )
-                    .unwrap();
+                    .map_err(|e| MembershipError::InvalidMembershipProof {
+                        index,
+                        description: e.to_string(),
+                    })?;
             }
 
-            KVPair {
+            Ok(KVPair {
                 path: merkle_path
                     .key_path
                     .into_iter()
                     .map(|v| v.into_vec().into())
                     .collect(),
-                value: value.into(),
-            }
+                value: committed_kv_value(value, hashed_values).into(),
+            })
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    MembershipOutput {
+    Ok(MembershipOutput {
         commitmentRoot: app_hash.into(),
         kvPairs: kv_pairs,
-    }
+        proofSpecsHash: proof_specs_hash(proof_specs).into(),
+        hashedValues: hashed_values,
+    })
 }
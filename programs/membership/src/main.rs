@@ -13,6 +13,7 @@ use alloy_sol_types::SolValue;
 
 use ibc_proto::Protobuf;
 use sp1_ics07_tendermint_membership::membership;
+use sp1_ics07_tendermint_solidity::decode_proof_specs;
 
 use ibc_core_commitment_types::merkle::MerkleProof;
 
@@ -24,7 +25,14 @@ pub fn main() {
     let encoded_1 = sp1_zkvm::io::read_vec();
     let app_hash: [u8; 32] = encoded_1.try_into().unwrap();
 
-    // encoded_2 is the number of key-value pairs we want to verify
+    // encoded_2 is the ICS-23 proof specs of the proofs
+    let encoded_2 = sp1_zkvm::io::read_vec();
+    let proof_specs = decode_proof_specs(&encoded_2).unwrap();
+
+    // encoded_3 indicates whether the output commits the hashes of the values
+    let hashed_values = sp1_zkvm::io::read_vec()[0] != 0;
+
+    // encoded_4 is the number of key-value pairs we want to verify
     let request_len = sp1_zkvm::io::read_vec()[0];
     assert!(request_len != 0);
 
@@ -43,7 +51,8 @@ pub fn main() {
         (path, value, merkle_proof)
     });
 
-    let output = membership(app_hash, request_iter);
+    let output = membership(app_hash, &proof_specs, hashed_values, request_iter)
+        .unwrap_or_else(|e| panic!("{e}"));
 
     sp1_zkvm::io::commit_slice(&output.abi_encode());
 }
@@ -0,0 +1,104 @@
+//! Contains the errors of the misbehaviour program.
+
+use std::fmt;
+
+/// An error of the verification of a misbehaviour by [`crate::check_for_misbehaviour`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MisbehaviourError {
+    /// The chain identifier is not a valid chain identifier.
+    InvalidChainId {
+        /// The chain identifier.
+        chain_id: String,
+    },
+    /// A header of the misbehaviour is not of the chain of the client.
+    ChainIdMismatch {
+        /// The chain identifier of the client.
+        expected: String,
+        /// The chain identifier of the header.
+        actual: String,
+    },
+    /// A height cannot be represented in the client.
+    InvalidHeight {
+        /// The height.
+        height: String,
+    },
+    /// The trusting period of a trusted consensus state has passed.
+    TrustedStateExpired {
+        /// The timestamp of the trusted consensus state in seconds.
+        trusted_timestamp: i64,
+        /// The trusting period of the client in seconds.
+        trusting_period: u32,
+        /// The time of the verification in seconds.
+        time: u64,
+    },
+    /// A header of the misbehaviour is ahead of the time of the verification by more than the
+    /// max clock drift.
+    HeaderFromFuture {
+        /// The timestamp of the header in seconds.
+        header_timestamp: i64,
+        /// The max clock drift of the client in seconds.
+        max_clock_drift: u32,
+        /// The time of the verification in seconds.
+        time: u64,
+    },
+    /// The validators of a trusted consensus state that signed a header do not have enough
+    /// voting power.
+    InsufficientVotingPower(String),
+    /// A header of the misbehaviour cannot be verified, or the misbehaviour is malformed.
+    InvalidMisbehaviour(String),
+    /// The headers are valid but do not conflict, so the client must not be frozen.
+    NotMisbehaviour,
+}
+
+impl MisbehaviourError {
+    /// Classifies an error of the misbehaviour verification of ibc-rs, which only describes the
+    /// verdict of the light client verifier in its message.
+    #[must_use]
+    pub fn from_verification(description: String) -> Self {
+        // The light client verifier reports both a lack of overlap with the trusted validators
+        // and a lack of signers of a header as insufficient.
+        if description.contains("insufficient") {
+            Self::InsufficientVotingPower(description)
+        } else {
+            Self::InvalidMisbehaviour(description)
+        }
+    }
+}
+
+impl fmt::Display for MisbehaviourError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChainId { chain_id } => write!(f, "invalid chain identifier {chain_id}"),
+            Self::ChainIdMismatch { expected, actual } => write!(
+                f,
+                "the header of chain {actual} is not of the chain of the client ({expected})"
+            ),
+            Self::InvalidHeight { height } => write!(f, "invalid height {height}"),
+            Self::TrustedStateExpired {
+                trusted_timestamp,
+                trusting_period,
+                time,
+            } => write!(
+                f,
+                "the trusted consensus state at {trusted_timestamp} has expired at {time} with a trusting period of {trusting_period} seconds"
+            ),
+            Self::HeaderFromFuture {
+                header_timestamp,
+                max_clock_drift,
+                time,
+            } => write!(
+                f,
+                "the header at {header_timestamp} is more than the max clock drift of {max_clock_drift} seconds ahead of {time}"
+            ),
+            Self::InsufficientVotingPower(description) => {
+                write!(f, "insufficient voting power: {description}")
+            }
+            Self::InvalidMisbehaviour(description) => {
+                write!(f, "invalid misbehaviour: {description}")
+            }
+            Self::NotMisbehaviour => write!(f, "the headers are not a misbehaviour"),
+        }
+    }
+}
+
+impl std::error::Error for MisbehaviourError {}
@@ -2,8 +2,10 @@
 //! program.
 #![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
 
+pub mod errors;
 pub mod types;
 
+use errors::MisbehaviourError;
 use ibc_client_tendermint::client_state::{
     check_for_misbehaviour_on_misbehavior, verify_misbehaviour,
 };
@@ -18,35 +20,49 @@ use tendermint_light_client_verifier::options::Options;
 use tendermint_light_client_verifier::ProdVerifier;
 
 /// The main function of the program without the zkVM wrapper.
+///
+/// # Errors
+/// Returns an error if a header of the misbehaviour cannot be verified from its trusted consensus
+/// state, or if the headers are not a misbehaviour.
 #[allow(clippy::missing_panics_doc)]
-#[must_use]
 pub fn check_for_misbehaviour(
     client_state: ClientState,
     misbehaviour: &Misbehaviour,
     trusted_consensus_state_1: ConsensusState,
     trusted_consensus_state_2: ConsensusState,
     time: u64,
-) -> MisbehaviourOutput {
+) -> Result<MisbehaviourOutput, MisbehaviourError> {
     let client_id = ClientId::new(TENDERMINT_CLIENT_TYPE, 0).unwrap();
-    assert_eq!(
-        client_state.chainId,
-        misbehaviour
-            .header1()
-            .signed_header
-            .header
-            .chain_id
-            .to_string()
-    );
+    for header in [misbehaviour.header1(), misbehaviour.header2()] {
+        let header_chain_id = header.signed_header.header.chain_id.as_str();
+        if header_chain_id != client_state.chainId {
+            return Err(MisbehaviourError::ChainIdMismatch {
+                expected: client_state.chainId,
+                actual: header_chain_id.to_string(),
+            });
+        }
+    }
+    let chain_id =
+        ChainId::new(&client_state.chainId).map_err(|_| MisbehaviourError::InvalidChainId {
+            chain_id: client_state.chainId.clone(),
+        })?;
+
+    check_time(
+        &client_state,
+        misbehaviour,
+        [&trusted_consensus_state_1, &trusted_consensus_state_2],
+        time,
+    )?;
 
     // Insert the two trusted consensus states into the trusted consensus state map that exists in the ClientValidationContext that is expected by verifyMisbehaviour
     // Since we are mocking the existence of prior trusted consensus states, we are only filling in the two consensus states that are passed in into the map
     let trusted_consensus_state_map = HashMap::from([
         (
-            misbehaviour.header1().trusted_height.revision_height(),
+            misbehaviour.header1().trusted_height,
             &trusted_consensus_state_1,
         ),
         (
-            misbehaviour.header2().trusted_height.revision_height(),
+            misbehaviour.header2().trusted_height,
             &trusted_consensus_state_2,
         ),
     ]);
@@ -56,7 +72,7 @@ pub fn check_for_misbehaviour(
     let options = Options {
         trust_threshold: client_state.trustLevel.clone().into(),
         trusting_period: Duration::from_secs(client_state.trustingPeriod.into()),
-        clock_drift: Duration::default(),
+        clock_drift: Duration::from_secs(client_state.maxClockDrift.into()),
     };
 
     // Call into ibc-rs verify_misbehaviour function to verify that both headers are valid given their respective trusted consensus states
@@ -64,28 +80,75 @@ pub fn check_for_misbehaviour(
         &ctx,
         misbehaviour,
         &client_id,
-        &ChainId::new(&client_state.chainId).unwrap(),
+        &chain_id,
         &options,
         &ProdVerifier::default(),
     )
-    .unwrap();
+    .map_err(|e| MisbehaviourError::from_verification(e.to_string()))?;
 
     // Call into ibc-rs check_for_misbehaviour_on_misbehaviour method to ensure that the misbehaviour is valid
     // i.e. the headers are same height but different commits, or headers are not monotonically increasing in time
     let is_misbehaviour =
         check_for_misbehaviour_on_misbehavior(misbehaviour.header1(), misbehaviour.header2())
-            .unwrap();
-    assert!(is_misbehaviour, "Misbehaviour is not detected");
+            .map_err(|e| MisbehaviourError::InvalidMisbehaviour(e.to_string()))?;
+    if !is_misbehaviour {
+        return Err(MisbehaviourError::NotMisbehaviour);
+    }
+
+    let trusted_height = |height: ibc_core_client::types::Height| {
+        height
+            .try_into()
+            .map_err(|_| MisbehaviourError::InvalidHeight {
+                height: height.to_string(),
+            })
+    };
 
     // The prover takes in the trusted headers as an input but does not maintain its own internal state
     // Thus, the verifier must ensure that the trusted headers that were used in the proof are trusted consensus
     // states stored in its own internal state before it can accept the misbehaviour proof as valid.
-    MisbehaviourOutput {
+    Ok(MisbehaviourOutput {
         clientState: client_state,
-        trustedHeight1: misbehaviour.header1().trusted_height.try_into().unwrap(),
-        trustedHeight2: misbehaviour.header2().trusted_height.try_into().unwrap(),
+        trustedHeight1: trusted_height(misbehaviour.header1().trusted_height)?,
+        trustedHeight2: trusted_height(misbehaviour.header2().trusted_height)?,
         trustedConsensusState1: trusted_consensus_state_1.into(),
         trustedConsensusState2: trusted_consensus_state_2.into(),
         time,
+    })
+}
+
+/// Checks that the trusted consensus states are within the trusting period, and that the headers
+/// are within the max clock drift, at the time of the verification, as the light client verifier
+/// does.
+fn check_time(
+    client_state: &ClientState,
+    misbehaviour: &Misbehaviour,
+    trusted_consensus_states: [&ConsensusState; 2],
+    time: u64,
+) -> Result<(), MisbehaviourError> {
+    for trusted_consensus_state in trusted_consensus_states {
+        let trusted_timestamp = trusted_consensus_state.timestamp.unix_timestamp();
+        if i128::from(trusted_timestamp) + i128::from(client_state.trustingPeriod)
+            <= i128::from(time)
+        {
+            return Err(MisbehaviourError::TrustedStateExpired {
+                trusted_timestamp,
+                trusting_period: client_state.trustingPeriod,
+                time,
+            });
+        }
     }
+
+    for header in [misbehaviour.header1(), misbehaviour.header2()] {
+        let header_timestamp = header.signed_header.header.time.unix_timestamp();
+        if i128::from(header_timestamp) >= i128::from(time) + i128::from(client_state.maxClockDrift)
+        {
+            return Err(MisbehaviourError::HeaderFromFuture {
+                header_timestamp,
+                max_clock_drift: client_state.maxClockDrift,
+                time,
+            });
+        }
+    }
+
+    Ok(())
 }
@@ -48,7 +48,8 @@ pub fn main() {
         trusted_consensus_state_1,
         trusted_consensus_state_2,
         time,
-    );
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
 
     sp1_zkvm::io::commit_slice(&output.abi_encode());
 }
@@ -4,7 +4,10 @@ use ibc_client_tendermint::{
     client_state::ClientState as ClientStateWrapper,
     consensus_state::ConsensusState as ConsensusStateWrapper, types::ConsensusState,
 };
-use ibc_core_client::context::{ClientValidationContext, ExtClientValidationContext};
+use ibc_core_client::{
+    context::{ClientValidationContext, ExtClientValidationContext},
+    types::Height,
+};
 use ibc_core_host_types::error::HostError;
 use ibc_primitives::Timestamp;
 use std::collections::HashMap;
@@ -13,7 +16,8 @@ use std::collections::HashMap;
 pub struct MisbehaviourValidationContext<'a> {
     /// Current time in seconds.
     time: u64,
-    trusted_consensus_states: HashMap<u64, &'a ConsensusState>,
+    /// The trusted consensus states by height, including the revision number.
+    trusted_consensus_states: HashMap<Height, &'a ConsensusState>,
 }
 
 impl<'a> MisbehaviourValidationContext<'a> {
@@ -21,7 +25,7 @@ impl<'a> MisbehaviourValidationContext<'a> {
     #[must_use]
     pub const fn new(
         time: u64,
-        trusted_consensus_states: HashMap<u64, &'a ConsensusState>,
+        trusted_consensus_states: HashMap<Height, &'a ConsensusState>,
     ) -> Self {
         Self {
             time,
@@ -38,10 +42,17 @@ impl ClientValidationContext for MisbehaviourValidationContext<'_> {
         &self,
         client_cons_state_path: &ibc_core_host_types::path::ClientConsensusStatePath,
     ) -> Result<Self::ConsensusStateRef, HostError> {
-        let height = client_cons_state_path.revision_height;
-        let trusted_consensus_state = self.trusted_consensus_states[&height];
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )
+        .map_err(|e| HostError::invalid_state(e.to_string()))?;
+        let trusted_consensus_state = self
+            .trusted_consensus_states
+            .get(&height)
+            .ok_or_else(|| HostError::missing_state(format!("consensus state at {height}")))?;
 
-        Ok(trusted_consensus_state.clone().into())
+        Ok((*trusted_consensus_state).clone().into())
     }
 
     fn client_state(
@@ -0,0 +1,46 @@
+//! Contains the errors of the multi-height membership program.
+
+use std::fmt;
+
+use sp1_ics07_tendermint_membership::errors::MembershipError;
+
+/// An error of the verification of key-value pairs by [`crate::multi_membership`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiMembershipError {
+    /// No groups of key-value pairs were requested.
+    NoGroups,
+    /// The group at the given index has no key-value pairs.
+    EmptyGroup {
+        /// The index of the group in the request.
+        index: usize,
+    },
+    /// The height of the group at the given index is not greater than the height of the previous
+    /// group.
+    HeightsNotIncreasing {
+        /// The index of the group in the request.
+        index: usize,
+    },
+    /// A key-value pair of the group at the given index cannot be verified.
+    Membership {
+        /// The index of the group in the request.
+        index: usize,
+        /// The error of the verification.
+        error: MembershipError,
+    },
+}
+
+impl fmt::Display for MultiMembershipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoGroups => write!(f, "no key-value pairs to verify"),
+            Self::EmptyGroup { index } => write!(f, "group {index} has no key-value pairs"),
+            Self::HeightsNotIncreasing { index } => write!(
+                f,
+                "the height of group {index} is not greater than the height of the previous group"
+            ),
+            Self::Membership { index, error } => write!(f, "group {index}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for MultiMembershipError {}
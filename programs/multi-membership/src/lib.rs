@@ -0,0 +1,76 @@
+//! The crate that contains the types and utilities for `sp1-ics07-tendermint-multi-membership`
+//! program.
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+
+pub mod errors;
+
+use errors::MultiMembershipError;
+use ibc_core_commitment_types::{merkle::MerkleProof, specs::ProofSpecs};
+use sp1_ics07_tendermint_membership::membership;
+use sp1_ics07_tendermint_solidity::{
+    proof_specs_hash,
+    IICS02ClientMsgs::Height,
+    IICS07TendermintMsgs::ConsensusState,
+    IMultiMembershipMsgs::{MembershipGroup, MultiMembershipOutput},
+};
+
+/// A group of key-value pairs and their proofs against the root of the trusted consensus state at
+/// the height.
+pub type MembershipRequest = (
+    Height,
+    ConsensusState,
+    Vec<(Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
+);
+
+/// The main function of the program without the zkVM wrapper.
+///
+/// Each group of key-value pairs is verified against the root of its own trusted consensus
+/// state, with the given ICS-23 proof specs. The groups must be ordered by strictly increasing
+/// height, so that the contract finds at most one group at a height. If `hashed_values` is set,
+/// the output commits the keccak256 hash of each value instead of the value itself.
+///
+/// # Errors
+/// Returns an error if there are no groups, if a group is empty or not at a greater height than
+/// the previous one, or if the proof of a key-value pair cannot be verified.
+pub fn multi_membership(
+    proof_specs: &ProofSpecs,
+    hashed_values: bool,
+    request_iter: impl Iterator<Item = MembershipRequest>,
+) -> Result<MultiMembershipOutput, MultiMembershipError> {
+    let mut groups: Vec<MembershipGroup> = Vec::new();
+    for (index, (height, trusted_consensus_state, kv_proofs)) in request_iter.enumerate() {
+        if kv_proofs.is_empty() {
+            return Err(MultiMembershipError::EmptyGroup { index });
+        }
+        if groups
+            .last()
+            .is_some_and(|previous| previous.height >= height)
+        {
+            return Err(MultiMembershipError::HeightsNotIncreasing { index });
+        }
+
+        let output = membership(
+            trusted_consensus_state.root.0,
+            proof_specs,
+            hashed_values,
+            kv_proofs.into_iter(),
+        )
+        .map_err(|error| MultiMembershipError::Membership { index, error })?;
+
+        groups.push(MembershipGroup {
+            height,
+            trustedConsensusState: trusted_consensus_state,
+            kvPairs: output.kvPairs,
+        });
+    }
+
+    if groups.is_empty() {
+        return Err(MultiMembershipError::NoGroups);
+    }
+
+    Ok(MultiMembershipOutput {
+        groups,
+        proofSpecsHash: proof_specs_hash(proof_specs).into(),
+        hashedValues: hashed_values,
+    })
+}
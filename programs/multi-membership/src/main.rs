@@ -0,0 +1,76 @@
+//! A program that verifies the membership or non-membership of values in the commitment roots of
+//! several trusted consensus states.
+
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+#![allow(clippy::no_mangle_with_rust_abi)]
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+
+use ibc_proto::Protobuf;
+use sp1_ics07_tendermint_multi_membership::multi_membership;
+use sp1_ics07_tendermint_solidity::{
+    decode_proof_specs, IICS02ClientMsgs::Height as SolHeight,
+    IICS07TendermintMsgs::ConsensusState as SolConsensusState,
+};
+
+use ibc_core_commitment_types::merkle::MerkleProof;
+
+/// The main function of the program.
+///
+/// # Panics
+/// Panics if the verification fails.
+pub fn main() {
+    // encoded_1 is the ICS-23 proof specs of the proofs
+    let encoded_1 = sp1_zkvm::io::read_vec();
+    let proof_specs = decode_proof_specs(&encoded_1).unwrap();
+
+    // encoded_2 indicates whether the output commits the hashes of the values
+    let hashed_values = sp1_zkvm::io::read_vec()[0] != 0;
+
+    // encoded_3 is the number of groups of key-value pairs we want to verify
+    let groups_len = sp1_zkvm::io::read_vec()[0];
+    assert!(groups_len != 0);
+
+    let request_iter = (0..groups_len).map(|_| {
+        // group_encoded_1 is the height of the trusted consensus state
+        let group_encoded_1 = sp1_zkvm::io::read_vec();
+        let height = bincode::deserialize::<SolHeight>(&group_encoded_1).unwrap();
+
+        // group_encoded_2 is the trusted consensus state whose root the proofs are verified against
+        let group_encoded_2 = sp1_zkvm::io::read_vec();
+        let trusted_consensus_state =
+            bincode::deserialize::<SolConsensusState>(&group_encoded_2).unwrap();
+
+        // group_encoded_3 is the number of key-value pairs of the group
+        let request_len = sp1_zkvm::io::read_vec()[0];
+        let kv_proofs = (0..request_len)
+            .map(|_| {
+                // loop_encoded_1 is the path we want to verify the membership of
+                let loop_encoded_1 = sp1_zkvm::io::read_vec();
+                let path = bincode::deserialize(&loop_encoded_1).unwrap();
+
+                // loop_encoded_2 is the value we want to prove the membership of
+                // if it is empty, we are verifying non-membership
+                let value = sp1_zkvm::io::read_vec();
+
+                let loop_encoded_3 = sp1_zkvm::io::read_vec();
+                let merkle_proof = MerkleProof::decode_vec(&loop_encoded_3).unwrap();
+
+                (path, value, merkle_proof)
+            })
+            .collect();
+
+        (height, trusted_consensus_state, kv_proofs)
+    });
+
+    let output = multi_membership(&proof_specs, hashed_values, request_iter)
+        .unwrap_or_else(|e| panic!("{e}"));
+
+    sp1_zkvm::io::commit_slice(&output.abi_encode());
+}
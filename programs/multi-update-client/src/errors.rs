@@ -0,0 +1,47 @@
+//! Contains the errors of the multi-header update client program.
+
+use std::fmt;
+
+use sp1_ics07_tendermint_update_client::errors::UpdateClientError;
+
+/// An error of the verification of the headers by [`crate::multi_update_client`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiUpdateClientError {
+    /// No headers were proposed.
+    NoHeaders,
+    /// A header is not trusted from the height of the previous header.
+    HeadersNotSequential {
+        /// The index of the header.
+        index: usize,
+        /// The trusted height of the header.
+        trusted_height: String,
+        /// The height of the previous header.
+        previous_height: String,
+    },
+    /// A header cannot be verified from the consensus state of the previous header.
+    UpdateClient {
+        /// The index of the header.
+        index: usize,
+        /// The error of the verification.
+        error: UpdateClientError,
+    },
+}
+
+impl fmt::Display for MultiUpdateClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoHeaders => write!(f, "no headers to verify"),
+            Self::HeadersNotSequential {
+                index,
+                trusted_height,
+                previous_height,
+            } => write!(
+                f,
+                "header {index} is trusted from {trusted_height} instead of the previous header at {previous_height}"
+            ),
+            Self::UpdateClient { index, error } => write!(f, "header {index}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for MultiUpdateClientError {}
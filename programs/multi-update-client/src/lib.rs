@@ -0,0 +1,75 @@
+//! The crate that contains the types and utilities for `sp1-ics07-tendermint-multi-update-client`
+//! program.
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+
+pub mod errors;
+
+use errors::MultiUpdateClientError;
+use ibc_client_tendermint::types::{ConsensusState, Header};
+use sp1_ics07_tendermint_solidity::{
+    IICS02ClientMsgs::Height,
+    IICS07TendermintMsgs::ClientState,
+    IMultiUpdateClientMsgs::{ConsensusStateAtHeight, MultiUpdateClientOutput},
+};
+use sp1_ics07_tendermint_update_client::update_client;
+
+/// The main function of the program without the zkVM wrapper.
+///
+/// The first proposed header is verified from the trusted consensus state, and every following
+/// header from the consensus state of the previous one, so a client can catch up over a validator
+/// set that changed by more than the trust level with a single proof.
+///
+/// # Errors
+/// Returns an error if there are no proposed headers, if a header is not trusted from the height
+/// of the previous header, or if a header cannot be verified.
+pub fn multi_update_client(
+    client_state: ClientState,
+    trusted_consensus_state: ConsensusState,
+    proposed_headers: Vec<Header>,
+    time: u64,
+) -> Result<MultiUpdateClientOutput, MultiUpdateClientError> {
+    let mut trusted_height = None;
+    let mut previous_consensus_state = trusted_consensus_state.clone();
+    let mut new_consensus_states: Vec<ConsensusStateAtHeight> =
+        Vec::with_capacity(proposed_headers.len());
+    for (index, proposed_header) in proposed_headers.into_iter().enumerate() {
+        let output = update_client(
+            client_state.clone(),
+            previous_consensus_state,
+            proposed_header,
+            time,
+        )
+        .map_err(|error| MultiUpdateClientError::UpdateClient { index, error })?;
+
+        if let Some(previous) = new_consensus_states.last() {
+            if previous.height != output.trustedHeight {
+                return Err(MultiUpdateClientError::HeadersNotSequential {
+                    index,
+                    trusted_height: format_height(&output.trustedHeight),
+                    previous_height: format_height(&previous.height),
+                });
+            }
+        }
+        trusted_height.get_or_insert(output.trustedHeight);
+
+        previous_consensus_state = output.newConsensusState.clone().into();
+        new_consensus_states.push(ConsensusStateAtHeight {
+            height: output.newHeight,
+            consensusState: output.newConsensusState,
+        });
+    }
+    let trusted_height = trusted_height.ok_or(MultiUpdateClientError::NoHeaders)?;
+
+    Ok(MultiUpdateClientOutput {
+        clientState: client_state,
+        trustedConsensusState: trusted_consensus_state.into(),
+        newConsensusStates: new_consensus_states,
+        time,
+        trustedHeight: trusted_height,
+    })
+}
+
+/// Formats a height of the client as ibc-rs does.
+fn format_height(height: &Height) -> String {
+    format!("{}-{}", height.revisionNumber, height.revisionHeight)
+}
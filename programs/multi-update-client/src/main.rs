@@ -0,0 +1,53 @@
+//! A program that verifies a sequence of block headers of a blockchain, each from the previous
+//! one, using an IBC tendermint light client.
+
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+#![allow(clippy::no_mangle_with_rust_abi)]
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use ibc_client_tendermint::types::Header;
+use sp1_ics07_tendermint_multi_update_client::multi_update_client;
+use sp1_ics07_tendermint_solidity::IICS07TendermintMsgs::{
+    ClientState as SolClientState, ConsensusState as SolConsensusState,
+};
+
+/// The main function of the program.
+///
+/// # Panics
+/// Panics if the verification fails.
+pub fn main() {
+    let encoded_1 = sp1_zkvm::io::read_vec();
+    let encoded_2 = sp1_zkvm::io::read_vec();
+    let encoded_3 = sp1_zkvm::io::read_vec();
+
+    // input 1: the client state
+    let client_state = bincode::deserialize::<SolClientState>(&encoded_1).unwrap();
+    // input 2: the trusted consensus state
+    let trusted_consensus_state = bincode::deserialize::<SolConsensusState>(&encoded_2)
+        .unwrap()
+        .into();
+    // input 3: time
+    let time = u64::from_le_bytes(encoded_3.try_into().unwrap());
+    // input 4: the number of proposed headers
+    let headers_len = sp1_zkvm::io::read_vec()[0];
+    // input 5..: the proposed headers, in order
+    let proposed_headers = (0..headers_len)
+        .map(|_| serde_cbor::from_slice::<Header>(&sp1_zkvm::io::read_vec()).unwrap())
+        .collect();
+
+    let output = multi_update_client(
+        client_state,
+        trusted_consensus_state,
+        proposed_headers,
+        time,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+
+    sp1_zkvm::io::commit_slice(&output.abi_encode());
+}
@@ -0,0 +1,64 @@
+//! Contains the errors of the update client and membership program.
+
+use std::fmt;
+
+use sp1_ics07_tendermint_membership::errors::MembershipError;
+use sp1_ics07_tendermint_update_client::errors::UpdateClientError;
+
+/// An error of [`crate::update_client_and_membership`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UcAndMembershipError {
+    /// The app hash of the proposed header is not 32 bytes long.
+    InvalidAppHash,
+    /// The proof specs are not the proof specs of the client.
+    ProofSpecsHashMismatch {
+        /// The proof specs hash of the client.
+        expected: [u8; 32],
+        /// The hash of the given proof specs.
+        actual: [u8; 32],
+    },
+    /// The proposed header cannot be verified.
+    UpdateClient(UpdateClientError),
+    /// A key-value pair cannot be verified.
+    Membership(MembershipError),
+}
+
+impl fmt::Display for UcAndMembershipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAppHash => {
+                write!(f, "the app hash of the proposed header is not 32 bytes")
+            }
+            Self::ProofSpecsHashMismatch { expected, actual } => write!(
+                f,
+                "the proof specs hash 0x{} does not match the proof specs hash of the client 0x{}",
+                hex::encode(actual),
+                hex::encode(expected)
+            ),
+            Self::UpdateClient(err) => write!(f, "update client: {err}"),
+            Self::Membership(err) => write!(f, "membership: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for UcAndMembershipError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidAppHash | Self::ProofSpecsHashMismatch { .. } => None,
+            Self::UpdateClient(err) => Some(err),
+            Self::Membership(err) => Some(err),
+        }
+    }
+}
+
+impl From<UpdateClientError> for UcAndMembershipError {
+    fn from(err: UpdateClientError) -> Self {
+        Self::UpdateClient(err)
+    }
+}
+
+impl From<MembershipError> for UcAndMembershipError {
+    fn from(err: MembershipError) -> Self {
+        Self::Membership(err)
+    }
+}
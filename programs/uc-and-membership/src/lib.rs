@@ -1,43 +1,70 @@
 //! The crate that contains the types and utilities for `sp1-ics07-tendermint-membership` program.
 #![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
 
+pub mod errors;
+
+use errors::UcAndMembershipError;
 use sp1_ics07_tendermint_solidity::{
-    IICS07TendermintMsgs::ClientState, IUpdateClientAndMembershipMsgs::UcAndMembershipOutput,
+    proof_specs_hash, IICS07TendermintMsgs::ClientState,
+    IUpdateClientAndMembershipMsgs::UcAndMembershipOutput,
 };
 
 use ibc_client_tendermint_types::{ConsensusState, Header};
 
-use ibc_core_commitment_types::merkle::MerkleProof;
+use ibc_core_commitment_types::{merkle::MerkleProof, specs::ProofSpecs};
 
 /// The main function of the program without the zkVM wrapper.
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
+///
+/// If `hashed_values` is set, the output commits the keccak256 hash of each value instead of the
+/// value itself.
+///
+/// # Errors
+/// Returns an error if the proof specs are not the proof specs of the client, if the proposed
+/// header cannot be verified, or if the proof of a key-value pair cannot be verified against the
+/// app hash of the proposed header.
 pub fn update_client_and_membership(
     client_state: ClientState,
     trusted_consensus_state: ConsensusState,
     proposed_header: Header,
     time: u64,
+    proof_specs: &ProofSpecs,
+    hashed_values: bool,
     request_iter: impl Iterator<Item = (Vec<Vec<u8>>, Vec<u8>, MerkleProof)>,
-) -> UcAndMembershipOutput {
+) -> Result<UcAndMembershipOutput, UcAndMembershipError> {
     let app_hash: [u8; 32] = proposed_header
         .signed_header
         .header()
         .app_hash
         .as_bytes()
         .try_into()
-        .unwrap();
+        .map_err(|_| UcAndMembershipError::InvalidAppHash)?;
+
+    // The output only commits the client state, so the proof specs are checked against it here.
+    let proof_specs_hash = proof_specs_hash(proof_specs);
+    if proof_specs_hash != client_state.proofSpecsHash {
+        return Err(UcAndMembershipError::ProofSpecsHashMismatch {
+            expected: client_state.proofSpecsHash.0,
+            actual: proof_specs_hash,
+        });
+    }
 
     let uc_output = sp1_ics07_tendermint_update_client::update_client(
         client_state,
         trusted_consensus_state,
         proposed_header,
         time,
-    );
+    )?;
 
-    let mem_output = sp1_ics07_tendermint_membership::membership(app_hash, request_iter);
+    let mem_output = sp1_ics07_tendermint_membership::membership(
+        app_hash,
+        proof_specs,
+        hashed_values,
+        request_iter,
+    )?;
 
-    UcAndMembershipOutput {
+    Ok(UcAndMembershipOutput {
         updateClientOutput: uc_output,
         kvPairs: mem_output.kvPairs,
-    }
+        hashedValues: hashed_values,
+    })
 }
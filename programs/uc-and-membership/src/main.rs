@@ -17,8 +17,9 @@ use sp1_ics07_tendermint_uc_and_membership::update_client_and_membership;
 use ibc_core_commitment_types::merkle::MerkleProof;
 
 use ibc_client_tendermint_types::Header;
-use sp1_ics07_tendermint_solidity::IICS07TendermintMsgs::{
-    ClientState as SolClientState, ConsensusState as SolConsensusState,
+use sp1_ics07_tendermint_solidity::{
+    decode_proof_specs,
+    IICS07TendermintMsgs::{ClientState as SolClientState, ConsensusState as SolConsensusState},
 };
 
 /// The main function of the program.
@@ -30,7 +31,9 @@ pub fn main() {
     let encoded_2 = sp1_zkvm::io::read_vec();
     let encoded_3 = sp1_zkvm::io::read_vec();
     let encoded_4 = sp1_zkvm::io::read_vec();
-    // encoded_5 is the number of key-value pairs we want to verify
+    let encoded_5 = sp1_zkvm::io::read_vec();
+    let encoded_6 = sp1_zkvm::io::read_vec();
+    // encoded_7 is the number of key-value pairs we want to verify
     let request_len = sp1_zkvm::io::read_vec()[0];
     assert!(request_len != 0);
 
@@ -44,6 +47,10 @@ pub fn main() {
     let proposed_header = serde_cbor::from_slice::<Header>(&encoded_3).unwrap();
     // input 4: time
     let time = u64::from_le_bytes(encoded_4.try_into().unwrap());
+    // input 5: the ICS-23 proof specs of the client
+    let proof_specs = decode_proof_specs(&encoded_5).unwrap();
+    // input 6: whether the output commits the hashes of the values
+    let hashed_values = encoded_6[0] != 0;
     // TODO: find an encoding that works for all the structs above.
 
     let request_iter = (0..request_len).map(|_| {
@@ -66,8 +73,11 @@ pub fn main() {
         trusted_consensus_state,
         proposed_header,
         time,
+        &proof_specs,
+        hashed_values,
         request_iter,
-    );
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
 
     sp1_zkvm::io::commit_slice(&output.abi_encode());
 }
@@ -0,0 +1,113 @@
+//! Contains the errors of the update client program.
+
+use std::fmt;
+
+/// An error of the verification of a header by [`crate::update_client`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateClientError {
+    /// The chain identifier is not a valid chain identifier.
+    InvalidChainId {
+        /// The chain identifier.
+        chain_id: String,
+    },
+    /// The header is not of the chain of the client, nor of a later revision of it.
+    ChainIdMismatch {
+        /// The chain identifier of the client.
+        expected: String,
+        /// The chain identifier of the header.
+        actual: String,
+    },
+    /// The trusted height is of a later revision than the proposed header.
+    TrustedHeightOfLaterRevision {
+        /// The trusted height.
+        trusted_height: String,
+        /// The height of the proposed header.
+        proposed_height: String,
+    },
+    /// A height cannot be represented in the client.
+    InvalidHeight {
+        /// The height.
+        height: String,
+    },
+    /// The trusting period of the trusted consensus state has passed.
+    TrustedStateExpired {
+        /// The timestamp of the trusted consensus state in seconds.
+        trusted_timestamp: i64,
+        /// The trusting period of the client in seconds.
+        trusting_period: u32,
+        /// The time of the verification in seconds.
+        time: u64,
+    },
+    /// The proposed header is ahead of the time of the verification by more than the max clock
+    /// drift.
+    HeaderFromFuture {
+        /// The timestamp of the proposed header in seconds.
+        header_timestamp: i64,
+        /// The max clock drift of the client in seconds.
+        max_clock_drift: u32,
+        /// The time of the verification in seconds.
+        time: u64,
+    },
+    /// The validators of the trusted consensus state that signed the proposed header do not have
+    /// enough voting power, e.g. because the validator set changed by more than the trust level.
+    InsufficientVotingPower(String),
+    /// The proposed header is invalid.
+    InvalidHeader(String),
+}
+
+impl UpdateClientError {
+    /// Classifies an error of the header verification of ibc-rs, which only describes the
+    /// verdict of the light client verifier in its message.
+    #[must_use]
+    pub fn from_verification(description: String) -> Self {
+        // The light client verifier reports both a lack of overlap with the trusted validators
+        // and a lack of signers of the header as insufficient.
+        if description.contains("insufficient") {
+            Self::InsufficientVotingPower(description)
+        } else {
+            Self::InvalidHeader(description)
+        }
+    }
+}
+
+impl fmt::Display for UpdateClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChainId { chain_id } => write!(f, "invalid chain identifier {chain_id}"),
+            Self::ChainIdMismatch { expected, actual } => write!(
+                f,
+                "the header of chain {actual} is not of the chain of the client ({expected}) nor of a later revision of it"
+            ),
+            Self::TrustedHeightOfLaterRevision {
+                trusted_height,
+                proposed_height,
+            } => write!(
+                f,
+                "the trusted height {trusted_height} is of a later revision than the proposed header at {proposed_height}"
+            ),
+            Self::InvalidHeight { height } => write!(f, "invalid height {height}"),
+            Self::TrustedStateExpired {
+                trusted_timestamp,
+                trusting_period,
+                time,
+            } => write!(
+                f,
+                "the trusted consensus state at {trusted_timestamp} has expired at {time} with a trusting period of {trusting_period} seconds"
+            ),
+            Self::HeaderFromFuture {
+                header_timestamp,
+                max_clock_drift,
+                time,
+            } => write!(
+                f,
+                "the header at {header_timestamp} is more than the max clock drift of {max_clock_drift} seconds ahead of {time}"
+            ),
+            Self::InsufficientVotingPower(description) => {
+                write!(f, "insufficient voting power: {description}")
+            }
+            Self::InvalidHeader(description) => write!(f, "invalid header: {description}"),
+        }
+    }
+}
+
+impl std::error::Error for UpdateClientError {}
@@ -2,60 +2,150 @@
 //! program.
 #![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
 
+pub mod errors;
 pub mod types;
 
 use std::{str::FromStr, time::Duration};
 
+use errors::UpdateClientError;
 use ibc_client_tendermint::{
     client_state::verify_header,
     types::{ConsensusState, Header, TENDERMINT_CLIENT_TYPE},
 };
+use ibc_core_client::types::Height;
 use ibc_core_host_types::identifiers::{ChainId, ClientId};
 use sp1_ics07_tendermint_solidity::{
-    IICS07TendermintMsgs::ClientState, IUpdateClientMsgs::UpdateClientOutput,
+    revision_chain_id, IICS07TendermintMsgs::ClientState, IUpdateClientMsgs::UpdateClientOutput,
 };
 
 use tendermint_light_client_verifier::{options::Options, ProdVerifier};
 
 /// The main function of the program without the zkVM wrapper.
+///
+/// The proposed header may be of a later revision of the chain of the client, e.g. `foo-2` for a
+/// client of `foo-1`, in which case it is verified against the chain identifier of the new
+/// revision from a trusted consensus state of the previous revision.
+///
+/// # Errors
+/// Returns an error if the proposed header cannot be verified from the trusted consensus state.
 #[allow(clippy::missing_panics_doc)]
-#[must_use]
 pub fn update_client(
     client_state: ClientState,
     trusted_consensus_state: ConsensusState,
     proposed_header: Header,
     time: u64,
-) -> UpdateClientOutput {
+) -> Result<UpdateClientOutput, UpdateClientError> {
     let client_id = ClientId::new(TENDERMINT_CLIENT_TYPE, 0).unwrap();
-    let chain_id = ChainId::from_str(&client_state.chainId).unwrap();
+    let header_chain_id = proposed_header.signed_header.header.chain_id.as_str();
+    let chain_id = revision_chain_id(&client_state.chainId, header_chain_id);
+    if chain_id != header_chain_id {
+        return Err(UpdateClientError::ChainIdMismatch {
+            expected: client_state.chainId,
+            actual: header_chain_id.to_string(),
+        });
+    }
+    let chain_id = ChainId::from_str(chain_id).map_err(|_| UpdateClientError::InvalidChainId {
+        chain_id: chain_id.to_string(),
+    })?;
     let options = Options {
         trust_threshold: client_state.trustLevel.clone().into(),
         trusting_period: Duration::from_secs(client_state.trustingPeriod.into()),
-        clock_drift: Duration::default(),
+        clock_drift: Duration::from_secs(client_state.maxClockDrift.into()),
+    };
+
+    // ibc-rs only verifies a header from a trusted height of the same revision, so a header of a
+    // new revision is verified as a copy whose trusted height is in the new revision. The trusted
+    // height of the proposed header and of the output is kept as stored, so the contract checks
+    // the trusted consensus state at the actual trusted height.
+    let trusted_height = proposed_header.trusted_height;
+    let revision_number = proposed_header.height().revision_number();
+    if trusted_height.revision_number() > revision_number {
+        return Err(UpdateClientError::TrustedHeightOfLaterRevision {
+            trusted_height: trusted_height.to_string(),
+            proposed_height: proposed_header.height().to_string(),
+        });
+    }
+    let revision_header = if trusted_height.revision_number() == revision_number {
+        None
+    } else {
+        let mut header = proposed_header.clone();
+        header.trusted_height = Height::new(revision_number, trusted_height.revision_height())
+            .map_err(|_| UpdateClientError::InvalidHeight {
+                height: trusted_height.to_string(),
+            })?;
+        Some(header)
     };
 
+    check_time(
+        &client_state,
+        &trusted_consensus_state,
+        &proposed_header,
+        time,
+    )?;
+
     let ctx = types::validation::ClientValidationCtx::new(time, &trusted_consensus_state);
 
     verify_header::<_, sha2::Sha256>(
         &ctx,
-        &proposed_header,
+        revision_header.as_ref().unwrap_or(&proposed_header),
         &client_id,
         &chain_id,
         &options,
         &ProdVerifier::default(),
     )
-    .unwrap();
+    .map_err(|e| UpdateClientError::from_verification(e.to_string()))?;
 
-    let trusted_height = proposed_header.trusted_height.try_into().unwrap();
-    let new_height = proposed_header.height().try_into().unwrap();
+    let trusted_height =
+        trusted_height
+            .try_into()
+            .map_err(|_| UpdateClientError::InvalidHeight {
+                height: trusted_height.to_string(),
+            })?;
+    let new_height =
+        proposed_header
+            .height()
+            .try_into()
+            .map_err(|_| UpdateClientError::InvalidHeight {
+                height: proposed_header.height().to_string(),
+            })?;
     let new_consensus_state = ConsensusState::from(proposed_header);
 
-    UpdateClientOutput {
+    Ok(UpdateClientOutput {
         clientState: client_state,
         trustedConsensusState: trusted_consensus_state.into(),
         newConsensusState: new_consensus_state.into(),
         time,
         trustedHeight: trusted_height,
         newHeight: new_height,
+    })
+}
+
+/// Checks that the trusted consensus state is within the trusting period, and that the proposed
+/// header is within the max clock drift, at the time of the verification, as the light client
+/// verifier does.
+fn check_time(
+    client_state: &ClientState,
+    trusted_consensus_state: &ConsensusState,
+    proposed_header: &Header,
+    time: u64,
+) -> Result<(), UpdateClientError> {
+    let trusted_timestamp = trusted_consensus_state.timestamp.unix_timestamp();
+    if i128::from(trusted_timestamp) + i128::from(client_state.trustingPeriod) <= i128::from(time) {
+        return Err(UpdateClientError::TrustedStateExpired {
+            trusted_timestamp,
+            trusting_period: client_state.trustingPeriod,
+            time,
+        });
     }
+
+    let header_timestamp = proposed_header.signed_header.header.time.unix_timestamp();
+    if i128::from(header_timestamp) >= i128::from(time) + i128::from(client_state.maxClockDrift) {
+        return Err(UpdateClientError::HeaderFromFuture {
+            header_timestamp,
+            max_clock_drift: client_state.maxClockDrift,
+            time,
+        });
+    }
+
+    Ok(())
 }
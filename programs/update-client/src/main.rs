@@ -40,7 +40,8 @@ pub fn main() {
     let time = u64::from_le_bytes(encoded_4.try_into().unwrap());
     // TODO: find an encoding that works for all the structs above.
 
-    let output = update_client(client_state, trusted_consensus_state, proposed_header, time);
+    let output = update_client(client_state, trusted_consensus_state, proposed_header, time)
+        .unwrap_or_else(|e| panic!("{e}"));
 
     sp1_zkvm::io::commit_slice(&output.abi_encode());
 }
@@ -0,0 +1,59 @@
+//! Contains the errors of the upgrade client program.
+
+use std::fmt;
+
+/// An error of the verification of an upgrade by [`crate::upgrade_client`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeClientError {
+    /// The proof specs do not match the proof specs hash of the client state.
+    ProofSpecsMismatch,
+    /// The proof of the upgraded client state is invalid.
+    InvalidClientStateProof(String),
+    /// The proof of the upgraded consensus state is invalid.
+    InvalidConsensusStateProof(String),
+    /// The upgraded client state cannot be decoded.
+    InvalidClientState(String),
+    /// The upgraded consensus state cannot be decoded.
+    InvalidConsensusState(String),
+    /// The upgraded client state is not at a greater height than the upgrade height.
+    HeightNotIncreasing {
+        /// The upgrade height, i.e. the latest height of the client.
+        upgrade_height: u64,
+        /// The latest height of the upgraded client state.
+        new_height: u64,
+    },
+}
+
+impl fmt::Display for UpgradeClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProofSpecsMismatch => {
+                write!(f, "the proof specs do not match the proof specs of the client")
+            }
+            Self::InvalidClientStateProof(description) => {
+                write!(f, "invalid proof of the upgraded client state: {description}")
+            }
+            Self::InvalidConsensusStateProof(description) => {
+                write!(
+                    f,
+                    "invalid proof of the upgraded consensus state: {description}"
+                )
+            }
+            Self::InvalidClientState(description) => {
+                write!(f, "invalid upgraded client state: {description}")
+            }
+            Self::InvalidConsensusState(description) => {
+                write!(f, "invalid upgraded consensus state: {description}")
+            }
+            Self::HeightNotIncreasing {
+                upgrade_height,
+                new_height,
+            } => write!(
+                f,
+                "the upgraded client state at height {new_height} is not above the upgrade height {upgrade_height}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UpgradeClientError {}
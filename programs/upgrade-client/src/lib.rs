@@ -0,0 +1,203 @@
+//! The crate that contains the types and utilities for `sp1-ics07-tendermint-upgrade-client`
+//! program.
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+
+pub mod errors;
+
+use errors::UpgradeClientError;
+use ibc_client_tendermint_types::{
+    TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_core_commitment_types::{
+    commitment::CommitmentRoot,
+    merkle::{MerklePath, MerkleProof},
+    proto::ics23::HostFunctionsManager,
+    specs::ProofSpecs,
+};
+use ibc_proto::{
+    google::protobuf::Any,
+    ibc::lightclients::tendermint::v1::{
+        ClientState as RawClientState, ConsensusState as RawConsensusState,
+    },
+};
+use prost::Message;
+use sp1_ics07_tendermint_solidity::{
+    proof_specs_hash, upgraded_client_state_path, upgraded_consensus_state_path,
+    IICS02ClientMsgs::Height,
+    IICS07TendermintMsgs::{ClientState, ConsensusState},
+    IUpgradeClientMsgs::UpgradeClientOutput,
+};
+
+/// The commitment root of the upgraded consensus state, as in ibc-go.
+/// The chain does not know its own app hash when it commits the upgraded consensus state, so no
+/// membership can be verified at the upgrade height until the client is updated.
+const SENTINEL_ROOT: &[u8] = b"sentinel_root";
+
+/// The main function of the program without the zkVM wrapper.
+///
+/// Verifies the membership of the upgraded client and consensus states, encoded as protobuf
+/// `Any` as committed by the chain, under the ICS-02 upgrade path at the latest height of the
+/// client, against the root of the trusted consensus state at that height. The proofs are
+/// verified with the given ICS-23 proof specs, which must be the proof specs of the client.
+///
+/// The upgraded client state only sets the chain-wide parameters, i.e. the chain identifier, the
+/// unbonding period and the latest height. The trust level, the max clock drift, the proof specs
+/// and the zk algorithm of the client are kept, and the trusting period is shortened
+/// proportionally if the unbonding period is.
+///
+/// # Errors
+/// Returns an error if the proof specs do not match the client state, if a proof cannot be
+/// verified, if an upgraded state cannot be decoded, or if the upgraded client state is not at a
+/// greater height than the upgrade height.
+pub fn upgrade_client(
+    client_state: ClientState,
+    trusted_consensus_state: ConsensusState,
+    proof_specs: &ProofSpecs,
+    upgraded_client_state: Vec<u8>,
+    upgraded_consensus_state: Vec<u8>,
+    proof_upgrade_client: MerkleProof,
+    proof_upgrade_consensus_state: MerkleProof,
+    time: u64,
+) -> Result<UpgradeClientOutput, UpgradeClientError> {
+    if proof_specs_hash(proof_specs) != client_state.proofSpecsHash {
+        return Err(UpgradeClientError::ProofSpecsMismatch);
+    }
+
+    let upgrade_height = client_state.latestHeight.revisionHeight;
+    let commitment_root = CommitmentRoot::from_bytes(trusted_consensus_state.root.as_slice());
+
+    proof_upgrade_client
+        .verify_membership::<HostFunctionsManager>(
+            proof_specs,
+            commitment_root.clone().into(),
+            merkle_path(upgraded_client_state_path(upgrade_height.into())),
+            upgraded_client_state.clone(),
+            0,
+        )
+        .map_err(|e| UpgradeClientError::InvalidClientStateProof(e.to_string()))?;
+    proof_upgrade_consensus_state
+        .verify_membership::<HostFunctionsManager>(
+            proof_specs,
+            commitment_root.into(),
+            merkle_path(upgraded_consensus_state_path(upgrade_height.into())),
+            upgraded_consensus_state.clone(),
+            0,
+        )
+        .map_err(|e| UpgradeClientError::InvalidConsensusStateProof(e.to_string()))?;
+
+    let new_client_state = new_client_state(&client_state, &upgraded_client_state)?;
+    let new_consensus_state = new_consensus_state(&upgraded_consensus_state)?;
+    if new_client_state.latestHeight <= client_state.latestHeight {
+        return Err(UpgradeClientError::HeightNotIncreasing {
+            upgrade_height: client_state.latestHeight.revisionHeight.into(),
+            new_height: new_client_state.latestHeight.revisionHeight.into(),
+        });
+    }
+
+    Ok(UpgradeClientOutput {
+        trustedHeight: client_state.latestHeight.clone(),
+        clientState: client_state,
+        trustedConsensusState: trusted_consensus_state,
+        newClientState: new_client_state,
+        newConsensusState: new_consensus_state,
+        time,
+    })
+}
+
+/// Converts a path to a merkle path.
+fn merkle_path(path: Vec<Vec<u8>>) -> MerklePath {
+    MerklePath {
+        key_path: path.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// Builds the client state after the upgrade from the encoded upgraded client state.
+fn new_client_state(
+    client_state: &ClientState,
+    upgraded_client_state: &[u8],
+) -> Result<ClientState, UpgradeClientError> {
+    let invalid = |reason: &str| UpgradeClientError::InvalidClientState(reason.to_string());
+
+    let any = Any::decode(upgraded_client_state).map_err(|e| invalid(&e.to_string()))?;
+    if any.type_url != TENDERMINT_CLIENT_STATE_TYPE_URL {
+        return Err(invalid(&format!("unexpected type {}", any.type_url)));
+    }
+    let upgraded =
+        RawClientState::decode(any.value.as_slice()).map_err(|e| invalid(&e.to_string()))?;
+
+    let latest_height = upgraded
+        .latest_height
+        .ok_or_else(|| invalid("missing latest height"))?;
+    let unbonding_period: u32 = upgraded
+        .unbonding_period
+        .ok_or_else(|| invalid("missing unbonding period"))?
+        .seconds
+        .try_into()
+        .map_err(|_| invalid("unbonding period out of range"))?;
+    if unbonding_period == 0 {
+        return Err(invalid("the unbonding period is zero"));
+    }
+
+    let trusting_period = if unbonding_period < client_state.unbondingPeriod {
+        u32::try_from(
+            u64::from(client_state.trustingPeriod) * u64::from(unbonding_period)
+                / u64::from(client_state.unbondingPeriod),
+        )
+        .map_err(|_| invalid("trusting period out of range"))?
+    } else {
+        client_state.trustingPeriod
+    };
+
+    Ok(ClientState {
+        chainId: upgraded.chain_id,
+        trustLevel: client_state.trustLevel.clone(),
+        latestHeight: Height {
+            revisionNumber: latest_height
+                .revision_number
+                .try_into()
+                .map_err(|_| invalid("revision number out of range"))?,
+            revisionHeight: latest_height
+                .revision_height
+                .try_into()
+                .map_err(|_| invalid("revision height out of range"))?,
+        },
+        trustingPeriod: trusting_period,
+        unbondingPeriod: unbonding_period,
+        maxClockDrift: client_state.maxClockDrift,
+        proofSpecsHash: client_state.proofSpecsHash,
+        isFrozen: false,
+        zkAlgorithm: client_state.zkAlgorithm,
+    })
+}
+
+/// Builds the consensus state after the upgrade from the encoded upgraded consensus state.
+fn new_consensus_state(
+    upgraded_consensus_state: &[u8],
+) -> Result<ConsensusState, UpgradeClientError> {
+    let invalid = |reason: &str| UpgradeClientError::InvalidConsensusState(reason.to_string());
+
+    let any = Any::decode(upgraded_consensus_state).map_err(|e| invalid(&e.to_string()))?;
+    if any.type_url != TENDERMINT_CONSENSUS_STATE_TYPE_URL {
+        return Err(invalid(&format!("unexpected type {}", any.type_url)));
+    }
+    let upgraded =
+        RawConsensusState::decode(any.value.as_slice()).map_err(|e| invalid(&e.to_string()))?;
+
+    let mut root = [0u8; 32];
+    root[..SENTINEL_ROOT.len()].copy_from_slice(SENTINEL_ROOT);
+    let next_validators_hash: [u8; 32] = upgraded
+        .next_validators_hash
+        .try_into()
+        .map_err(|_| invalid("the next validators hash is not 32 bytes"))?;
+
+    Ok(ConsensusState {
+        timestamp: upgraded
+            .timestamp
+            .ok_or_else(|| invalid("missing timestamp"))?
+            .seconds
+            .try_into()
+            .map_err(|_| invalid("timestamp out of range"))?,
+        root: root.into(),
+        nextValidatorsHash: next_validators_hash.into(),
+    })
+}
@@ -0,0 +1,66 @@
+//! A program that verifies the upgraded client and consensus states committed by a blockchain
+//! for a planned upgrade, using an IBC tendermint light client.
+
+#![deny(missing_docs, clippy::nursery, clippy::pedantic, warnings)]
+#![allow(clippy::no_mangle_with_rust_abi)]
+// These two lines are necessary for the program to properly compile.
+//
+// Under the hood, we wrap your main function with some extra code so that it behaves properly
+// inside the zkVM.
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use alloy_sol_types::SolValue;
+use ibc_core_commitment_types::merkle::MerkleProof;
+use ibc_proto::Protobuf;
+use sp1_ics07_tendermint_solidity::{
+    decode_proof_specs,
+    IICS07TendermintMsgs::{ClientState as SolClientState, ConsensusState as SolConsensusState},
+};
+use sp1_ics07_tendermint_upgrade_client::upgrade_client;
+
+/// The main function of the program.
+///
+/// # Panics
+/// Panics if the verification fails.
+pub fn main() {
+    let encoded_1 = sp1_zkvm::io::read_vec();
+    let encoded_2 = sp1_zkvm::io::read_vec();
+    let encoded_3 = sp1_zkvm::io::read_vec();
+    let encoded_4 = sp1_zkvm::io::read_vec();
+    let encoded_5 = sp1_zkvm::io::read_vec();
+    let encoded_6 = sp1_zkvm::io::read_vec();
+    let encoded_7 = sp1_zkvm::io::read_vec();
+    let encoded_8 = sp1_zkvm::io::read_vec();
+
+    // input 1: the client state
+    let client_state = bincode::deserialize::<SolClientState>(&encoded_1).unwrap();
+    // input 2: the trusted consensus state at the upgrade height
+    let trusted_consensus_state = bincode::deserialize::<SolConsensusState>(&encoded_2).unwrap();
+    // input 3: the ICS-23 proof specs of the proofs
+    let proof_specs = decode_proof_specs(&encoded_3).unwrap();
+    // input 4: the upgraded client state, encoded as committed by the chain
+    let upgraded_client_state = encoded_4;
+    // input 5: the upgraded consensus state, encoded as committed by the chain
+    let upgraded_consensus_state = encoded_5;
+    // input 6: the proof of the upgraded client state
+    let proof_upgrade_client = MerkleProof::decode_vec(&encoded_6).unwrap();
+    // input 7: the proof of the upgraded consensus state
+    let proof_upgrade_consensus_state = MerkleProof::decode_vec(&encoded_7).unwrap();
+    // input 8: time
+    let time = u64::from_le_bytes(encoded_8.try_into().unwrap());
+
+    let output = upgrade_client(
+        client_state,
+        trusted_consensus_state,
+        &proof_specs,
+        upgraded_client_state,
+        upgraded_consensus_state,
+        proof_upgrade_client,
+        proof_upgrade_consensus_state,
+        time,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+
+    sp1_zkvm::io::commit_slice(&output.abi_encode());
+}